@@ -1,11 +1,16 @@
 use serde::{Deserialize, Serialize};
 
+use crate::secret::SecretValue;
+
 // --- Core auth types ---
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub enum AirflowAuth {
     Basic(BasicAuth),
+    /// Username/password exchanged for a JWT at `/auth/token` (Airflow 3).
+    Jwt(BasicAuth),
     Token(TokenSource),
+    OAuth2(OAuth2Auth),
     Conveyor,
     Mwaa(MwaaAuth),
     Astronomer(AstronomerAuth),
@@ -14,15 +19,17 @@ pub enum AirflowAuth {
 
 #[derive(Deserialize, Serialize, Clone)]
 pub struct BasicAuth {
+    /// May contain `${ENV_VAR}` placeholders.
     pub username: String,
-    pub password: String,
+    pub password: SecretValue,
 }
 
 impl std::fmt::Debug for BasicAuth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // `SecretValue` redacts inline values but shows env/cmd references.
         f.debug_struct("BasicAuth")
             .field("username", &self.username)
-            .field("password", &"<redacted>")
+            .field("password", &self.password)
             .finish()
     }
 }
@@ -31,21 +38,60 @@ impl std::fmt::Debug for BasicAuth {
 #[serde(untagged)]
 pub enum TokenSource {
     Command { cmd: String },
-    Static { token: String },
+    Static { token: SecretValue },
 }
 
 impl std::fmt::Debug for TokenSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TokenSource::Command { cmd } => f.debug_struct("Command").field("cmd", cmd).finish(),
-            TokenSource::Static { .. } => f
-                .debug_struct("Static")
-                .field("token", &"<redacted>")
-                .finish(),
+            TokenSource::Static { token } => {
+                f.debug_struct("Static").field("token", token).finish()
+            }
         }
     }
 }
 
+/// OAuth 2.0 / OIDC grant used to obtain access tokens.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth2Flow {
+    /// Machine-to-machine login with a client id and secret.
+    ClientCredentials,
+    /// Browser login on another device (RFC 8628). `flowrs config add` runs
+    /// the login once and stores the refresh token; flowrs then refreshes.
+    DeviceCode,
+}
+
+/// OAuth 2.0 / OIDC authentication against an identity provider such as Keycloak.
+///
+/// ```toml
+/// [servers.auth.OAuth2]
+/// flow = "device_code"
+/// token_url = "https://sso.example.com/realms/data/protocol/openid-connect/token"
+/// device_authorization_url = "https://sso.example.com/realms/data/protocol/openid-connect/auth/device"
+/// client_id = "flowrs"
+/// scopes = ["openid", "offline_access"]
+/// refresh_token = { keyring = "prod/refresh_token" }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct OAuth2Auth {
+    pub flow: OAuth2Flow,
+    pub token_url: String,
+    /// Required for the device-code flow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_authorization_url: Option<String>,
+    pub client_id: String,
+    /// Required for client credentials; public clients leave it unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<SecretValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    /// Refresh token obtained by the device-code login.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<SecretValue>,
+}
+
 // --- Managed service auth data ---
 
 /// MWAA authentication token type
@@ -1,13 +1,18 @@
 pub mod auth;
 pub mod base;
+pub mod error;
+pub mod filter;
 pub mod v1;
 pub mod v2;
+pub mod version;
 
 use anyhow::Result;
 
 use crate::config::{AirflowConfig, AirflowVersion};
 
-pub use base::BaseClient;
+pub use base::{ApiRequest, BaseClient, RetryPolicy};
+pub use error::AirflowApiError;
+pub use filter::{DagFilter, DagRunFilter};
 pub use v1::V1Client;
 pub use v2::V2Client;
 
@@ -19,12 +24,18 @@ pub enum AirflowApiClient {
     V2(V2Client),
 }
 
-/// Create an Airflow API client based on the configuration version
+/// Create an Airflow API client based on the configuration version.
+///
+/// Servers configured with `AirflowVersion::Auto` must have been resolved with
+/// [`version::resolve_version`] first.
 pub fn create_api_client(config: &AirflowConfig) -> Result<AirflowApiClient> {
-    let base = BaseClient::new(config.clone())?;
+    let config = version::with_resolved_version(config)?;
+    let version = config.version.clone();
+    let base = BaseClient::new(config)?;
 
-    match config.version {
+    match version {
         AirflowVersion::V2 => Ok(AirflowApiClient::V1(V1Client::new(base))), // V2 uses API v1
         AirflowVersion::V3 => Ok(AirflowApiClient::V2(V2Client::new(base))), // V3 uses API v2
+        AirflowVersion::Auto => unreachable!("version resolved above"),
     }
 }
@@ -88,6 +88,12 @@ impl AuthProvider for CommandTokenProvider {
         let (token, _) = cached.as_ref().expect("token cached above");
         Ok(request.bearer_auth(token))
     }
+
+    async fn invalidate(&self) -> bool {
+        // The token was revoked or expired before the TTL ran out.
+        *self.cached.lock().await = None;
+        true
+    }
 }
 
 #[cfg(test)]
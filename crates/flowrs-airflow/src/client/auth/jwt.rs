@@ -0,0 +1,257 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use base64::Engine;
+use log::{debug, info};
+use reqwest::{RequestBuilder, Url};
+use serde::Deserialize;
+
+use super::AuthProvider;
+
+/// Log in again this long before the token expires, so a request never goes
+/// out with a token that expires in flight.
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// How long to reuse a token whose expiry cannot be decoded.
+const FALLBACK_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+/// Exchanges username/password for a JWT at Airflow 3's `/auth/token`
+/// endpoint (simple auth manager and FAB), and logs in again shortly before
+/// the token expires.
+pub struct JwtAuthProvider {
+    client: reqwest::Client,
+    token_url: Url,
+    username: String,
+    password: String,
+    /// Cached `(token, refresh_at)`. The async mutex also single-flights
+    /// concurrent logins.
+    cached: tokio::sync::Mutex<Option<(String, SystemTime)>>,
+}
+
+impl JwtAuthProvider {
+    pub fn new(
+        client: reqwest::Client,
+        endpoint: &str,
+        username: String,
+        password: String,
+    ) -> Result<Self> {
+        let token_url = Url::parse(endpoint)?.join("auth/token")?;
+        Ok(Self {
+            client,
+            token_url,
+            username,
+            password,
+            cached: tokio::sync::Mutex::new(None),
+        })
+    }
+
+    async fn login(&self) -> Result<String> {
+        let response = self
+            .client
+            .post(self.token_url.clone())
+            .json(&serde_json::json!({
+                "username": self.username,
+                "password": self.password,
+            }))
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach {}", self.token_url))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "Login as '{}' at {} failed: {status} {}",
+                self.username,
+                self.token_url,
+                body.trim()
+            );
+        }
+
+        let token: TokenResponse = response
+            .json()
+            .await
+            .context("Login response did not contain an access_token")?;
+        Ok(token.access_token)
+    }
+}
+
+/// Read the `exp` claim of a JWT without verifying it; the server does that.
+fn token_expiry(token: &str) -> Option<SystemTime> {
+    #[derive(Deserialize)]
+    struct Claims {
+        exp: u64,
+    }
+
+    let payload = token.split('.').nth(1)?;
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    let claims: Claims = serde_json::from_slice(&decoded).ok()?;
+    Some(UNIX_EPOCH + Duration::from_secs(claims.exp))
+}
+
+/// When to log in again for a token obtained at `now`.
+fn refresh_at(token: &str, now: SystemTime) -> SystemTime {
+    if let Some(exp) = token_expiry(token) {
+        exp.checked_sub(REFRESH_MARGIN).unwrap_or(exp).max(now)
+    } else {
+        debug!("JWT has no readable exp claim; reusing it for {FALLBACK_TTL:?}");
+        now + FALLBACK_TTL
+    }
+}
+
+impl fmt::Debug for JwtAuthProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtAuthProvider")
+            .field("token_url", &self.token_url.as_str())
+            .field("username", &self.username)
+            .field("password", &"***redacted***")
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl AuthProvider for JwtAuthProvider {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        let mut cached = self.cached.lock().await;
+
+        let now = SystemTime::now();
+        let fresh = cached.as_ref().is_some_and(|(_, refresh)| now < *refresh);
+
+        if !fresh {
+            info!("🔑 JWT Auth: logging in as {}", self.username);
+            let token = self.login().await?;
+            let refresh = refresh_at(&token, now);
+            *cached = Some((token, refresh));
+        }
+
+        let (token, _) = cached.as_ref().expect("token cached above");
+        Ok(request.bearer_auth(token))
+    }
+
+    async fn invalidate(&self) -> bool {
+        *self.cached.lock().await = None;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{MockResponse, MockServer};
+
+    /// An unsigned JWT with the given `exp` claim.
+    fn jwt(exp: u64) -> String {
+        let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        let header = engine.encode(r#"{"alg":"none"}"#);
+        let payload = engine.encode(format!(r#"{{"sub":"airflow","exp":{exp}}}"#));
+        format!("{header}.{payload}.sig")
+    }
+
+    fn unix_now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    fn bearer(request: RequestBuilder) -> String {
+        request
+            .build()
+            .unwrap()
+            .headers()
+            .get("authorization")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    async fn token_server(exp: u64) -> MockServer {
+        MockServer::start(move |req| {
+            if req.method == "POST" && req.path == "/auth/token" && req.body.contains("hunter2") {
+                MockResponse::json(201, &serde_json::json!({ "access_token": jwt(exp) }))
+            } else {
+                MockResponse::new(401, "Invalid credentials")
+            }
+        })
+        .await
+    }
+
+    fn provider(url: &str, password: &str) -> JwtAuthProvider {
+        JwtAuthProvider::new(
+            reqwest::Client::new(),
+            url,
+            "airflow".to_string(),
+            password.to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn decodes_expiry_from_payload() {
+        let exp = token_expiry(&jwt(1_700_000_000)).unwrap();
+        assert_eq!(exp, UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        assert!(token_expiry("not-a-jwt").is_none());
+    }
+
+    #[test]
+    fn refreshes_before_expiry_or_after_fallback_ttl() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+        assert_eq!(
+            refresh_at(&jwt(2_000), now),
+            UNIX_EPOCH + Duration::from_secs(2_000) - REFRESH_MARGIN
+        );
+        // Already (nearly) expired: refresh on the next request.
+        assert_eq!(refresh_at(&jwt(1_010), now), now);
+        assert_eq!(refresh_at("opaque", now), now + FALLBACK_TTL);
+    }
+
+    #[tokio::test]
+    async fn logs_in_once_and_reuses_token() {
+        let exp = unix_now() + 3600;
+        let server = token_server(exp).await;
+        let provider = provider(&server.url, "hunter2");
+        let get = || reqwest::Client::new().get("http://localhost/api/v2/dags");
+
+        let first = bearer(provider.authenticate(get()).await.unwrap());
+        let second = bearer(provider.authenticate(get()).await.unwrap());
+        assert_eq!(first, format!("Bearer {}", jwt(exp)));
+        assert_eq!(first, second);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].body.contains(r#""username":"airflow""#));
+    }
+
+    #[tokio::test]
+    async fn logs_in_again_when_token_expires() {
+        // Expires within the refresh margin, so every request logs in again.
+        let server = token_server(unix_now() + 5).await;
+        let provider = provider(&server.url, "hunter2");
+        let get = || reqwest::Client::new().get("http://localhost/api/v2/dags");
+
+        bearer(provider.authenticate(get()).await.unwrap());
+        bearer(provider.authenticate(get()).await.unwrap());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn reports_failed_login() {
+        let server = token_server(unix_now() + 3600).await;
+        let provider = provider(&server.url, "wrong");
+        let get = reqwest::Client::new().get("http://localhost/api/v2/dags");
+
+        let err = provider.authenticate(get).await.unwrap_err().to_string();
+        assert!(err.contains("401"), "{err}");
+        assert!(err.contains("Invalid credentials"), "{err}");
+    }
+}
@@ -1,17 +1,25 @@
 mod basic;
 mod command;
+mod jwt;
+mod oauth2;
 mod static_token;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 pub use basic::BasicAuthProvider;
 pub use command::CommandTokenProvider;
+pub use jwt::JwtAuthProvider;
+pub use oauth2::{
+    poll_device_token, request_device_code, set_refresh_token_writer, ClientCredentialsProvider,
+    DeviceAuthorization, DeviceCodeProvider, RefreshTokenWriter, TokenResponse,
+};
 pub use static_token::StaticTokenProvider;
 
 use async_trait::async_trait;
 use reqwest::RequestBuilder;
 
-use crate::auth::{AirflowAuth, BasicAuth, TokenSource};
+use crate::auth::{AirflowAuth, BasicAuth, OAuth2Flow, TokenSource};
+use crate::config::AirflowConfig;
 #[cfg(feature = "astronomer")]
 use crate::managed_services::astronomer::AstronomerAuthProvider;
 #[cfg(feature = "composer")]
@@ -20,6 +28,7 @@ use crate::managed_services::composer::ComposerAuthProvider;
 use crate::managed_services::conveyor::ConveyorAuthProvider;
 #[cfg(feature = "mwaa")]
 use crate::managed_services::mwaa::MwaaAuthProvider;
+use crate::secret::expand_env_vars;
 
 /// Authentication provider trait for Airflow API requests.
 ///
@@ -28,21 +37,57 @@ use crate::managed_services::mwaa::MwaaAuthProvider;
 #[async_trait]
 pub trait AuthProvider: Send + Sync {
     async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder>;
+
+    /// Called when the server rejected the credentials (401 or 403).
+    /// Providers that cache or can renew credentials discard them, so the
+    /// next `authenticate` obtains fresh ones, and return `true`: the request
+    /// is then retried once. Fixed credentials return `false`.
+    async fn invalidate(&self) -> bool {
+        false
+    }
 }
 
-/// Create an auth provider from an `AirflowAuth` config enum variant.
-pub fn create_auth_provider(auth: &AirflowAuth) -> Result<Box<dyn AuthProvider>> {
-    match auth {
+/// Create an auth provider for the `auth` section of a server config.
+///
+/// Secret references (`${ENV_VAR}`, `{ env = .. }`, `{ cmd = .. }`) are
+/// resolved here, so the resolved values only ever live in the provider.
+/// Providers that talk to the server themselves (JWT login) reuse `client`.
+pub fn create_auth_provider(
+    config: &AirflowConfig,
+    client: &reqwest::Client,
+) -> Result<Box<dyn AuthProvider>> {
+    match &config.auth {
         AirflowAuth::Basic(BasicAuth { username, password }) => Ok(Box::new(BasicAuthProvider {
-            username: username.clone(),
-            password: password.clone(),
+            username: expand_env_vars(username)?,
+            password: password
+                .resolve()
+                .context("Failed to resolve basic auth password")?,
         })),
+        AirflowAuth::Jwt(BasicAuth { username, password }) => Ok(Box::new(JwtAuthProvider::new(
+            client.clone(),
+            &config.endpoint,
+            expand_env_vars(username)?,
+            password
+                .resolve()
+                .context("Failed to resolve login password")?,
+        )?)),
         AirflowAuth::Token(TokenSource::Static { token }) => Ok(Box::new(StaticTokenProvider {
-            token: token.clone(),
+            token: token.resolve().context("Failed to resolve static token")?,
         })),
         AirflowAuth::Token(TokenSource::Command { cmd }) => {
             Ok(Box::new(CommandTokenProvider::new(cmd.clone())))
         }
+        AirflowAuth::OAuth2(oauth2) => match oauth2.flow {
+            OAuth2Flow::ClientCredentials => Ok(Box::new(ClientCredentialsProvider::new(
+                client.clone(),
+                oauth2,
+            )?)),
+            OAuth2Flow::DeviceCode => Ok(Box::new(DeviceCodeProvider::new(
+                client.clone(),
+                &config.name,
+                oauth2,
+            )?)),
+        },
         #[cfg(feature = "conveyor")]
         AirflowAuth::Conveyor => Ok(Box::new(ConveyorAuthProvider::new())),
         #[cfg(not(feature = "conveyor"))]
@@ -79,22 +124,48 @@ pub fn create_auth_provider(auth: &AirflowAuth) -> Result<Box<dyn AuthProvider>>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::base::config;
+
+    fn create(auth: AirflowAuth) -> Result<Box<dyn AuthProvider>> {
+        let config = AirflowConfig { auth, ..config() };
+        create_auth_provider(&config, &reqwest::Client::new())
+    }
 
     #[test]
     fn test_create_auth_provider_basic() {
         let auth = AirflowAuth::Basic(BasicAuth {
             username: "user".to_string(),
-            password: "pass".to_string(),
+            password: "pass".into(),
         });
-        assert!(create_auth_provider(&auth).is_ok());
+        assert!(create(auth).is_ok());
     }
 
     #[test]
     fn test_create_auth_provider_static_token() {
         let auth = AirflowAuth::Token(TokenSource::Static {
-            token: "tok".to_string(),
+            token: "tok".into(),
+        });
+        assert!(create(auth).is_ok());
+    }
+
+    #[test]
+    fn test_create_auth_provider_unresolvable_secret() {
+        let auth = AirflowAuth::Basic(BasicAuth {
+            username: "user".to_string(),
+            password: crate::secret::SecretValue::Env {
+                env: "FLOWRS_TEST_SURELY_UNSET_VAR".to_string(),
+            },
+        });
+        assert!(create(auth).is_err());
+    }
+
+    #[test]
+    fn test_create_auth_provider_jwt() {
+        let auth = AirflowAuth::Jwt(BasicAuth {
+            username: "user".to_string(),
+            password: "pass".into(),
         });
-        assert!(create_auth_provider(&auth).is_ok());
+        assert!(create(auth).is_ok());
     }
 
     #[test]
@@ -102,6 +173,6 @@ mod tests {
         let auth = AirflowAuth::Token(TokenSource::Command {
             cmd: "echo hi".to_string(),
         });
-        assert!(create_auth_provider(&auth).is_ok());
+        assert!(create(auth).is_ok());
     }
 }
@@ -0,0 +1,221 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use log::{info, warn};
+use reqwest::RequestBuilder;
+
+use super::{CachedToken, TokenEndpoint};
+use crate::auth::OAuth2Auth;
+use crate::client::auth::AuthProvider;
+
+#[derive(Default)]
+struct State {
+    token: Option<CachedToken>,
+    refresh_token: Option<String>,
+}
+
+/// OAuth 2.0 client-credentials grant: the client id and secret are exchanged
+/// for an access token, which is cached until shortly before it expires.
+///
+/// Most providers issue no refresh token for this grant; when one is
+/// returned it is tried first, falling back to a full client login.
+pub struct ClientCredentialsProvider {
+    endpoint: TokenEndpoint,
+    state: tokio::sync::Mutex<State>,
+}
+
+impl ClientCredentialsProvider {
+    pub fn new(client: reqwest::Client, auth: &OAuth2Auth) -> Result<Self> {
+        if auth.client_secret.is_none() {
+            anyhow::bail!("The OAuth2 client-credentials flow requires a client_secret");
+        }
+        Ok(Self {
+            endpoint: TokenEndpoint::new(client, auth)?,
+            state: tokio::sync::Mutex::new(State::default()),
+        })
+    }
+}
+
+impl fmt::Debug for ClientCredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientCredentialsProvider")
+            .field("token_url", &self.endpoint.token_url.as_str())
+            .field("client_id", &self.endpoint.client_id)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl AuthProvider for ClientCredentialsProvider {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        let mut state = self.state.lock().await;
+
+        if !state.token.as_ref().is_some_and(CachedToken::is_fresh) {
+            let now = SystemTime::now();
+            let refreshed = match state.refresh_token.take() {
+                Some(refresh_token) => match self.endpoint.refresh(&refresh_token).await {
+                    Ok(response) => Some(response),
+                    Err(e) => {
+                        warn!("OAuth2 refresh failed, logging in again: {e}");
+                        None
+                    }
+                },
+                None => None,
+            };
+            let response = if let Some(response) = refreshed {
+                response
+            } else {
+                info!(
+                    "🔑 OAuth2 Auth: client credentials for {}",
+                    self.endpoint.client_id
+                );
+                self.endpoint.client_credentials().await?
+            };
+            state.token = Some(CachedToken::new(&response, now));
+            state.refresh_token = response.refresh_token;
+        }
+
+        let token = state.token.as_ref().expect("token cached above");
+        Ok(request.bearer_auth(&token.access_token))
+    }
+
+    async fn invalidate(&self) -> bool {
+        self.state.lock().await.token = None;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::auth::OAuth2Flow;
+    use crate::mock_server::{MockResponse, MockServer};
+
+    fn auth(url: &str) -> OAuth2Auth {
+        OAuth2Auth {
+            flow: OAuth2Flow::ClientCredentials,
+            token_url: format!("{url}token"),
+            device_authorization_url: None,
+            client_id: "flowrs".to_string(),
+            client_secret: Some("s3cr3t".into()),
+            scopes: vec!["airflow".to_string()],
+            refresh_token: None,
+        }
+    }
+
+    fn bearer(request: RequestBuilder) -> String {
+        request
+            .build()
+            .unwrap()
+            .headers()
+            .get("authorization")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn get() -> RequestBuilder {
+        reqwest::Client::new().get("http://localhost/api/v2/dags")
+    }
+
+    #[tokio::test]
+    async fn fetches_and_caches_token() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                200,
+                &serde_json::json!({"access_token": "tok-1", "expires_in": 3600}),
+            )
+        })
+        .await;
+        let provider =
+            ClientCredentialsProvider::new(reqwest::Client::new(), &auth(&server.url)).unwrap();
+
+        assert_eq!(
+            bearer(provider.authenticate(get()).await.unwrap()),
+            "Bearer tok-1"
+        );
+        assert_eq!(
+            bearer(provider.authenticate(get()).await.unwrap()),
+            "Bearer tok-1"
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let body = &requests[0].body;
+        assert!(body.contains("grant_type=client_credentials"), "{body}");
+        assert!(body.contains("client_secret=s3cr3t"), "{body}");
+        assert!(body.contains("scope=airflow"), "{body}");
+    }
+
+    #[tokio::test]
+    async fn uses_refresh_token_when_expired() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let server = MockServer::start(move |req| {
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            if n > 0 {
+                assert!(
+                    req.body.contains("grant_type=refresh_token"),
+                    "{}",
+                    req.body
+                );
+                assert!(req.body.contains("refresh_token=r-1"), "{}", req.body);
+            }
+            // expires_in below the refresh margin: every request refreshes.
+            MockResponse::json(
+                200,
+                &serde_json::json!({
+                    "access_token": format!("tok-{n}"),
+                    "expires_in": 1,
+                    "refresh_token": "r-1",
+                }),
+            )
+        })
+        .await;
+        let provider =
+            ClientCredentialsProvider::new(reqwest::Client::new(), &auth(&server.url)).unwrap();
+
+        assert_eq!(
+            bearer(provider.authenticate(get()).await.unwrap()),
+            "Bearer tok-0"
+        );
+        assert_eq!(
+            bearer(provider.authenticate(get()).await.unwrap()),
+            "Bearer tok-1"
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn reports_oauth_errors() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                401,
+                &serde_json::json!({
+                    "error": "invalid_client",
+                    "error_description": "Invalid client credentials",
+                }),
+            )
+        })
+        .await;
+        let provider =
+            ClientCredentialsProvider::new(reqwest::Client::new(), &auth(&server.url)).unwrap();
+
+        let err = provider.authenticate(get()).await.unwrap_err().to_string();
+        assert!(err.contains("invalid_client"), "{err}");
+        assert!(err.contains("Invalid client credentials"), "{err}");
+    }
+
+    #[test]
+    fn requires_client_secret() {
+        let mut auth = auth("http://localhost/");
+        auth.client_secret = None;
+        assert!(ClientCredentialsProvider::new(reqwest::Client::new(), &auth).is_err());
+    }
+}
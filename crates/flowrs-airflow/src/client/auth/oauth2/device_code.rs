@@ -0,0 +1,404 @@
+use std::fmt;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use log::{info, warn};
+use reqwest::{RequestBuilder, Url};
+use serde::Deserialize;
+
+use super::{CachedToken, TokenEndpoint, TokenError, TokenResponse};
+use crate::auth::OAuth2Auth;
+use crate::client::auth::AuthProvider;
+use crate::secret::SecretValue;
+
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Polling interval when the provider does not specify one (RFC 8628 §3.2).
+const DEFAULT_POLL_INTERVAL: u64 = 5;
+
+/// The response of the device authorization endpoint: what the user has to
+/// open and enter, and the code flowrs polls with.
+#[derive(Deserialize, Debug)]
+pub struct DeviceAuthorization {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    #[serde(default)]
+    pub verification_uri_complete: Option<String>,
+    pub expires_in: u64,
+    #[serde(default)]
+    pub interval: Option<u64>,
+}
+
+fn device_authorization_url(auth: &OAuth2Auth) -> Result<Url> {
+    let url = auth
+        .device_authorization_url
+        .as_deref()
+        .context("The OAuth2 device-code flow requires a device_authorization_url")?;
+    Url::parse(url).with_context(|| format!("Invalid OAuth2 device_authorization_url '{url}'"))
+}
+
+/// Start a device-code login (RFC 8628). Show the returned user code and
+/// verification URI to the user, then call [`poll_device_token`].
+pub async fn request_device_code(
+    client: &reqwest::Client,
+    auth: &OAuth2Auth,
+) -> Result<DeviceAuthorization> {
+    let url = device_authorization_url(auth)?;
+    let endpoint = TokenEndpoint::new(client.clone(), auth)?;
+    Ok(endpoint.post_form(&url, &[]).await?)
+}
+
+/// Poll the token endpoint until the user has approved the login, it was
+/// denied, or the device code expired.
+pub async fn poll_device_token(
+    client: &reqwest::Client,
+    auth: &OAuth2Auth,
+    device: &DeviceAuthorization,
+) -> Result<TokenResponse> {
+    let endpoint = TokenEndpoint::new(client.clone(), auth)?;
+    let deadline = Instant::now() + Duration::from_secs(device.expires_in);
+    let mut interval = device.interval.unwrap_or(DEFAULT_POLL_INTERVAL);
+
+    loop {
+        let grant = [
+            ("grant_type", DEVICE_CODE_GRANT),
+            ("device_code", device.device_code.as_str()),
+        ];
+        match endpoint.request(&grant).await {
+            Ok(response) => return Ok(response),
+            Err(TokenError::OAuth { code, .. }) if code == "authorization_pending" => {}
+            Err(TokenError::OAuth { code, .. }) if code == "slow_down" => interval += 5,
+            Err(e) => return Err(e.into()),
+        }
+        if Instant::now() >= deadline {
+            anyhow::bail!("The device code expired before the login was approved");
+        }
+        tokio::time::sleep(Duration::from_secs(interval)).await;
+    }
+}
+
+/// Writes a rotated refresh token of the named server back to the config
+/// file, for refresh tokens stored there in plain text.
+pub type RefreshTokenWriter = Box<dyn Fn(&str, &str) -> Result<()> + Send + Sync>;
+
+static REFRESH_TOKEN_WRITER: OnceLock<RefreshTokenWriter> = OnceLock::new();
+
+/// Set how rotated refresh tokens stored in the config file are written back.
+/// The application owns the config file, so this crate cannot do it itself.
+/// Only the first writer set is used.
+pub fn set_refresh_token_writer(writer: RefreshTokenWriter) {
+    if REFRESH_TOKEN_WRITER.set(writer).is_err() {
+        warn!("A refresh token writer is already set");
+    }
+}
+
+struct State {
+    token: Option<CachedToken>,
+    refresh_token: String,
+}
+
+/// OAuth 2.0 device-code flow at runtime.
+///
+/// The interactive part of the login happens once in `flowrs config add`;
+/// from then on this provider only redeems the stored refresh token. A
+/// rotated refresh token is written back to the keyring or the config file
+/// it came from.
+pub struct DeviceCodeProvider {
+    endpoint: TokenEndpoint,
+    /// Name of the server, to find it in the config file
+    server: String,
+    /// Where the refresh token is stored
+    refresh_store: SecretValue,
+    state: tokio::sync::Mutex<State>,
+}
+
+impl DeviceCodeProvider {
+    pub fn new(client: reqwest::Client, server: &str, auth: &OAuth2Auth) -> Result<Self> {
+        let stored = auth
+            .refresh_token
+            .as_ref()
+            .context("No OAuth2 refresh token stored; log in with `flowrs config update` first")?;
+        let refresh_token = stored
+            .resolve()
+            .context("Failed to resolve OAuth2 refresh token")?;
+        Ok(Self {
+            endpoint: TokenEndpoint::new(client, auth)?,
+            server: server.to_string(),
+            refresh_store: stored.clone(),
+            state: tokio::sync::Mutex::new(State {
+                token: None,
+                refresh_token,
+            }),
+        })
+    }
+
+    /// Persist a rotated refresh token so the next session can still log in.
+    /// The identity provider may revoke the previous one, so a token that
+    /// comes from an environment variable or command has to be updated by
+    /// the user.
+    async fn store_refresh_token(&self, refresh_token: &str) {
+        let refresh_token = refresh_token.to_string();
+        let server = self.server.clone();
+        let stored = match self.refresh_store.clone() {
+            SecretValue::Keyring { keyring, backend } => {
+                tokio::task::spawn_blocking(move || {
+                    store_in_keyring(&keyring, &refresh_token, backend)
+                })
+                .await
+            }
+            SecretValue::Plain(_) => {
+                tokio::task::spawn_blocking(move || match REFRESH_TOKEN_WRITER.get() {
+                    Some(write) => write(&server, &refresh_token),
+                    None => anyhow::bail!("no refresh token writer is set"),
+                })
+                .await
+            }
+            SecretValue::Env { env } => {
+                warn!(
+                    "The OAuth2 refresh token of '{server}' was rotated, but it comes from \
+                     the environment variable {env}, which flowrs cannot update. Log in again \
+                     with `flowrs config update` before the next start."
+                );
+                return;
+            }
+            SecretValue::Command { cmd } => {
+                warn!(
+                    "The OAuth2 refresh token of '{server}' was rotated, but it comes from \
+                     the command `{cmd}`, which flowrs cannot update. Log in again with \
+                     `flowrs config update` before the next start."
+                );
+                return;
+            }
+        };
+        match stored {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => warn!("Failed to store rotated OAuth2 refresh token: {e:#}"),
+            Err(e) => warn!("Failed to store rotated OAuth2 refresh token: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "keyring")]
+fn store_in_keyring(key: &str, value: &str, backend: crate::KeyringBackend) -> Result<()> {
+    crate::keyring::set_secret(key, value, backend)
+}
+
+#[cfg(not(feature = "keyring"))]
+fn store_in_keyring(_key: &str, _value: &str, _backend: crate::KeyringBackend) -> Result<()> {
+    anyhow::bail!("Keyring support not compiled. Enable the 'keyring' feature.")
+}
+
+impl fmt::Debug for DeviceCodeProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeviceCodeProvider")
+            .field("token_url", &self.endpoint.token_url.as_str())
+            .field("client_id", &self.endpoint.client_id)
+            .field("server", &self.server)
+            .field("refresh_store", &self.refresh_store)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl AuthProvider for DeviceCodeProvider {
+    async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        let mut state = self.state.lock().await;
+
+        if !state.token.as_ref().is_some_and(CachedToken::is_fresh) {
+            info!(
+                "🔑 OAuth2 Auth: refreshing token for {}",
+                self.endpoint.client_id
+            );
+            let now = SystemTime::now();
+            let response = self.endpoint.refresh(&state.refresh_token).await.context(
+                "OAuth2 session expired or was revoked; log in again with `flowrs config update`",
+            )?;
+            state.token = Some(CachedToken::new(&response, now));
+            if let Some(rotated) = response.refresh_token {
+                if rotated != state.refresh_token {
+                    self.store_refresh_token(&rotated).await;
+                    state.refresh_token = rotated;
+                }
+            }
+        }
+
+        let token = state.token.as_ref().expect("token cached above");
+        Ok(request.bearer_auth(&token.access_token))
+    }
+
+    async fn invalidate(&self) -> bool {
+        self.state.lock().await.token = None;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::auth::OAuth2Flow;
+    use crate::mock_server::{MockResponse, MockServer};
+
+    fn auth(url: &str) -> OAuth2Auth {
+        OAuth2Auth {
+            flow: OAuth2Flow::DeviceCode,
+            token_url: format!("{url}token"),
+            device_authorization_url: Some(format!("{url}device")),
+            client_id: "flowrs".to_string(),
+            client_secret: None,
+            scopes: vec!["openid".to_string(), "offline_access".to_string()],
+            refresh_token: Some("r-0".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn device_login_polls_until_approved() {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&polls);
+        let server = MockServer::start(move |req| match req.path.as_str() {
+            "/device" => MockResponse::json(
+                200,
+                &serde_json::json!({
+                    "device_code": "dev-123",
+                    "user_code": "ABCD-EFGH",
+                    "verification_uri": "https://sso.example.com/device",
+                    "expires_in": 600,
+                    "interval": 0,
+                }),
+            ),
+            _ if counter.fetch_add(1, Ordering::SeqCst) < 2 => {
+                MockResponse::json(400, &serde_json::json!({"error": "authorization_pending"}))
+            }
+            _ => MockResponse::json(
+                200,
+                &serde_json::json!({
+                    "access_token": "tok",
+                    "expires_in": 300,
+                    "refresh_token": "r-1",
+                }),
+            ),
+        })
+        .await;
+        let client = reqwest::Client::new();
+        let auth = auth(&server.url);
+
+        let device = request_device_code(&client, &auth).await.unwrap();
+        assert_eq!(device.user_code, "ABCD-EFGH");
+        let token = poll_device_token(&client, &auth, &device).await.unwrap();
+        assert_eq!(token.refresh_token.as_deref(), Some("r-1"));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[0].body.contains("scope=openid+offline_access"));
+        assert!(requests[3].body.contains("device_code=dev-123"));
+    }
+
+    #[tokio::test]
+    async fn device_login_fails_when_denied() {
+        let server = MockServer::start(|req| match req.path.as_str() {
+            "/device" => MockResponse::json(
+                200,
+                &serde_json::json!({
+                    "device_code": "dev-123",
+                    "user_code": "ABCD-EFGH",
+                    "verification_uri": "https://sso.example.com/device",
+                    "expires_in": 600,
+                    "interval": 0,
+                }),
+            ),
+            _ => MockResponse::json(400, &serde_json::json!({"error": "access_denied"})),
+        })
+        .await;
+        let client = reqwest::Client::new();
+        let auth = auth(&server.url);
+
+        let device = request_device_code(&client, &auth).await.unwrap();
+        let err = poll_device_token(&client, &auth, &device)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("access_denied"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn provider_redeems_refresh_token_and_keeps_rotated_one() {
+        let server = MockServer::start(|req| {
+            let n = if req.body.contains("refresh_token=r-0") {
+                1
+            } else {
+                2
+            };
+            MockResponse::json(
+                200,
+                &serde_json::json!({
+                    "access_token": format!("tok-{n}"),
+                    "expires_in": 1,
+                    "refresh_token": format!("r-{n}"),
+                }),
+            )
+        })
+        .await;
+        let provider =
+            DeviceCodeProvider::new(reqwest::Client::new(), "prod", &auth(&server.url)).unwrap();
+        let get = || reqwest::Client::new().get("http://localhost/api/v2/dags");
+        let bearer = |request: RequestBuilder| {
+            request.build().unwrap().headers()["authorization"]
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(
+            bearer(provider.authenticate(get()).await.unwrap()),
+            "Bearer tok-1"
+        );
+        // The first response rotated the refresh token to r-1.
+        assert_eq!(
+            bearer(provider.authenticate(get()).await.unwrap()),
+            "Bearer tok-2"
+        );
+        assert!(server.requests()[1].body.contains("refresh_token=r-1"));
+    }
+
+    #[tokio::test]
+    async fn plain_rotated_refresh_token_is_written_back() {
+        static WRITTEN: std::sync::Mutex<Vec<(String, String)>> = std::sync::Mutex::new(Vec::new());
+        set_refresh_token_writer(Box::new(|server, refresh_token| {
+            WRITTEN
+                .lock()
+                .unwrap()
+                .push((server.to_string(), refresh_token.to_string()));
+            Ok(())
+        }));
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                200,
+                &serde_json::json!({
+                    "access_token": "tok",
+                    "expires_in": 300,
+                    "refresh_token": "r-1",
+                }),
+            )
+        })
+        .await;
+        let provider =
+            DeviceCodeProvider::new(reqwest::Client::new(), "staging", &auth(&server.url)).unwrap();
+        let request = reqwest::Client::new().get("http://localhost/api/v2/dags");
+        assert!(provider.authenticate(request).await.is_ok());
+
+        let written = WRITTEN.lock().unwrap();
+        assert!(written.contains(&("staging".to_string(), "r-1".to_string())));
+    }
+
+    #[test]
+    fn provider_requires_refresh_token() {
+        let mut auth = auth("http://localhost/");
+        auth.refresh_token = None;
+        assert!(DeviceCodeProvider::new(reqwest::Client::new(), "prod", &auth).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! OAuth 2.0 / OIDC token acquisition against an external identity provider.
+//!
+//! [`TokenEndpoint`] speaks the token endpoint protocol (RFC 6749) shared by
+//! both providers; the grant-specific logic lives in the submodules.
+
+mod client_credentials;
+mod device_code;
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result};
+use reqwest::Url;
+use serde::Deserialize;
+
+use crate::auth::OAuth2Auth;
+
+pub use client_credentials::ClientCredentialsProvider;
+pub use device_code::{
+    poll_device_token, request_device_code, set_refresh_token_writer, DeviceAuthorization,
+    DeviceCodeProvider, RefreshTokenWriter,
+};
+
+/// Fetch a new access token this long before the current one expires.
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// Lifetime assumed for tokens whose response carries no `expires_in`.
+const DEFAULT_TOKEN_TTL: Duration = Duration::from_secs(300);
+
+/// A successful token endpoint response.
+#[derive(Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+    #[serde(default)]
+    pub refresh_token: Option<String>,
+}
+
+impl fmt::Debug for TokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenResponse")
+            .field("access_token", &"***redacted***")
+            .field("expires_in", &self.expires_in)
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| "***redacted***"),
+            )
+            .finish()
+    }
+}
+
+/// An OAuth 2.0 error response, e.g. `{"error": "invalid_grant"}`.
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// Why a token request failed: an OAuth 2.0 error code, or anything else.
+#[derive(Debug)]
+enum TokenError {
+    OAuth { code: String, description: String },
+    Other(anyhow::Error),
+}
+
+impl From<TokenError> for anyhow::Error {
+    fn from(error: TokenError) -> Self {
+        match error {
+            TokenError::OAuth { code, description } if description.is_empty() => {
+                anyhow::anyhow!("OAuth2 token request failed: {code}")
+            }
+            TokenError::OAuth { code, description } => {
+                anyhow::anyhow!("OAuth2 token request failed: {code} ({description})")
+            }
+            TokenError::Other(e) => e,
+        }
+    }
+}
+
+/// The token endpoint of an identity provider, with resolved client credentials.
+pub(crate) struct TokenEndpoint {
+    client: reqwest::Client,
+    token_url: Url,
+    client_id: String,
+    client_secret: Option<String>,
+    scopes: Vec<String>,
+}
+
+impl TokenEndpoint {
+    pub(crate) fn new(client: reqwest::Client, auth: &OAuth2Auth) -> Result<Self> {
+        let client_secret = auth
+            .client_secret
+            .as_ref()
+            .map(crate::secret::SecretValue::resolve)
+            .transpose()
+            .context("Failed to resolve OAuth2 client secret")?;
+        Ok(Self {
+            client,
+            token_url: Url::parse(&auth.token_url)
+                .with_context(|| format!("Invalid OAuth2 token_url '{}'", auth.token_url))?,
+            client_id: auth.client_id.clone(),
+            client_secret,
+            scopes: auth.scopes.clone(),
+        })
+    }
+
+    /// Client authentication and scope parameters sent with every request.
+    fn client_params(&self) -> Vec<(&str, &str)> {
+        let mut params = vec![("client_id", self.client_id.as_str())];
+        if let Some(secret) = &self.client_secret {
+            params.push(("client_secret", secret.as_str()));
+        }
+        params
+    }
+
+    fn scope(&self) -> Option<String> {
+        (!self.scopes.is_empty()).then(|| self.scopes.join(" "))
+    }
+
+    /// POST a form to `url` and decode an OAuth 2.0 success or error response.
+    async fn post_form<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &Url,
+        grant: &[(&str, &str)],
+    ) -> Result<T, TokenError> {
+        let mut form = self.client_params();
+        form.extend_from_slice(grant);
+        let scope = self.scope();
+        if let Some(scope) = &scope {
+            form.push(("scope", scope));
+        }
+
+        let response = self
+            .client
+            .post(url.clone())
+            .form(&form)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach {url}"))
+            .map_err(TokenError::Other)?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .context("Failed to read OAuth2 response")
+            .map_err(TokenError::Other)?;
+        if status.is_success() {
+            return serde_json::from_str(&body)
+                .with_context(|| format!("Unexpected OAuth2 response from {url}"))
+                .map_err(TokenError::Other);
+        }
+        match serde_json::from_str::<ErrorResponse>(&body) {
+            Ok(error) => Err(TokenError::OAuth {
+                code: error.error,
+                description: error.error_description.unwrap_or_default(),
+            }),
+            Err(_) => Err(TokenError::Other(anyhow::anyhow!(
+                "OAuth2 request to {url} failed: {status} {}",
+                body.trim()
+            ))),
+        }
+    }
+
+    async fn request(&self, grant: &[(&str, &str)]) -> Result<TokenResponse, TokenError> {
+        self.post_form(&self.token_url, grant).await
+    }
+
+    pub(crate) async fn client_credentials(&self) -> Result<TokenResponse> {
+        Ok(self
+            .request(&[("grant_type", "client_credentials")])
+            .await?)
+    }
+
+    pub(crate) async fn refresh(&self, refresh_token: &str) -> Result<TokenResponse> {
+        Ok(self
+            .request(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token),
+            ])
+            .await?)
+    }
+}
+
+/// An access token and when to replace it.
+struct CachedToken {
+    access_token: String,
+    refresh_at: SystemTime,
+}
+
+impl CachedToken {
+    fn new(response: &TokenResponse, now: SystemTime) -> Self {
+        let ttl = response
+            .expires_in
+            .map_or(DEFAULT_TOKEN_TTL, Duration::from_secs);
+        Self {
+            access_token: response.access_token.clone(),
+            refresh_at: now + ttl.saturating_sub(REFRESH_MARGIN),
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        SystemTime::now() < self.refresh_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_token_refreshes_before_expiry() {
+        let now = SystemTime::UNIX_EPOCH;
+        let token = |expires_in| {
+            CachedToken::new(
+                &TokenResponse {
+                    access_token: "tok".to_string(),
+                    expires_in,
+                    refresh_token: None,
+                },
+                now,
+            )
+        };
+        assert_eq!(token(Some(300)).refresh_at, now + Duration::from_secs(270));
+        assert_eq!(token(Some(10)).refresh_at, now);
+        assert_eq!(
+            token(None).refresh_at,
+            now + DEFAULT_TOKEN_TTL - REFRESH_MARGIN
+        );
+    }
+}
@@ -1,12 +1,21 @@
-use anyhow::Result;
-use log::{debug, warn};
-use reqwest::{Method, Url};
+use anyhow::{Context, Result};
+use log::{debug, info, warn};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{
+    Certificate, Identity, Method, NoProxy, Proxy, Request, RequestBuilder, Response, Url,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::fmt;
+use std::path::Path;
 use std::time::Duration;
 
 use super::auth::{create_auth_provider, AuthProvider};
-use crate::config::AirflowConfig;
+use super::error::AirflowApiError;
+use crate::config::{AirflowConfig, ProxyConfig};
+use crate::secret::{expand_env_vars, SecretValue};
 
 /// Base HTTP client for Airflow API communication.
 /// Handles authentication and provides base request building functionality.
@@ -14,6 +23,47 @@ pub struct BaseClient {
     pub client: reqwest::Client,
     pub config: AirflowConfig,
     auth_provider: Box<dyn AuthProvider>,
+    retry: RetryPolicy,
+}
+
+/// How often idempotent requests are repeated after a transient failure
+/// (429, 502, 503, 504 or a connection error), with exponential backoff.
+///
+/// A `Retry-After` header from the server takes precedence over the
+/// backoff, up to `max_delay`.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// No retries at all.
+    pub const fn none() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    /// The delay before retry number `attempt` (starting at 0).
+    fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        retry_after
+            .unwrap_or_else(|| self.base_delay.saturating_mul(2u32.saturating_pow(attempt)))
+            .min(self.max_delay)
+    }
 }
 
 impl fmt::Debug for BaseClient {
@@ -22,46 +72,274 @@ impl fmt::Debug for BaseClient {
             .field("client", &self.client)
             .field("config", &self.config)
             .field("auth_provider", &"<AuthProvider>")
+            .field("retry", &self.retry)
             .finish()
     }
 }
 
+/// Build the HTTP client for a server: timeout, proxy and TLS settings
+/// (extra CA certificates, client certificate for mutual TLS, or no
+/// verification), and the configured extra headers, which every request
+/// sends, including those for logging in.
+pub fn http_client(config: &AirflowConfig) -> Result<reqwest::Client> {
+    if config.insecure {
+        warn!(
+            "TLS certificate verification is disabled for server '{}'",
+            config.name
+        );
+    }
+    let mut builder = reqwest::Client::builder()
+        .default_headers(resolve_headers(&config.headers)?)
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .use_rustls_tls()
+        .danger_accept_invalid_certs(config.insecure);
+
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(build_proxy(proxy)?);
+    }
+
+    if let Some(path) = &config.ca_cert {
+        let pem = read_pem(path, "ca_cert")?;
+        let certificates = Certificate::from_pem_bundle(&pem)
+            .with_context(|| format!("Invalid PEM in ca_cert {}", path.display()))?;
+        if certificates.is_empty() {
+            anyhow::bail!("ca_cert {} contains no certificates", path.display());
+        }
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    match (&config.client_cert, &config.client_key) {
+        (Some(cert_path), key_path) => {
+            let mut pem = read_pem(cert_path, "client_cert")?;
+            if let Some(key_path) = key_path {
+                pem.push(b'\n');
+                pem.extend(read_pem(key_path, "client_key")?);
+            }
+            let identity = Identity::from_pem(&pem).with_context(|| {
+                format!(
+                    "Invalid client certificate or key in {}",
+                    cert_path.display()
+                )
+            })?;
+            builder = builder.identity(identity);
+        }
+        (None, Some(_)) => anyhow::bail!("client_key is set but client_cert is not"),
+        (None, None) => {}
+    }
+
+    Ok(builder.build()?)
+}
+
+fn build_proxy(config: &ProxyConfig) -> Result<Proxy> {
+    let url = expand_env_vars(&config.url)?;
+    let proxy = Proxy::all(&url).context("Invalid proxy url")?;
+    Ok(proxy.no_proxy(config.no_proxy.as_deref().and_then(NoProxy::from_string)))
+}
+
+/// Resolve the configured headers into a `HeaderMap`. Values are marked
+/// sensitive so they are never logged.
+fn resolve_headers(headers: &BTreeMap<String, SecretValue>) -> Result<HeaderMap> {
+    let mut resolved = HeaderMap::new();
+    for (name, value) in headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .with_context(|| format!("Invalid header name '{name}'"))?;
+        let value = value
+            .resolve()
+            .with_context(|| format!("Failed to resolve header '{name}'"))?;
+        let mut header_value = HeaderValue::from_str(&value)
+            .with_context(|| format!("Invalid value for header '{name}'"))?;
+        header_value.set_sensitive(true);
+        resolved.insert(header_name, header_value);
+    }
+    Ok(resolved)
+}
+
+fn read_pem(path: &Path, field: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).with_context(|| format!("Failed to read {field} {}", path.display()))
+}
+
 impl BaseClient {
     pub fn new(config: AirflowConfig) -> Result<Self> {
-        if config.insecure {
-            warn!(
-                "TLS certificate verification is disabled for server '{}'",
-                config.name
-            );
-        }
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(config.timeout_secs))
-            .use_rustls_tls()
-            .danger_accept_invalid_certs(config.insecure)
-            .build()?;
+        let client = http_client(&config)?;
 
-        let auth_provider = create_auth_provider(&config.auth)?;
+        let auth_provider = create_auth_provider(&config, &client)?;
 
         Ok(Self {
             client,
             config,
             auth_provider,
+            retry: RetryPolicy::default(),
         })
     }
 
-    /// Build a base request with authentication for the specified API version
-    pub async fn base_api(
+    /// [`BaseClient::new`] on the blocking pool, since resolving `{ cmd = .. }`
+    /// secrets runs a shell command.
+    pub async fn connect(config: AirflowConfig) -> Result<Self> {
+        tokio::task::spawn_blocking(move || Self::new(config)).await?
+    }
+
+    /// Replace the default [`RetryPolicy`].
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Build a request to `endpoint` of the specified API version.
+    /// Authentication is added when the request is sent.
+    pub fn base_api(
         &self,
         method: Method,
         endpoint: &str,
         api_version: &str,
-    ) -> Result<reqwest::RequestBuilder> {
+    ) -> Result<ApiRequest<'_>> {
         let base_url = Url::parse(&self.config.endpoint)?;
         let url = base_url.join(format!("{api_version}/{endpoint}").as_str())?;
         debug!("🔗 Request URL: {url}");
 
-        let request = self.client.request(method, url);
-        self.auth_provider.authenticate(request).await
+        let builder = self.client.request(method, url);
+        Ok(ApiRequest {
+            base: self,
+            builder,
+        })
+    }
+
+    /// Authenticate and send `request`, turning unsuccessful statuses into an
+    /// [`AirflowApiError`]. When the server rejects the credentials (401 or
+    /// 403) and the auth provider can renew them, the request is sent once
+    /// more. Expired MWAA and Composer sessions come back as 403, so both
+    /// count; with fixed credentials either status is final.
+    async fn execute(&self, request: &Request) -> Result<Response> {
+        let error = match self.execute_once(clone_request(request)?).await {
+            Ok(response) => return Ok(response),
+            Err(error) => error,
+        };
+        let rejected = matches!(
+            error.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::Unauthorized { .. } | AirflowApiError::Forbidden { .. })
+        );
+        if !rejected || !self.auth_provider.invalidate().await {
+            return Err(error);
+        }
+        info!(
+            "🔑 Credentials for '{}' were rejected; retrying with fresh ones",
+            self.config.name
+        );
+        self.execute_once(clone_request(request)?).await
+    }
+
+    async fn execute_once(&self, request: Request) -> Result<Response> {
+        let builder = RequestBuilder::from_parts(self.client.clone(), request);
+        let response = self
+            .auth_provider
+            .authenticate(builder)
+            .await?
+            .send()
+            .await
+            .map_err(AirflowApiError::from)?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let url = response.url().clone();
+        let headers = response.headers().clone();
+        let body = response.text().await.unwrap_or_default();
+        Err(AirflowApiError::from_status(url, status, &headers, &body).into())
+    }
+}
+
+/// Copy `request` for another attempt; API requests never have streaming bodies.
+fn clone_request(request: &Request) -> Result<Request> {
+    request
+        .try_clone()
+        .context("Failed to clone request for retrying")
+}
+
+/// A request to the Airflow REST API, built by [`BaseClient::base_api`].
+///
+/// `send` authenticates the request, retries idempotent ones according to the
+/// client's [`RetryPolicy`], and reports failures as [`AirflowApiError`].
+#[derive(Debug)]
+#[must_use = "requests do nothing unless sent"]
+pub struct ApiRequest<'a> {
+    base: &'a BaseClient,
+    builder: RequestBuilder,
+}
+
+impl ApiRequest<'_> {
+    pub fn query<T: Serialize + ?Sized>(self, query: &T) -> Self {
+        Self {
+            builder: self.builder.query(query),
+            ..self
+        }
+    }
+
+    pub fn json<T: Serialize + ?Sized>(self, json: &T) -> Self {
+        Self {
+            builder: self.builder.json(json),
+            ..self
+        }
+    }
+
+    pub fn header(self, name: &str, value: &str) -> Self {
+        Self {
+            builder: self.builder.header(name, value),
+            ..self
+        }
+    }
+
+    /// Send the request and return the successful response.
+    pub async fn send(self) -> Result<Response> {
+        let base = self.base;
+        let request = self.builder.build().map_err(AirflowApiError::from)?;
+        if !matches!(*request.method(), Method::GET | Method::HEAD) {
+            return base.execute(&request).await;
+        }
+
+        let mut attempt = 0;
+        loop {
+            let error = match base.execute(&request).await {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+            let Some(api_error) = error.downcast_ref::<AirflowApiError>() else {
+                return Err(error);
+            };
+            if attempt >= base.retry.max_retries || !api_error.is_transient() {
+                return Err(error);
+            }
+            let retry_after = match api_error {
+                AirflowApiError::RateLimited { retry_after, .. } => *retry_after,
+                _ => None,
+            };
+            let delay = base.retry.delay(attempt, retry_after);
+            warn!(
+                "{api_error}; retrying in {}ms ({}/{})",
+                delay.as_millis(),
+                attempt + 1,
+                base.retry.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send the request and decode its JSON body. A body that does not match
+    /// `T` is reported with a snippet of it.
+    pub async fn send_json<T: DeserializeOwned>(self) -> Result<T> {
+        let response = self.send().await?;
+        let url = response.url().clone();
+        let body = response.text().await.map_err(AirflowApiError::from)?;
+        serde_json::from_str(&body).map_err(|e| AirflowApiError::decode(url, &e, &body).into())
+    }
+
+    /// Send the request and return its body as text.
+    pub async fn send_text(self) -> Result<String> {
+        let response = self.send().await?;
+        Ok(response.text().await.map_err(AirflowApiError::from)?)
     }
 }
 
@@ -72,3 +350,448 @@ impl TryFrom<&AirflowConfig> for BaseClient {
         Self::new(config.clone())
     }
 }
+
+/// A server with a static token, for tests to adjust
+#[cfg(test)]
+pub(crate) fn config() -> AirflowConfig {
+    use crate::auth::{AirflowAuth, TokenSource};
+    use crate::config::AirflowVersion;
+
+    AirflowConfig {
+        name: "test".to_string(),
+        endpoint: "https://localhost:8443".to_string(),
+        auth: AirflowAuth::Token(TokenSource::Static {
+            token: "tok".into(),
+        }),
+        managed: None,
+        version: AirflowVersion::V3,
+        timeout_secs: 5,
+        insecure: false,
+        ca_cert: None,
+        client_cert: None,
+        client_key: None,
+        proxy: None,
+        headers: BTreeMap::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::auth::{AirflowAuth, BasicAuth, TokenSource};
+    use crate::mock_server::{MockResponse, MockServer};
+
+    fn testdata(file: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata/tls")
+            .join(file)
+    }
+
+    #[tokio::test]
+    async fn sends_configured_headers() {
+        let server = MockServer::start(|_| MockResponse::new(200, "{}")).await;
+        let config = AirflowConfig {
+            endpoint: server.url.clone(),
+            headers: BTreeMap::from([
+                ("X-Api-Key".to_string(), "k3y".into()),
+                (
+                    "X-Tenant".to_string(),
+                    SecretValue::Command {
+                        cmd: "echo analytics".to_string(),
+                    },
+                ),
+            ]),
+            ..config()
+        };
+        let base = BaseClient::new(config).unwrap();
+        base.base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.header("x-tenant"), Some("analytics"));
+        assert_eq!(request.header("x-api-key"), Some("k3y"));
+        assert!(!format!("{base:?}").contains("k3y"));
+    }
+
+    #[tokio::test]
+    async fn sends_configured_headers_when_logging_in() {
+        let server = MockServer::start(|req| {
+            if req.path == "/auth/token" {
+                MockResponse::json(201, &serde_json::json!({"access_token": "jwt"}))
+            } else {
+                MockResponse::new(200, "{}")
+            }
+        })
+        .await;
+        let config = AirflowConfig {
+            endpoint: server.url.clone(),
+            auth: AirflowAuth::Jwt(BasicAuth {
+                username: "airflow".to_string(),
+                password: "airflow".into(),
+            }),
+            headers: BTreeMap::from([("X-Api-Key".to_string(), "k3y".into())]),
+            ..config()
+        };
+        let base = BaseClient::new(config).unwrap();
+        base.base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/auth/token");
+        assert!(requests
+            .iter()
+            .all(|request| request.header("x-api-key") == Some("k3y")));
+    }
+
+    /// A client for `server` that retries quickly.
+    fn retrying_client(server: &MockServer) -> BaseClient {
+        let config = AirflowConfig {
+            endpoint: server.url.clone(),
+            ..config()
+        };
+        BaseClient::new(config)
+            .unwrap()
+            .with_retry_policy(RetryPolicy {
+                max_retries: 2,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(10),
+            })
+    }
+
+    /// A server answering with `failures` before succeeding.
+    async fn flaky_server(failures: Vec<MockResponse>) -> MockServer {
+        let failures = std::sync::Mutex::new(failures.into_iter());
+        MockServer::start(move |_| {
+            failures
+                .lock()
+                .unwrap()
+                .next()
+                .unwrap_or_else(|| MockResponse::json(200, &serde_json::json!({"ok": true})))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn retries_transient_get_failures() {
+        let server = flaky_server(vec![
+            MockResponse::new(503, "unavailable"),
+            MockResponse::new(429, "slow down").with_header("Retry-After", "0"),
+        ])
+        .await;
+        let base = retrying_client(&server);
+
+        let body: serde_json::Value = base
+            .base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send_json()
+            .await
+            .unwrap();
+        assert_eq!(body["ok"], true);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() {
+        let server = MockServer::start(|_| MockResponse::new(502, "bad gateway")).await;
+        let base = retrying_client(&server);
+
+        let err = base
+            .base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::Server { status, .. }) if *status == reqwest::StatusCode::BAD_GATEWAY
+        ));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_writes_or_permanent_errors() {
+        let server = flaky_server(vec![
+            MockResponse::new(503, "unavailable"),
+            MockResponse::json(404, &serde_json::json!({"detail": "DAG not found"})),
+        ])
+        .await;
+        let base = retrying_client(&server);
+
+        let err = base
+            .base_api(Method::PATCH, "dags/x", "api/v2")
+            .unwrap()
+            .json(&serde_json::json!({"is_paused": true}))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::Server { .. })
+        ));
+
+        let err = base
+            .base_api(Method::GET, "dags/x", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::NotFound { detail, .. }) if detail == "DAG not found"
+        ));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn reports_unexpected_json_with_body_snippet() {
+        let server = MockServer::start(|_| MockResponse::new(200, r#"{"dags": "nope"}"#)).await;
+        let base = retrying_client(&server);
+
+        let err = base
+            .base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send_json::<Vec<String>>()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::Decode { snippet, .. }) if snippet == r#"{"dags": "nope"}"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn renews_rejected_credentials_once() {
+        // The helper prints how often it ran, so each fetch yields a new token.
+        let marker = std::env::temp_dir().join(format!("flowrs-reauth-{}", std::process::id()));
+        let _ = std::fs::remove_file(&marker);
+        let server = MockServer::start(|req| match req.header("authorization") {
+            Some("Bearer 2") => MockResponse::json(200, &serde_json::json!({"ok": true})),
+            _ => MockResponse::json(401, &serde_json::json!({"detail": "Token expired"})),
+        })
+        .await;
+        let config = AirflowConfig {
+            endpoint: server.url.clone(),
+            auth: AirflowAuth::Token(TokenSource::Command {
+                cmd: format!("echo run >> {0}; wc -l < {0}", marker.display()),
+            }),
+            ..config()
+        };
+        let base = BaseClient::new(config).unwrap();
+
+        let response = base
+            .base_api(Method::PATCH, "dags/x", "api/v2")
+            .unwrap()
+            .json(&serde_json::json!({"is_paused": true}))
+            .send()
+            .await;
+        let _ = std::fs::remove_file(&marker);
+        response.unwrap();
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].body, r#"{"is_paused":true}"#);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_rejected_fixed_credentials() {
+        let server = MockServer::start(|_| MockResponse::new(401, "unauthorized")).await;
+        let base = retrying_client(&server);
+
+        let err = base
+            .base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::Unauthorized { .. })
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn renews_forbidden_credentials_once() {
+        let marker = std::env::temp_dir().join(format!("flowrs-reauth-403-{}", std::process::id()));
+        let _ = std::fs::remove_file(&marker);
+        let server = MockServer::start(|req| match req.header("authorization") {
+            Some("Bearer 2") => MockResponse::json(200, &serde_json::json!({"ok": true})),
+            _ => MockResponse::new(403, "forbidden"),
+        })
+        .await;
+        let config = AirflowConfig {
+            endpoint: server.url.clone(),
+            auth: AirflowAuth::Token(TokenSource::Command {
+                cmd: format!("echo run >> {0}; wc -l < {0}", marker.display()),
+            }),
+            ..config()
+        };
+        let base = BaseClient::new(config).unwrap();
+
+        let response = base
+            .base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await;
+        let _ = std::fs::remove_file(&marker);
+        response.unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retries_forbidden_renewable_credentials_only_once() {
+        let server = MockServer::start(|_| MockResponse::new(403, "forbidden")).await;
+        let config = AirflowConfig {
+            endpoint: server.url.clone(),
+            auth: AirflowAuth::Token(TokenSource::Command {
+                cmd: "echo tok".to_string(),
+            }),
+            ..config()
+        };
+        let base = BaseClient::new(config).unwrap();
+
+        let err = base
+            .base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::Forbidden { .. })
+        ));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_forbidden_fixed_credentials() {
+        let server = MockServer::start(|_| MockResponse::new(403, "forbidden")).await;
+        let base = retrying_client(&server);
+
+        let err = base
+            .base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AirflowApiError>(),
+            Some(AirflowApiError::Forbidden { .. })
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn backoff_doubles_and_honours_retry_after() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+        };
+        assert_eq!(policy.delay(0, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, None), Duration::from_millis(400));
+        assert_eq!(policy.delay(10, None), Duration::from_secs(1));
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_millis(700))),
+            Duration::from_millis(700)
+        );
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(60))),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_header_names() {
+        let config = AirflowConfig {
+            headers: BTreeMap::from([("bad header".to_string(), "x".into())]),
+            ..config()
+        };
+        assert!(BaseClient::new(config).is_err());
+    }
+
+    #[tokio::test]
+    async fn routes_requests_through_proxy() {
+        // An HTTP proxy receives the absolute target URL in the request line.
+        let proxy = MockServer::start(|_| MockResponse::new(200, "{}")).await;
+        let config = AirflowConfig {
+            endpoint: "http://airflow.internal.example.com/".to_string(),
+            proxy: Some(ProxyConfig {
+                url: proxy.url.clone(),
+                no_proxy: None,
+            }),
+            ..config()
+        };
+        let base = BaseClient::new(config).unwrap();
+        base.base_api(Method::GET, "dags", "api/v2")
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            proxy.requests()[0].path,
+            "http://airflow.internal.example.com/api/v2/dags"
+        );
+    }
+
+    #[test]
+    fn accepts_socks_proxy() {
+        let config = AirflowConfig {
+            proxy: Some(ProxyConfig {
+                url: "socks5h://localhost:1080".to_string(),
+                no_proxy: Some("localhost,.internal".to_string()),
+            }),
+            ..config()
+        };
+        assert!(http_client(&config).is_ok());
+    }
+
+    #[test]
+    fn loads_ca_bundle_and_client_identity() {
+        let config = AirflowConfig {
+            ca_cert: Some(testdata("ca.pem")),
+            client_cert: Some(testdata("client.pem")),
+            client_key: Some(testdata("client.key")),
+            ..config()
+        };
+        assert!(http_client(&config).is_ok());
+    }
+
+    #[test]
+    fn missing_ca_file_names_the_field() {
+        let config = AirflowConfig {
+            ca_cert: Some(testdata("missing.pem")),
+            ..config()
+        };
+        let err = http_client(&config).unwrap_err().to_string();
+        assert!(err.contains("ca_cert"), "{err}");
+        assert!(err.contains("missing.pem"), "{err}");
+    }
+
+    #[test]
+    fn rejects_key_without_certificate() {
+        let config = AirflowConfig {
+            client_key: Some(testdata("client.key")),
+            ..config()
+        };
+        assert!(http_client(&config).is_err());
+    }
+
+    #[test]
+    fn rejects_certificate_without_key() {
+        let config = AirflowConfig {
+            client_cert: Some(testdata("client.pem")),
+            ..config()
+        };
+        let err = http_client(&config).unwrap_err().to_string();
+        assert!(err.contains("Invalid client certificate"), "{err}");
+    }
+}
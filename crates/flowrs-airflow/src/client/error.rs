@@ -0,0 +1,326 @@
+//! Typed errors for failed Airflow REST API requests.
+
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{StatusCode, Url};
+use time::format_description::well_known::Rfc2822;
+use time::OffsetDateTime;
+
+/// Longest excerpt of a response body kept in an error.
+const SNIPPET_LEN: usize = 500;
+
+/// Why an Airflow API request failed.
+///
+/// Errors returned by the clients are `anyhow::Error`s wrapping this type, so
+/// callers can recover it with `downcast_ref::<AirflowApiError>()`.
+#[derive(Debug)]
+pub enum AirflowApiError {
+    /// 401: credentials are missing, invalid or expired.
+    Unauthorized { url: Url, detail: String },
+    /// 403: the user is not allowed to perform this request.
+    Forbidden { url: Url, detail: String },
+    /// 404: the DAG, run, task or endpoint does not exist.
+    NotFound { url: Url, detail: String },
+    /// 409: the request conflicts with existing state, e.g. a duplicate run id.
+    Conflict { url: Url, detail: String },
+    /// 429: too many requests; retry after the given delay, if known.
+    RateLimited {
+        url: Url,
+        retry_after: Option<Duration>,
+    },
+    /// 5xx: Airflow, or a proxy or load balancer in front of it, failed.
+    Server {
+        url: Url,
+        status: StatusCode,
+        detail: String,
+    },
+    /// Any other unsuccessful status, e.g. 400 for an invalid request body.
+    Status {
+        url: Url,
+        status: StatusCode,
+        detail: String,
+    },
+    /// The response body is not the JSON the client expected.
+    Decode {
+        url: Url,
+        message: String,
+        snippet: String,
+    },
+    /// The request could not be sent or its response not read.
+    Request(reqwest::Error),
+}
+
+impl AirflowApiError {
+    /// Classify an unsuccessful response by its status code.
+    pub(crate) fn from_status(
+        url: Url,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Self {
+        let detail = error_detail(body);
+        match status {
+            StatusCode::UNAUTHORIZED => Self::Unauthorized { url, detail },
+            StatusCode::FORBIDDEN => Self::Forbidden { url, detail },
+            StatusCode::NOT_FOUND => Self::NotFound { url, detail },
+            StatusCode::CONFLICT => Self::Conflict { url, detail },
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited {
+                url,
+                retry_after: retry_after(headers, SystemTime::now()),
+            },
+            status if status.is_server_error() => Self::Server {
+                url,
+                status,
+                detail,
+            },
+            status => Self::Status {
+                url,
+                status,
+                detail,
+            },
+        }
+    }
+
+    pub(crate) fn decode(url: Url, error: &serde_json::Error, body: &str) -> Self {
+        Self::Decode {
+            url,
+            message: error.to_string(),
+            snippet: snippet(body),
+        }
+    }
+
+    /// The HTTP status of the response, if one was received.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Unauthorized { .. } => Some(StatusCode::UNAUTHORIZED),
+            Self::Forbidden { .. } => Some(StatusCode::FORBIDDEN),
+            Self::NotFound { .. } => Some(StatusCode::NOT_FOUND),
+            Self::Conflict { .. } => Some(StatusCode::CONFLICT),
+            Self::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Self::Server { status, .. } | Self::Status { status, .. } => Some(*status),
+            Self::Decode { .. } => None,
+            Self::Request(e) => e.status(),
+        }
+    }
+
+    /// Whether repeating the same request may succeed: rate limiting, gateway
+    /// errors and connection failures.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::RateLimited { .. } => true,
+            Self::Server { status, .. } => matches!(
+                *status,
+                StatusCode::BAD_GATEWAY
+                    | StatusCode::SERVICE_UNAVAILABLE
+                    | StatusCode::GATEWAY_TIMEOUT
+            ),
+            Self::Request(e) => e.is_connect() || e.is_timeout(),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for AirflowApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let with_detail = |f: &mut fmt::Formatter<'_>, detail: &str| {
+            if detail.is_empty() {
+                Ok(())
+            } else {
+                write!(f, ": {detail}")
+            }
+        };
+        match self {
+            Self::Unauthorized { url, detail } => {
+                write!(f, "401 Unauthorized for {url}")?;
+                with_detail(f, detail)
+            }
+            Self::Forbidden { url, detail } => {
+                write!(f, "403 Forbidden for {url}")?;
+                with_detail(f, detail)
+            }
+            Self::NotFound { url, detail } => {
+                write!(f, "404 Not Found for {url}")?;
+                with_detail(f, detail)
+            }
+            Self::Conflict { url, detail } => {
+                write!(f, "409 Conflict for {url}")?;
+                with_detail(f, detail)
+            }
+            Self::RateLimited { url, retry_after } => {
+                write!(f, "429 Too Many Requests for {url}")?;
+                match retry_after {
+                    Some(delay) => write!(f, " (retry after {}s)", delay.as_secs()),
+                    None => Ok(()),
+                }
+            }
+            Self::Server {
+                url,
+                status,
+                detail,
+            }
+            | Self::Status {
+                url,
+                status,
+                detail,
+            } => {
+                write!(f, "{status} for {url}")?;
+                with_detail(f, detail)
+            }
+            Self::Decode {
+                url,
+                message,
+                snippet,
+            } => write!(
+                f,
+                "Unexpected response from {url}: {message}. Response body: {snippet}"
+            ),
+            Self::Request(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for AirflowApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Request(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for AirflowApiError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Request(error)
+    }
+}
+
+/// The human-readable part of an error body: the `detail` (or `title`) of
+/// Airflow's problem JSON, or else a snippet of the raw body.
+fn error_detail(body: &str) -> String {
+    if let Ok(json) = serde_json::from_str::<serde_json::Value>(body) {
+        for key in ["detail", "title"] {
+            match json.get(key) {
+                Some(serde_json::Value::String(text)) => {
+                    if !text.is_empty() {
+                        return text.clone();
+                    }
+                }
+                Some(serde_json::Value::Null) | None => {}
+                // FastAPI validation errors carry a list of problems.
+                Some(other) => return snippet(&other.to_string()),
+            }
+        }
+    }
+    snippet(body)
+}
+
+fn snippet(body: &str) -> String {
+    let body = body.trim();
+    if body.chars().count() <= SNIPPET_LEN {
+        return body.to_string();
+    }
+    let mut snippet: String = body.chars().take(SNIPPET_LEN).collect();
+    snippet.push('…');
+    snippet
+}
+
+/// Parse a `Retry-After` header: either a number of seconds or an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = OffsetDateTime::parse(value, &Rfc2822).ok()?;
+    Some(
+        SystemTime::from(date)
+            .duration_since(now)
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn url() -> Url {
+        Url::parse("http://localhost/api/v2/dags/x").unwrap()
+    }
+
+    #[test]
+    fn classifies_statuses_and_extracts_detail() {
+        let body = r#"{"detail": "The DAG with dag_id: `x` was not found", "status": 404}"#;
+        let error =
+            AirflowApiError::from_status(url(), StatusCode::NOT_FOUND, &HeaderMap::new(), body);
+        assert!(
+            matches!(&error, AirflowApiError::NotFound { detail, .. } if detail.contains("`x` was not found"))
+        );
+        assert_eq!(error.status(), Some(StatusCode::NOT_FOUND));
+
+        let error = AirflowApiError::from_status(
+            url(),
+            StatusCode::BAD_GATEWAY,
+            &HeaderMap::new(),
+            "<html>Bad Gateway</html>",
+        );
+        assert!(error.is_transient());
+        assert_eq!(
+            error.to_string(),
+            "502 Bad Gateway for http://localhost/api/v2/dags/x: <html>Bad Gateway</html>"
+        );
+
+        let error = AirflowApiError::from_status(
+            url(),
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &HeaderMap::new(),
+            "",
+        );
+        assert!(!error.is_transient());
+    }
+
+    #[test]
+    fn validation_errors_are_kept_as_json() {
+        let body = r#"{"detail": [{"loc": ["body", "state"], "msg": "invalid"}]}"#;
+        let error = AirflowApiError::from_status(
+            url(),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            &HeaderMap::new(),
+            body,
+        );
+        assert!(error.to_string().contains(r#""msg":"invalid""#), "{error}");
+    }
+
+    #[test]
+    fn decode_error_includes_truncated_body() {
+        let body = format!("{{\"dags\": {}", "x".repeat(1000));
+        let json_error = serde_json::from_str::<serde_json::Value>(&body).unwrap_err();
+        let AirflowApiError::Decode { snippet, .. } =
+            AirflowApiError::decode(url(), &json_error, &body)
+        else {
+            panic!("expected a decode error");
+        };
+        assert!(snippet.starts_with("{\"dags\": xxx"));
+        assert_eq!(snippet.chars().count(), SNIPPET_LEN + 1);
+    }
+
+    #[test]
+    fn parses_retry_after_seconds_and_dates() {
+        let mut headers = HeaderMap::new();
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_445_412_470);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(7)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(10)));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers, now), None);
+    }
+}
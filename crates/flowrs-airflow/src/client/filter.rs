@@ -0,0 +1,72 @@
+//! Filters applied server-side by the list endpoints.
+
+use anyhow::Result;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+/// Filters for listing DAGs. Empty fields do not filter.
+///
+/// Tags and owners match exactly; the DAG id pattern matches any part of the
+/// id, case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DagFilter {
+    /// Only DAGs whose id contains this pattern.
+    pub dag_id_pattern: Option<String>,
+    /// Only DAGs with these tags. The Airflow 2 API returns DAGs with any of
+    /// them rather than all of them.
+    pub tags: Vec<String>,
+    /// Only DAGs owned by one of these owners. The Airflow 2 API cannot filter
+    /// on owners, so the V1 client ignores this.
+    pub owners: Vec<String>,
+    /// Only paused (`true`) or unpaused (`false`) DAGs.
+    pub paused: Option<bool>,
+}
+
+impl DagFilter {
+    /// The id pattern, tags and paused state as query parameters, which both
+    /// API versions name the same.
+    pub(crate) fn common_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(pattern) = &self.dag_id_pattern {
+            params.push(("dag_id_pattern", pattern.clone()));
+        }
+        params.extend(self.tags.iter().map(|tag| ("tags", tag.clone())));
+        if let Some(paused) = self.paused {
+            params.push(("paused", paused.to_string()));
+        }
+        params
+    }
+}
+
+/// Filters for listing the runs of a DAG. Empty fields do not filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DagRunFilter {
+    /// Only runs in one of these states, e.g. `"failed"`.
+    pub states: Vec<String>,
+    /// Only runs of one of these types, e.g. `"manual"`. The Airflow 2 API
+    /// cannot filter on the run type, so the V1 client ignores this.
+    pub run_types: Vec<String>,
+    /// Only runs with a logical date at or after this instant.
+    pub logical_date_gte: Option<OffsetDateTime>,
+    /// Only runs with a logical date at or before this instant.
+    pub logical_date_lte: Option<OffsetDateTime>,
+}
+
+impl DagRunFilter {
+    /// The logical date bounds as query parameters, under the names the API
+    /// version uses for them.
+    pub(crate) fn date_params(
+        &self,
+        gte: &'static str,
+        lte: &'static str,
+    ) -> Result<Vec<(&'static str, String)>> {
+        let mut params = Vec::new();
+        if let Some(date) = self.logical_date_gte {
+            params.push((gte, date.format(&Rfc3339)?));
+        }
+        if let Some(date) = self.logical_date_lte {
+            params.push((lte, date.format(&Rfc3339)?));
+        }
+        Ok(params)
+    }
+}
@@ -3,28 +3,27 @@ use log::{debug, info};
 use reqwest::Method;
 
 use super::model::dag::DagCollectionResponse;
-use super::{parse_json_response, V1Client};
+use super::V1Client;
+use crate::client::filter::DagFilter;
 
 const PAGE_SIZE: usize = 50;
 
 impl V1Client {
-    pub async fn fetch_dags(&self) -> Result<DagCollectionResponse> {
+    /// All DAGs matching `filter`, fetched page by page.
+    pub async fn fetch_dags(&self, filter: &DagFilter) -> Result<DagCollectionResponse> {
+        let query = filter.common_params();
         let mut all_dags = Vec::new();
         let mut offset = 0;
         let limit = PAGE_SIZE;
         let mut total_entries;
 
         loop {
-            let response = self
-                .base_api(Method::GET, "dags")
-                .await?
+            let page: DagCollectionResponse = self
+                .base_api(Method::GET, "dags")?
+                .query(&query)
                 .query(&[("limit", limit.to_string()), ("offset", offset.to_string())])
-                .send()
-                .await?
-                .error_for_status()?;
-
-            let response_text = response.text().await?;
-            let page: DagCollectionResponse = parse_json_response(&response_text, "DAGs response")?;
+                .send_json()
+                .await?;
 
             total_entries = page.total_entries;
             let fetched_count = page.dags.len();
@@ -53,34 +52,25 @@ impl V1Client {
     }
 
     pub async fn patch_dag_pause(&self, dag_id: &str, is_paused: bool) -> Result<()> {
-        self.base_api(Method::PATCH, &format!("dags/{dag_id}"))
-            .await?
+        self.base_api(Method::PATCH, &format!("dags/{dag_id}"))?
             .query(&[("update_mask", "is_paused")])
             .json(&serde_json::json!({"is_paused": !is_paused}))
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         Ok(())
     }
 
     pub async fn fetch_dag_code(&self, file_token: &str) -> Result<String> {
-        let r = self
-            .base_api(Method::GET, &format!("dagSources/{file_token}"))
-            .await?
-            .build()?;
-        let response = self.base.client.execute(r).await?.error_for_status()?;
-        let code = response.text().await?;
-        Ok(code)
+        self.base_api(Method::GET, &format!("dagSources/{file_token}"))?
+            .send_text()
+            .await
     }
 
     pub async fn fetch_dag_params(&self, dag_id: &str) -> Result<Option<serde_json::Value>> {
-        let response = self
-            .base_api(Method::GET, &format!("dags/{dag_id}/details"))
-            .await?
-            .send()
-            .await?
-            .error_for_status()?;
-        let body: serde_json::Value = response.json().await?;
+        let body: serde_json::Value = self
+            .base_api(Method::GET, &format!("dags/{dag_id}/details"))?
+            .send_json()
+            .await?;
         Ok(body.get("params").cloned())
     }
 }
@@ -4,33 +4,41 @@ use reqwest::{Method, Response};
 
 use super::model;
 use super::V1Client;
+use crate::client::filter::DagRunFilter;
 
 impl V1Client {
+    /// One page of a DAG's runs, newest first.
+    ///
+    /// The run type filter is not supported by the Airflow 2 API and ignored.
     pub async fn fetch_dagruns(
         &self,
         dag_id: &str,
+        filter: &DagRunFilter,
+        offset: usize,
+        limit: usize,
     ) -> Result<model::dagrun::DAGRunCollectionResponse> {
-        let response: Response = self
-            .base_api(Method::GET, &format!("dags/{dag_id}/dagRuns"))
-            .await?
-            .query(&[("order_by", "-execution_date"), ("limit", "50")])
-            .send()
-            .await?
-            .error_for_status()?;
+        let mut query = vec![
+            ("order_by", "-execution_date".to_string()),
+            ("limit", limit.to_string()),
+            ("offset", offset.to_string()),
+        ];
+        query.extend(filter.states.iter().map(|state| ("state", state.clone())));
+        query.extend(filter.date_params("execution_date_gte", "execution_date_lte")?);
 
-        let dagruns: model::dagrun::DAGRunCollectionResponse = response.json().await?;
+        let dagruns: model::dagrun::DAGRunCollectionResponse = self
+            .base_api(Method::GET, &format!("dags/{dag_id}/dagRuns"))?
+            .query(&query)
+            .send_json()
+            .await?;
         Ok(dagruns)
     }
 
     pub async fn fetch_all_dagruns(&self) -> Result<model::dagrun::DAGRunCollectionResponse> {
-        let response: Response = self
-            .base_api(Method::POST, "dags/~/dagRuns/list")
-            .await?
+        let dagruns: model::dagrun::DAGRunCollectionResponse = self
+            .base_api(Method::POST, "dags/~/dagRuns/list")?
             .json(&serde_json::json!({"page_limit": 200}))
-            .send()
-            .await?
-            .error_for_status()?;
-        let dagruns: model::dagrun::DAGRunCollectionResponse = response.json().await?;
+            .send_json()
+            .await?;
         Ok(dagruns)
     }
 
@@ -38,12 +46,10 @@ impl V1Client {
         self.base_api(
             Method::PATCH,
             &format!("dags/{dag_id}/dagRuns/{dag_run_id}"),
-        )
-        .await?
+        )?
         .json(&serde_json::json!({"state": status}))
         .send()
-        .await?
-        .error_for_status()?;
+        .await?;
         Ok(())
     }
 
@@ -51,12 +57,10 @@ impl V1Client {
         self.base_api(
             Method::POST,
             &format!("dags/{dag_id}/dagRuns/{dag_run_id}/clear"),
-        )
-        .await?
+        )?
         .json(&serde_json::json!({"dry_run": false}))
         .send()
-        .await?
-        .error_for_status()?;
+        .await?;
         Ok(())
     }
 
@@ -76,12 +80,10 @@ impl V1Client {
         }
 
         let resp: Response = self
-            .base_api(Method::POST, &format!("dags/{dag_id}/dagRuns"))
-            .await?
+            .base_api(Method::POST, &format!("dags/{dag_id}/dagRuns"))?
             .json(&body)
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         debug!("{resp:?}");
         Ok(())
     }
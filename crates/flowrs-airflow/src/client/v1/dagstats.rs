@@ -9,15 +9,11 @@ impl V1Client {
         &self,
         dag_ids: Vec<&str>,
     ) -> Result<model::dagstats::DagStatsResponse> {
-        let response = self
-            .base_api(Method::GET, "dagStats")
-            .await?
+        let dag_stats = self
+            .base_api(Method::GET, "dagStats")?
             .query(&[("dag_ids", dag_ids.join(","))])
-            .send()
-            .await?
-            .error_for_status()?;
-
-        let dag_stats = response.json::<model::dagstats::DagStatsResponse>().await?;
+            .send_json::<model::dagstats::DagStatsResponse>()
+            .await?;
         Ok(dag_stats)
     }
 }
@@ -36,8 +36,10 @@ pub fn parse_v1_log_content(content: &str) -> String {
         // Not V1 format, return as-is
         content.to_string()
     } else {
+        // Keep the final newline, which tells whether the last line is complete
+        let terminated = fragments.last().is_some_and(|f| f.ends_with("\\n"));
         // Join fragments and expand escaped newlines
-        fragments
+        let mut text = fragments
             .into_iter()
             .flat_map(|fragment| {
                 fragment
@@ -47,32 +49,46 @@ pub fn parse_v1_log_content(content: &str) -> String {
                     .collect::<Vec<_>>()
             })
             .collect::<Vec<_>>()
-            .join("\n")
+            .join("\n");
+        if terminated {
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// Query parameters for a log request: the full log, or the part after the
+/// given continuation token.
+fn log_query(continuation_token: Option<&str>) -> [(&'static str, &str); 1] {
+    match continuation_token {
+        Some(token) => [("token", token)],
+        None => [("full_content", "true")],
     }
 }
 
 impl V1Client {
+    /// Fetch the log of a task try. Without a `continuation_token` this is the
+    /// full log; with the token returned by a previous call, only what was
+    /// written since.
     pub async fn fetch_task_logs(
         &self,
         dag_id: &str,
         dag_run_id: &str,
         task_id: &str,
         task_try: u32,
+        continuation_token: Option<&str>,
     ) -> Result<model::log::Log> {
-        let response = self
+        let log = self
             .base_api(
                 Method::GET,
                 &format!(
                     "dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances/{task_id}/logs/{task_try}"
                 ),
-            )
-            .await?
-            .query(&[("full_content", "true")])
+            )?
+            .query(&log_query(continuation_token))
             .header("Accept", "application/json")
-            .send()
-            .await?
-            .error_for_status()?;
-        let log = response.json::<model::log::Log>().await?;
+            .send_json::<model::log::Log>()
+            .await?;
         Ok(log)
     }
 }
@@ -102,6 +118,13 @@ mod tests {
         assert_eq!(result, "line1\nline2\nline3");
     }
 
+    #[test]
+    fn test_parse_v1_keeps_the_final_newline() {
+        let content = r#"[('host', "line1\nline2\n")]"#;
+        let result = parse_v1_log_content(content);
+        assert_eq!(result, "line1\nline2\n");
+    }
+
     #[test]
     fn test_parse_v1_multiple_tuples_joined() {
         let content = r#"[('host1', 'log1'), ('host2', "log2")]"#;
@@ -10,24 +10,7 @@ mod taskinstance;
 use anyhow::Result;
 use reqwest::Method;
 
-use super::base::BaseClient;
-
-/// Parse a JSON response body, including a snippet of the body in the error on failure.
-///
-/// Some Airflow deployments return responses that don't match the documented schema
-/// (e.g. older v2.x versions omit `dag_display_name` / `task_display_name`). Surfacing
-/// the response body makes those parse failures actionable.
-pub(crate) fn parse_json_response<T: serde::de::DeserializeOwned>(
-    body: &str,
-    context: &str,
-) -> Result<T> {
-    serde_json::from_str(body).map_err(|e| {
-        anyhow::anyhow!(
-            "Failed to parse {context}: {e}. Response body (first 1000 chars): {}",
-            body.chars().take(1000).collect::<String>()
-        )
-    })
-}
+use super::base::{ApiRequest, BaseClient};
 
 /// API v1 client implementation (for Airflow v2, uses /api/v1 endpoint)
 #[derive(Debug)]
@@ -42,14 +25,8 @@ impl V1Client {
         Self { base }
     }
 
-    pub(crate) async fn base_api(
-        &self,
-        method: Method,
-        endpoint: &str,
-    ) -> Result<reqwest::RequestBuilder> {
-        self.base
-            .base_api(method, endpoint, Self::API_VERSION)
-            .await
+    pub(crate) fn base_api(&self, method: Method, endpoint: &str) -> Result<ApiRequest<'_>> {
+        self.base.base_api(method, endpoint, Self::API_VERSION)
     }
 
     /// Returns the base endpoint URL for this client
@@ -3,7 +3,7 @@ use log::{debug, info};
 use reqwest::{Method, Response};
 
 use super::model;
-use super::{parse_json_response, V1Client};
+use super::V1Client;
 
 const PAGE_SIZE: usize = 100;
 
@@ -19,20 +19,14 @@ impl V1Client {
         let mut total_entries;
 
         loop {
-            let response: Response = self
+            let page: model::taskinstance::TaskInstanceCollectionResponse = self
                 .base_api(
                     Method::GET,
                     &format!("dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances"),
-                )
-                .await?
+                )?
                 .query(&[("limit", limit.to_string()), ("offset", offset.to_string())])
-                .send()
-                .await?
-                .error_for_status()?;
-
-            let response_text = response.text().await?;
-            let page: model::taskinstance::TaskInstanceCollectionResponse =
-                parse_json_response(&response_text, "task instances response")?;
+                .send_json()
+                .await?;
 
             total_entries = page.total_entries;
             let fetched_count = page.task_instances.len();
@@ -75,17 +69,11 @@ impl V1Client {
         let mut total_entries;
 
         loop {
-            let response: Response = self
-                .base_api(Method::GET, "dags/~/dagRuns/~/taskInstances")
-                .await?
+            let page: model::taskinstance::TaskInstanceCollectionResponse = self
+                .base_api(Method::GET, "dags/~/dagRuns/~/taskInstances")?
                 .query(&[("limit", limit.to_string()), ("offset", offset.to_string())])
-                .send()
-                .await?
-                .error_for_status()?;
-
-            let response_text = response.text().await?;
-            let page: model::taskinstance::TaskInstanceCollectionResponse =
-                parse_json_response(&response_text, "all task instances response")?;
+                .send_json()
+                .await?;
 
             total_entries = page.total_entries;
             let fetched_count = page.task_instances.len();
@@ -119,19 +107,13 @@ impl V1Client {
         dag_run_id: &str,
         task_id: &str,
     ) -> Result<model::taskinstance::TaskInstanceTriesResponse> {
-        let response: Response = self
+        let tries: model::taskinstance::TaskInstanceTriesResponse = self
             .base_api(
                 Method::GET,
                 &format!("dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances/{task_id}/tries"),
-            )
-            .await?
-            .send()
-            .await?
-            .error_for_status()?;
-
-        let response_text = response.text().await?;
-        let tries: model::taskinstance::TaskInstanceTriesResponse =
-            parse_json_response(&response_text, "task instance tries response")?;
+            )?
+            .send_json()
+            .await?;
         debug!(
             "Fetched {} tries for task {task_id}",
             tries.task_instances.len()
@@ -151,12 +133,10 @@ impl V1Client {
             .base_api(
                 Method::PATCH,
                 &format!("dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances/{task_id}"),
-            )
-            .await?
+            )?
             .json(&serde_json::json!({"new_state": status, "dry_run": false}))
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         debug!("{resp:?}");
         Ok(())
     }
@@ -168,8 +148,7 @@ impl V1Client {
         task_id: &str,
     ) -> Result<()> {
         let resp: Response = self
-            .base_api(Method::POST, &format!("dags/{dag_id}/clearTaskInstances"))
-            .await?
+            .base_api(Method::POST, &format!("dags/{dag_id}/clearTaskInstances"))?
             .json(&serde_json::json!(
                 {
                     "dry_run": false,
@@ -181,8 +160,7 @@ impl V1Client {
                 }
             ))
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         debug!("{resp:?}");
         Ok(())
     }
@@ -4,26 +4,30 @@ use reqwest::Method;
 
 use super::model;
 use super::V2Client;
+use crate::client::filter::DagFilter;
 
 const PAGE_SIZE: usize = 50;
 
 impl V2Client {
-    pub async fn fetch_dags(&self) -> Result<model::dag::DagList> {
+    /// All DAGs matching `filter`, fetched page by page.
+    pub async fn fetch_dags(&self, filter: &DagFilter) -> Result<model::dag::DagList> {
+        let mut query = filter.common_params();
+        if !filter.tags.is_empty() {
+            query.push(("tags_match_mode", "all".to_string()));
+        }
+        query.extend(filter.owners.iter().map(|owner| ("owners", owner.clone())));
         let mut all_dags = Vec::new();
         let mut offset = 0;
         let limit = PAGE_SIZE;
         let mut total_entries;
 
         loop {
-            let response = self
-                .base_api(Method::GET, "dags")
-                .await?
+            let page: model::dag::DagList = self
+                .base_api(Method::GET, "dags")?
+                .query(&query)
                 .query(&[("limit", limit.to_string()), ("offset", offset.to_string())])
-                .send()
-                .await?
-                .error_for_status()?;
-
-            let page: model::dag::DagList = response.json().await?;
+                .send_json()
+                .await?;
 
             total_entries = page.total_entries;
             let fetched_count = page.dags.len();
@@ -52,35 +56,65 @@ impl V2Client {
     }
 
     pub async fn patch_dag_pause(&self, dag_id: &str, is_paused: bool) -> Result<()> {
-        self.base_api(Method::PATCH, &format!("dags/{dag_id}"))
-            .await?
+        self.base_api(Method::PATCH, &format!("dags/{dag_id}"))?
             .json(&serde_json::json!({"is_paused": !is_paused}))
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         Ok(())
     }
 
     pub async fn fetch_dag_code(&self, dag_id: &str) -> Result<String> {
-        let r = self
-            .base_api(Method::GET, &format!("dagSources/{dag_id}"))
-            .await?
-            .build()?;
-        let response = self.base.client.execute(r).await?.error_for_status()?;
-        let dag_source: model::dag::DagSource = response.json().await?;
+        let dag_source: model::dag::DagSource = self
+            .base_api(Method::GET, &format!("dagSources/{dag_id}"))?
+            .send_json()
+            .await?;
         Ok(dag_source.content)
     }
 
     pub async fn fetch_dag_params(&self, dag_id: &str) -> Result<Option<serde_json::Value>> {
         // `params` lives on the details endpoint; the plain `dags/{dag_id}`
         // (DAGResponse) schema does not include it.
-        let response = self
-            .base_api(Method::GET, &format!("dags/{dag_id}/details"))
-            .await?
-            .send()
-            .await?
-            .error_for_status()?;
-        let body: serde_json::Value = response.json().await?;
+        let body: serde_json::Value = self
+            .base_api(Method::GET, &format!("dags/{dag_id}/details"))?
+            .send_json()
+            .await?;
         Ok(body.get("params").cloned())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::base::config;
+    use crate::client::BaseClient;
+    use crate::config::AirflowConfig;
+    use crate::mock_server::{MockResponse, MockServer};
+
+    #[tokio::test]
+    async fn pushes_filters_into_the_query() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(200, &serde_json::json!({"dags": [], "total_entries": 0}))
+        })
+        .await;
+        let client = V2Client::new(
+            BaseClient::new(AirflowConfig {
+                endpoint: server.url.clone(),
+                ..config()
+            })
+            .unwrap(),
+        );
+        let filter = DagFilter {
+            dag_id_pattern: Some("etl".to_string()),
+            tags: vec!["finance".to_string(), "daily".to_string()],
+            owners: vec!["data-eng".to_string()],
+            paused: Some(false),
+        };
+
+        client.fetch_dags(&filter).await.unwrap();
+        assert_eq!(
+            server.requests()[0].path,
+            "/api/v2/dags?dag_id_pattern=etl&tags=finance&tags=daily&paused=false\
+             &tags_match_mode=all&owners=data-eng&limit=50&offset=0"
+        );
+    }
+}
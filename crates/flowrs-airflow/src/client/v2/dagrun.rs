@@ -4,29 +4,45 @@ use reqwest::{Method, Response};
 
 use super::model;
 use super::V2Client;
+use crate::client::filter::DagRunFilter;
 
 impl V2Client {
-    pub async fn fetch_dagruns(&self, dag_id: &str) -> Result<model::dagrun::DagRunList> {
-        let response: Response = self
-            .base_api(Method::GET, &format!("dags/{dag_id}/dagRuns"))
-            .await?
-            .query(&[("order_by", "-run_after"), ("limit", "50")])
-            .send()
-            .await?
-            .error_for_status()?;
-        let dagruns: model::dagrun::DagRunList = response.json().await?;
+    /// One page of a DAG's runs, newest first.
+    pub async fn fetch_dagruns(
+        &self,
+        dag_id: &str,
+        filter: &DagRunFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<model::dagrun::DagRunList> {
+        let mut query = vec![
+            ("order_by", "-run_after".to_string()),
+            ("limit", limit.to_string()),
+            ("offset", offset.to_string()),
+        ];
+        query.extend(filter.states.iter().map(|state| ("state", state.clone())));
+        query.extend(
+            filter
+                .run_types
+                .iter()
+                .map(|run_type| ("run_type", run_type.clone())),
+        );
+        query.extend(filter.date_params("logical_date_gte", "logical_date_lte")?);
+
+        let dagruns: model::dagrun::DagRunList = self
+            .base_api(Method::GET, &format!("dags/{dag_id}/dagRuns"))?
+            .query(&query)
+            .send_json()
+            .await?;
         Ok(dagruns)
     }
 
     pub async fn fetch_all_dagruns(&self) -> Result<model::dagrun::DagRunList> {
-        let response: Response = self
-            .base_api(Method::POST, "dags/~/dagRuns/list")
-            .await?
+        let dagruns: model::dagrun::DagRunList = self
+            .base_api(Method::POST, "dags/~/dagRuns/list")?
             .json(&serde_json::json!({"page_limit": 200}))
-            .send()
-            .await?
-            .error_for_status()?;
-        let dagruns: model::dagrun::DagRunList = response.json().await?;
+            .send_json()
+            .await?;
         Ok(dagruns)
     }
 
@@ -34,12 +50,10 @@ impl V2Client {
         self.base_api(
             Method::PATCH,
             &format!("dags/{dag_id}/dagRuns/{dag_run_id}"),
-        )
-        .await?
+        )?
         .json(&serde_json::json!({"state": status}))
         .send()
-        .await?
-        .error_for_status()?;
+        .await?;
         Ok(())
     }
 
@@ -47,12 +61,10 @@ impl V2Client {
         self.base_api(
             Method::POST,
             &format!("dags/{dag_id}/dagRuns/{dag_run_id}/clear"),
-        )
-        .await?
+        )?
         .json(&serde_json::json!({"dry_run": false}))
         .send()
-        .await?
-        .error_for_status()?;
+        .await?;
         Ok(())
     }
 
@@ -68,13 +80,58 @@ impl V2Client {
         }
 
         let resp: Response = self
-            .base_api(Method::POST, &format!("dags/{dag_id}/dagRuns"))
-            .await?
+            .base_api(Method::POST, &format!("dags/{dag_id}/dagRuns"))?
             .json(&body)
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         debug!("{resp:?}");
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::client::base::config;
+    use crate::client::BaseClient;
+    use crate::config::AirflowConfig;
+    use crate::mock_server::{MockResponse, MockServer};
+
+    #[tokio::test]
+    async fn pushes_filters_and_paging_into_the_query() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                200,
+                &serde_json::json!({"dag_runs": [], "total_entries": 120}),
+            )
+        })
+        .await;
+        let client = V2Client::new(
+            BaseClient::new(AirflowConfig {
+                endpoint: server.url.clone(),
+                ..config()
+            })
+            .unwrap(),
+        );
+        let filter = DagRunFilter {
+            states: vec!["failed".to_string(), "running".to_string()],
+            run_types: vec!["manual".to_string()],
+            logical_date_gte: Some(datetime!(2024-01-01 00:00:00 UTC)),
+            logical_date_lte: None,
+        };
+
+        let page = client
+            .fetch_dagruns("example", &filter, 50, 50)
+            .await
+            .unwrap();
+        assert_eq!(page.total_entries, 120);
+        assert_eq!(
+            server.requests()[0].path,
+            "/api/v2/dags/example/dagRuns?order_by=-run_after&limit=50&offset=50\
+             &state=failed&state=running&run_type=manual\
+             &logical_date_gte=2024-01-01T00%3A00%3A00Z"
+        );
+    }
+}
@@ -9,19 +9,16 @@ impl V2Client {
         &self,
         dag_ids: Vec<&str>,
     ) -> Result<model::dagstats::DagStatsResponse> {
-        let response = self
-            .base_api(Method::GET, "dagStats")
-            .await?
+        let dag_stats = self
+            .base_api(Method::GET, "dagStats")?
             .query(
                 &dag_ids
                     .into_iter()
                     .map(|id| ("dag_ids", id))
                     .collect::<Vec<_>>(),
             )
-            .send()
-            .await?
-            .error_for_status()?;
-        let dag_stats = response.json::<model::dagstats::DagStatsResponse>().await?;
+            .send_json::<model::dagstats::DagStatsResponse>()
+            .await?;
         Ok(dag_stats)
     }
 }
@@ -5,31 +5,91 @@ use reqwest::Method;
 use super::model;
 use super::V2Client;
 
+/// Query parameters for a log request: the full log, or the part after the
+/// given continuation token.
+fn log_query(continuation_token: Option<&str>) -> [(&'static str, &str); 1] {
+    match continuation_token {
+        Some(token) => [("token", token)],
+        None => [("full_content", "true")],
+    }
+}
+
 impl V2Client {
+    /// Fetch the log of a task try. Without a `continuation_token` this is the
+    /// full log; with the token returned by a previous call, only what was
+    /// written since.
     pub async fn fetch_task_logs(
         &self,
         dag_id: &str,
         dag_run_id: &str,
         task_id: &str,
         task_try: u32,
+        continuation_token: Option<&str>,
     ) -> Result<model::log::Log> {
-        let response = self
+        let log: model::log::Log = self
             .base_api(
                 Method::GET,
                 &format!(
                     "dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances/{task_id}/logs/{task_try}"
                 ),
-            )
-            .await?
-            .query(&[("full_content", "true")])
+            )?
+            .query(&log_query(continuation_token))
             .header("Accept", "application/json")
-            .send()
-            .await?
-            .error_for_status()?;
-
-        debug!("Response: {response:?}");
-        let log = response.json::<model::log::Log>().await?;
+            .send_json()
+            .await?;
         debug!("Parsed Log: {log:?}");
         Ok(log)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::base::config;
+    use crate::client::BaseClient;
+    use crate::config::AirflowConfig;
+    use crate::mock_server::{MockResponse, MockServer};
+
+    #[tokio::test]
+    async fn continues_from_the_previous_token() {
+        let server = MockServer::start(|req| {
+            let (content, token) = if req.path.contains("token=") {
+                (vec![serde_json::json!({"event": "second"})], "t2")
+            } else {
+                (vec![serde_json::json!({"event": "first"})], "t1")
+            };
+            MockResponse::json(
+                200,
+                &serde_json::json!({"content": content, "continuation_token": token}),
+            )
+        })
+        .await;
+        let client = V2Client::new(
+            BaseClient::new(AirflowConfig {
+                endpoint: server.url.clone(),
+                ..config()
+            })
+            .unwrap(),
+        );
+
+        let first = client
+            .fetch_task_logs("dag", "run", "task", 1, None)
+            .await
+            .unwrap();
+        let token = first.continuation_token.unwrap();
+        let second = client
+            .fetch_task_logs("dag", "run", "task", 1, Some(&token))
+            .await
+            .unwrap();
+
+        assert_eq!(second.continuation_token.as_deref(), Some("t2"));
+        let paths: Vec<String> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/api/v2/dags/dag/dagRuns/run/taskInstances/task/logs/1?full_content=true",
+                "/api/v2/dags/dag/dagRuns/run/taskInstances/task/logs/1?token=t1",
+            ]
+        );
+    }
+}
@@ -10,7 +10,7 @@ mod taskinstance;
 use anyhow::Result;
 use reqwest::Method;
 
-use super::base::BaseClient;
+use super::base::{ApiRequest, BaseClient};
 
 /// API v2 client implementation (for Airflow v3, uses /api/v2 endpoint)
 #[derive(Debug)]
@@ -25,14 +25,8 @@ impl V2Client {
         Self { base }
     }
 
-    pub(crate) async fn base_api(
-        &self,
-        method: Method,
-        endpoint: &str,
-    ) -> Result<reqwest::RequestBuilder> {
-        self.base
-            .base_api(method, endpoint, Self::API_VERSION)
-            .await
+    pub(crate) fn base_api(&self, method: Method, endpoint: &str) -> Result<ApiRequest<'_>> {
+        self.base.base_api(method, endpoint, Self::API_VERSION)
     }
 
     /// Returns the base endpoint URL for this client
@@ -6,14 +6,10 @@ use super::V2Client;
 
 impl V2Client {
     pub async fn fetch_tasks(&self, dag_id: &str) -> Result<model::task::TaskCollectionResponse> {
-        let response = self
-            .base_api(Method::GET, &format!("dags/{dag_id}/tasks"))
-            .await?
-            .send()
-            .await?
-            .error_for_status()?;
-
-        let task_collection: model::task::TaskCollectionResponse = response.json().await?;
+        let task_collection: model::task::TaskCollectionResponse = self
+            .base_api(Method::GET, &format!("dags/{dag_id}/tasks"))?
+            .send_json()
+            .await?;
         Ok(task_collection)
     }
 }
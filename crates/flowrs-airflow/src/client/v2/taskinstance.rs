@@ -19,18 +19,14 @@ impl V2Client {
         let mut total_entries;
 
         loop {
-            let response: Response = self
+            let page: model::taskinstance::TaskInstanceList = self
                 .base_api(
                     Method::GET,
                     &format!("dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances"),
-                )
-                .await?
+                )?
                 .query(&[("limit", limit.to_string()), ("offset", offset.to_string())])
-                .send()
-                .await?
-                .error_for_status()?;
-
-            let page: model::taskinstance::TaskInstanceList = response.json().await?;
+                .send_json()
+                .await?;
 
             total_entries = page.total_entries;
             let fetched_count = page.task_instances.len();
@@ -67,15 +63,11 @@ impl V2Client {
         let mut total_entries;
 
         loop {
-            let response: Response = self
-                .base_api(Method::GET, "dags/~/dagRuns/~/taskInstances")
-                .await?
+            let page: model::taskinstance::TaskInstanceList = self
+                .base_api(Method::GET, "dags/~/dagRuns/~/taskInstances")?
                 .query(&[("limit", limit.to_string()), ("offset", offset.to_string())])
-                .send()
-                .await?
-                .error_for_status()?;
-
-            let page: model::taskinstance::TaskInstanceList = response.json().await?;
+                .send_json()
+                .await?;
 
             total_entries = page.total_entries;
             let fetched_count = page.task_instances.len();
@@ -115,17 +107,13 @@ impl V2Client {
         dag_run_id: &str,
         task_id: &str,
     ) -> Result<model::taskinstance::TaskInstanceTriesResponse> {
-        let response: Response = self
+        let tries: model::taskinstance::TaskInstanceTriesResponse = self
             .base_api(
                 Method::GET,
                 &format!("dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances/{task_id}/tries"),
-            )
-            .await?
-            .send()
-            .await?
-            .error_for_status()?;
-
-        let tries: model::taskinstance::TaskInstanceTriesResponse = response.json().await?;
+            )?
+            .send_json()
+            .await?;
         debug!(
             "Fetched {} tries for task {task_id}",
             tries.task_instances.len()
@@ -145,15 +133,13 @@ impl V2Client {
             .base_api(
                 Method::PATCH,
                 &format!("dags/{dag_id}/dagRuns/{dag_run_id}/taskInstances/{task_id}"),
-            )
-            .await?
+            )?
             // Airflow 3's `/api/v2` PatchTaskInstanceBody uses a strict schema that
             // forbids unknown fields; unlike the `/api/v1` endpoint it does not accept
             // `dry_run` (dry-run is a separate endpoint), so sending it yields a 422.
             .json(&serde_json::json!({"new_state": status}))
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         debug!("{resp:?}");
         Ok(())
     }
@@ -165,8 +151,7 @@ impl V2Client {
         task_id: &str,
     ) -> Result<()> {
         let resp: Response = self
-            .base_api(Method::POST, &format!("dags/{dag_id}/clearTaskInstances"))
-            .await?
+            .base_api(Method::POST, &format!("dags/{dag_id}/clearTaskInstances"))?
             .json(&serde_json::json!(
                 {
                     "dry_run": false,
@@ -178,8 +163,7 @@ impl V2Client {
                 }
             ))
             .send()
-            .await?
-            .error_for_status()?;
+            .await?;
         debug!("{resp:?}");
         Ok(())
     }
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+
+use anyhow::Result;
+use log::{debug, info};
+use reqwest::{Method, StatusCode};
+
+use super::base::{BaseClient, RetryPolicy};
+use super::error::AirflowApiError;
+use crate::config::{AirflowConfig, AirflowVersion};
+
+/// Versions detected for servers configured with `version = "Auto"`, keyed by
+/// endpoint, so each server is probed at most once per process.
+static DETECTED_VERSIONS: LazyLock<Mutex<HashMap<String, AirflowVersion>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Longest wait for each probe request, so an unreachable server is reported
+/// quickly instead of after the full request timeout and its retries.
+const PROBE_TIMEOUT_SECS: u64 = 5;
+
+/// Probe a server for its Airflow major version.
+///
+/// Airflow 3 serves its REST API under `/api/v2` and Airflow 2 under `/api/v1`,
+/// so the first `version` endpoint that exists decides. A 401/403 still proves
+/// the endpoint exists; credential problems are reported later by the real
+/// requests rather than being mistaken for a missing API.
+pub async fn detect_version(base: &BaseClient) -> Result<AirflowVersion> {
+    for (api_path, version) in [
+        ("api/v2", AirflowVersion::V3),
+        ("api/v1", AirflowVersion::V2),
+    ] {
+        let status = match base
+            .base_api(Method::GET, "version", api_path)?
+            .send()
+            .await
+        {
+            Ok(response) => response.status(),
+            Err(error) => match error.downcast_ref::<AirflowApiError>() {
+                Some(AirflowApiError::Request(_)) | None => return Err(error),
+                Some(api_error) => api_error.status().unwrap_or_default(),
+            },
+        };
+        if status.is_success()
+            || status == StatusCode::UNAUTHORIZED
+            || status == StatusCode::FORBIDDEN
+        {
+            info!(
+                "Detected Airflow {version:?} for '{}' via /{api_path}/version ({status})",
+                base.config.name
+            );
+            return Ok(version);
+        }
+        debug!("/{api_path}/version returned {status}");
+    }
+
+    anyhow::bail!(
+        "Could not detect the Airflow version of '{}': neither /api/v2/version nor /api/v1/version is available",
+        base.config.endpoint
+    )
+}
+
+/// The full Airflow version string reported by a server, e.g. `"2.10.5"`.
+///
+/// `base` must have a resolved (non-`Auto`) version.
+pub async fn server_version(base: &BaseClient) -> Result<String> {
+    #[derive(serde::Deserialize)]
+    struct VersionResponse {
+        version: String,
+    }
+
+    let api_path = base.config.version.api_path().ok_or_else(|| {
+        anyhow::anyhow!("Airflow version of '{}' is not resolved", base.config.name)
+    })?;
+    let response: VersionResponse = base
+        .base_api(Method::GET, "version", api_path)?
+        .send_json()
+        .await?;
+    Ok(response.version)
+}
+
+/// The version detected earlier for `endpoint`, if any.
+pub fn cached_version(endpoint: &str) -> Option<AirflowVersion> {
+    DETECTED_VERSIONS.lock().unwrap().get(endpoint).cloned()
+}
+
+/// Resolve the concrete version of a server, probing it (once) when it is
+/// configured as `Auto`.
+pub async fn resolve_version(config: &AirflowConfig) -> Result<AirflowVersion> {
+    if config.version != AirflowVersion::Auto {
+        return Ok(config.version.clone());
+    }
+    if let Some(version) = cached_version(&config.endpoint) {
+        return Ok(version);
+    }
+
+    let probe = AirflowConfig {
+        timeout_secs: config.timeout_secs.min(PROBE_TIMEOUT_SECS),
+        ..config.clone()
+    };
+    let base = BaseClient::connect(probe)
+        .await?
+        .with_retry_policy(RetryPolicy::none());
+    let version = detect_version(&base).await?;
+    DETECTED_VERSIONS
+        .lock()
+        .unwrap()
+        .insert(config.endpoint.clone(), version.clone());
+    Ok(version)
+}
+
+/// Return a copy of `config` whose `Auto` version is replaced by the cached
+/// detection result. Fails if the server has not been resolved yet.
+pub fn with_resolved_version(config: &AirflowConfig) -> Result<AirflowConfig> {
+    let mut resolved = config.clone();
+    if resolved.version == AirflowVersion::Auto {
+        resolved.version = cached_version(&config.endpoint).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Airflow version of '{}' has not been detected; set `version` to \"V2\" or \"V3\" if the server cannot be probed",
+                config.name
+            )
+        })?;
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::base::config;
+    use crate::mock_server::{MockResponse, MockServer};
+
+    fn auto_config(endpoint: &str) -> AirflowConfig {
+        AirflowConfig {
+            endpoint: endpoint.to_string(),
+            version: AirflowVersion::Auto,
+            ..config()
+        }
+    }
+
+    async fn serve(available: &'static str, status: u16) -> MockServer {
+        MockServer::start(move |req| {
+            if req.path.starts_with(available) {
+                MockResponse::json(status, &serde_json::json!({"version": "x"}))
+            } else {
+                MockResponse::new(404, "not found")
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn detects_airflow_3_from_api_v2() {
+        let server = serve("/api/v2/version", 200).await;
+        let base = BaseClient::new(auto_config(&server.url)).unwrap();
+        assert_eq!(detect_version(&base).await.unwrap(), AirflowVersion::V3);
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_api_v1_for_airflow_2() {
+        let server = serve("/api/v1/version", 200).await;
+        let base = BaseClient::new(auto_config(&server.url)).unwrap();
+        assert_eq!(detect_version(&base).await.unwrap(), AirflowVersion::V2);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unauthorized_still_counts_as_present() {
+        let server = serve("/api/v2/version", 401).await;
+        let base = BaseClient::new(auto_config(&server.url)).unwrap();
+        assert_eq!(detect_version(&base).await.unwrap(), AirflowVersion::V3);
+    }
+
+    #[tokio::test]
+    async fn errors_when_no_version_endpoint_exists() {
+        let server = serve("/nowhere", 200).await;
+        let base = BaseClient::new(auto_config(&server.url)).unwrap();
+        assert!(detect_version(&base).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn reads_server_version() {
+        let server = serve("/api/v2/version", 200).await;
+        let config = AirflowConfig {
+            version: AirflowVersion::V3,
+            ..auto_config(&server.url)
+        };
+        let base = BaseClient::new(config).unwrap();
+        assert_eq!(server_version(&base).await.unwrap(), "x");
+    }
+
+    #[tokio::test]
+    async fn resolve_version_probes_once_and_caches() {
+        let server = serve("/api/v2/version", 200).await;
+        let config = auto_config(&server.url);
+
+        assert!(with_resolved_version(&config).is_err());
+        assert_eq!(resolve_version(&config).await.unwrap(), AirflowVersion::V3);
+        assert_eq!(resolve_version(&config).await.unwrap(), AirflowVersion::V3);
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(
+            with_resolved_version(&config).unwrap().version,
+            AirflowVersion::V3
+        );
+    }
+}
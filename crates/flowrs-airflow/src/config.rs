@@ -1,23 +1,33 @@
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
 
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
 use crate::auth::AirflowAuth;
+use crate::secret::SecretValue;
 
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
 pub enum AirflowVersion {
     #[default]
     V2,
     V3,
+    /// Probe the server on the first connect to decide between `V2` and `V3`.
+    /// See [`crate::client::version::resolve_version`].
+    #[serde(alias = "auto")]
+    Auto,
 }
 
 impl AirflowVersion {
-    pub const fn api_path(&self) -> &str {
+    /// The REST API path prefix for this version, or `None` for `Auto`, which
+    /// has to be resolved to a concrete version first.
+    pub const fn api_path(&self) -> Option<&str> {
         match self {
-            Self::V2 => "api/v1",
-            Self::V3 => "api/v2",
+            Self::V2 => Some("api/v1"),
+            Self::V3 => Some("api/v2"),
+            Self::Auto => None,
         }
     }
 }
@@ -63,6 +73,39 @@ pub struct AirflowConfig {
     /// Whether to allow insecure SSL connections.
     #[serde(default)]
     pub insecure: bool,
+    /// PEM bundle of extra CA certificates to trust, e.g. an internal CA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate for mutual TLS. May also contain the key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key for `client_cert`, if it is not in the same file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+    /// Proxy to reach the server through, e.g. a bastion SOCKS proxy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+    /// Extra headers sent with every API request. Values support the same
+    /// secret references as credentials.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, SecretValue>,
+}
+
+/// An HTTP, HTTPS or SOCKS5 proxy.
+///
+/// ```toml
+/// [servers.proxy]
+/// url = "socks5h://localhost:1080"
+/// no_proxy = "localhost,.internal.example.com"
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// `http://`, `https://`, `socks5://` or `socks5h://` (DNS resolved by
+    /// the proxy). May contain `${ENV_VAR}` placeholders, e.g. for credentials.
+    pub url: String,
+    /// Comma-separated hosts, domains and CIDR ranges to connect to directly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
 }
 
 pub const fn default_timeout() -> u64 {
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::secret::KeyringBackend;
+
+/// Service name under which flowrs secrets are stored in the OS keyring.
+const SERVICE: &str = "flowrs";
+
+/// Read a secret from the given keyring backend.
+pub fn get_secret(key: &str, backend: KeyringBackend) -> Result<String> {
+    match backend {
+        KeyringBackend::System => on_own_thread(|| {
+            ::keyring::Entry::new(SERVICE, key)?
+                .get_password()
+                .with_context(|| format!("Failed to read '{key}' from the system keyring"))
+        }),
+        KeyringBackend::File => FileKeyring::default().get(key),
+    }
+}
+
+/// Store a secret in the given keyring backend, overwriting any previous value.
+pub fn set_secret(key: &str, value: &str, backend: KeyringBackend) -> Result<()> {
+    match backend {
+        KeyringBackend::System => on_own_thread(|| {
+            ::keyring::Entry::new(SERVICE, key)?
+                .set_password(value)
+                .with_context(|| format!("Failed to store '{key}' in the system keyring"))
+        }),
+        KeyringBackend::File => FileKeyring::default().set(key, value),
+    }
+}
+
+/// Remove a secret from the given keyring backend. Missing entries are not an error.
+pub fn delete_secret(key: &str, backend: KeyringBackend) -> Result<()> {
+    match backend {
+        KeyringBackend::System => {
+            on_own_thread(
+                || match ::keyring::Entry::new(SERVICE, key)?.delete_credential() {
+                    Ok(()) | Err(::keyring::Error::NoEntry) => Ok(()),
+                    Err(e) => Err(anyhow::Error::new(e)
+                        .context(format!("Failed to delete '{key}' from the system keyring"))),
+                },
+            )
+        }
+        KeyringBackend::File => FileKeyring::default().delete(key),
+    }
+}
+
+/// Run a keyring call on a dedicated thread.
+///
+/// The Secret Service client drives D-Bus with its own executor; keeping it off
+/// the caller's thread means it never runs inside a Tokio runtime context.
+fn on_own_thread<T, F>(f: F) -> Result<T>
+where
+    T: Send,
+    F: FnOnce() -> Result<T> + Send,
+{
+    std::thread::scope(|scope| {
+        scope
+            .spawn(f)
+            .join()
+            .map_err(|_| anyhow::anyhow!("Keyring access panicked"))?
+    })
+}
+
+/// A plain TOML file mapping keys to secrets, readable only by the owner.
+///
+/// Used on headless machines where no Secret Service daemon is running. It is
+/// no stronger than the config file itself, but keeps secrets out of a file
+/// that gets edited, exported and shared.
+#[derive(Debug, Clone)]
+pub struct FileKeyring {
+    path: PathBuf,
+}
+
+impl Default for FileKeyring {
+    /// `$XDG_DATA_HOME/flowrs/keyring.toml` (or the platform equivalent).
+    fn default() -> Self {
+        let base = dirs::data_dir().unwrap_or_default();
+        Self::new(base.join("flowrs").join("keyring.toml"))
+    }
+}
+
+impl FileKeyring {
+    pub const fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn get(&self, key: &str) -> Result<String> {
+        self.load()?.remove(key).with_context(|| {
+            format!(
+                "No secret named '{key}' in file keyring {}",
+                self.path.display()
+            )
+        })
+    }
+
+    pub fn set(&self, key: &str, value: &str) -> Result<()> {
+        let mut secrets = self.load()?;
+        secrets.insert(key.to_string(), value.to_string());
+        self.save(&secrets)
+    }
+
+    pub fn delete(&self, key: &str) -> Result<()> {
+        let mut secrets = self.load()?;
+        if secrets.remove(key).is_some() {
+            self.save(&secrets)?;
+        }
+        Ok(())
+    }
+
+    fn load(&self) -> Result<BTreeMap<String, String>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("Failed to parse {}", self.path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => {
+                Err(anyhow::Error::new(e)
+                    .context(format!("Failed to read {}", self.path.display())))
+            }
+        }
+    }
+
+    /// Write via a temp file + rename, created with 0o600 so the secrets are
+    /// never readable by others, not even briefly.
+    fn save(&self, secrets: &BTreeMap<String, String>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = toml::to_string(secrets)?;
+        let tmp_path = self
+            .path
+            .with_extension(format!("tmp.{}", std::process::id()));
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&tmp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        drop(file);
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_keyring(tag: &str) -> FileKeyring {
+        let dir =
+            std::env::temp_dir().join(format!("flowrs-keyring-test-{}-{tag}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        FileKeyring::new(dir.join("keyring.toml"))
+    }
+
+    #[test]
+    fn file_keyring_round_trip() {
+        let keyring = temp_keyring("round-trip");
+        assert!(keyring.get("prod/password").is_err());
+
+        keyring.set("prod/password", "hunter2").unwrap();
+        keyring.set("dev/token", "tok").unwrap();
+        assert_eq!(keyring.get("prod/password").unwrap(), "hunter2");
+
+        keyring.delete("prod/password").unwrap();
+        assert!(keyring.get("prod/password").is_err());
+        assert_eq!(keyring.get("dev/token").unwrap(), "tok");
+        // Deleting a missing key is fine.
+        keyring.delete("prod/password").unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_keyring_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+        let keyring = temp_keyring("perms");
+        keyring.set("k", "v").unwrap();
+        let mode = std::fs::metadata(&keyring.path)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
@@ -1,10 +1,20 @@
 pub mod auth;
 pub mod client;
 pub mod config;
+#[cfg(feature = "keyring")]
+pub mod keyring;
 pub mod managed_services;
+#[cfg(test)]
+mod mock_server;
+pub mod secret;
 
 pub use auth::{
-    AirflowAuth, AstronomerAuth, BasicAuth, ComposerAuth, MwaaAuth, MwaaTokenType, TokenSource,
+    AirflowAuth, AstronomerAuth, BasicAuth, ComposerAuth, MwaaAuth, MwaaTokenType, OAuth2Auth,
+    OAuth2Flow, TokenSource,
 };
-pub use client::{create_api_client, AirflowApiClient, BaseClient, V1Client, V2Client};
-pub use config::{AirflowConfig, AirflowVersion, GccConfig, ManagedService};
+pub use client::{
+    create_api_client, AirflowApiClient, AirflowApiError, BaseClient, DagFilter, DagRunFilter,
+    RetryPolicy, V1Client, V2Client,
+};
+pub use config::{AirflowConfig, AirflowVersion, GccConfig, ManagedService, ProxyConfig};
+pub use secret::{KeyringBackend, SecretValue};
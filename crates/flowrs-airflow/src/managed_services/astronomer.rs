@@ -7,6 +7,7 @@ use log::info;
 use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::env;
 use std::fmt;
 use std::sync::LazyLock;
@@ -266,11 +267,8 @@ pub async fn get_astronomer_environment_servers() -> (Vec<AirflowConfig>, Vec<St
             } else if deployment.airflow_version.starts_with("3.") {
                 AirflowVersion::V3
             } else {
-                errors.push(format!(
-                    "Unsupported Airflow version '{}' for deployment '{}' in organization '{}'",
-                    deployment.airflow_version, deployment.name, org.name
-                ));
-                continue;
+                // Unrecognised version strings are probed at startup instead.
+                AirflowVersion::Auto
             };
 
             // Ensure the endpoint has a proper scheme and trailing slash
@@ -302,6 +300,11 @@ pub async fn get_astronomer_environment_servers() -> (Vec<AirflowConfig>, Vec<St
                 version,
                 timeout_secs: 30,
                 insecure: false,
+                ca_cert: None,
+                client_cert: None,
+                client_key: None,
+                proxy: None,
+                headers: BTreeMap::new(),
             });
         }
     }
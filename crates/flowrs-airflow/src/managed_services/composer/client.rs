@@ -4,6 +4,7 @@ use google_cloud_auth::credentials::Builder;
 use log::{debug, error, info};
 use reqwest::header::AUTHORIZATION;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use crate::auth::AirflowAuth;
@@ -222,13 +223,15 @@ impl ComposerClient {
 
 /// Parses Airflow version from Composer image version string
 /// Format: "composer-X.Y.Z-airflow-A.B.C"
+///
+/// Unrecognised formats resolve to `Auto`, so the server is probed at startup
+/// instead of guessed.
 fn parse_airflow_version(image_version: &str) -> AirflowVersion {
-    if let Some(airflow_part) = image_version.split("-airflow-").nth(1) {
-        if airflow_part.starts_with("3.") {
-            return AirflowVersion::V3;
-        }
+    match image_version.split("-airflow-").nth(1) {
+        Some(airflow_part) if airflow_part.starts_with("3.") => AirflowVersion::V3,
+        Some(airflow_part) if airflow_part.starts_with("2.") => AirflowVersion::V2,
+        _ => AirflowVersion::Auto,
     }
-    AirflowVersion::V2
 }
 
 /// Extracts the environment name from the full resource name
@@ -326,6 +329,11 @@ pub async fn get_composer_environment_servers(
                 version,
                 timeout_secs: 30,
                 insecure: false,
+                ca_cert: None,
+                client_cert: None,
+                client_key: None,
+                proxy: None,
+                headers: BTreeMap::new(),
             });
         }
     }
@@ -364,9 +372,9 @@ mod tests {
 
     #[test]
     fn test_parse_airflow_version_unknown() {
-        // Unknown formats default to V2
-        assert_eq!(parse_airflow_version("unknown"), AirflowVersion::V2);
-        assert_eq!(parse_airflow_version(""), AirflowVersion::V2);
+        // Unknown formats are probed at startup
+        assert_eq!(parse_airflow_version("unknown"), AirflowVersion::Auto);
+        assert_eq!(parse_airflow_version(""), AirflowVersion::Auto);
     }
 
     #[test]
@@ -2,7 +2,7 @@ use crate::client::auth::AuthProvider;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use google_cloud_auth::credentials::{AccessTokenCredentials, Builder};
-use log::info;
+use log::{info, warn};
 use reqwest::RequestBuilder;
 
 use super::ComposerAuth;
@@ -10,18 +10,23 @@ use super::ComposerAuth;
 pub struct ComposerAuthProvider {
     project_id: String,
     environment_name: String,
-    credentials: AccessTokenCredentials,
+    /// Replaced on `invalidate`: the credentials cache their access token
+    /// internally and offer no way to drop it.
+    credentials: std::sync::Mutex<AccessTokenCredentials>,
+}
+
+fn build_credentials() -> Result<AccessTokenCredentials> {
+    Builder::default()
+        .build_access_token_credentials()
+        .context("Failed to build GCP credentials")
 }
 
 impl ComposerAuthProvider {
     pub fn new(auth: &ComposerAuth) -> Result<Self> {
-        let credentials = Builder::default()
-            .build_access_token_credentials()
-            .context("Failed to build GCP credentials")?;
         Ok(Self {
             project_id: auth.project_id.clone(),
             environment_name: auth.environment_name.clone(),
-            credentials,
+            credentials: std::sync::Mutex::new(build_credentials()?),
         })
     }
 }
@@ -43,11 +48,24 @@ impl AuthProvider for ComposerAuthProvider {
             "🔑 Composer Auth: {}/{}",
             self.project_id, self.environment_name
         );
-        let token = self
-            .credentials
+        let credentials = self.credentials.lock().unwrap().clone();
+        let token = credentials
             .access_token()
             .await
             .context("Failed to get GCP access token")?;
         Ok(request.bearer_auth(token.token))
     }
+
+    async fn invalidate(&self) -> bool {
+        match build_credentials() {
+            Ok(credentials) => {
+                *self.credentials.lock().unwrap() = credentials;
+                true
+            }
+            Err(e) => {
+                warn!("Failed to renew GCP credentials: {e:#}");
+                false
+            }
+        }
+    }
 }
@@ -7,6 +7,7 @@ use dirs::home_dir;
 use log::info;
 use reqwest::RequestBuilder;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io::Read;
 use std::process::{Child, Command, Stdio};
@@ -149,6 +150,11 @@ impl AuthProvider for ConveyorAuthProvider {
         let (token, _) = cached.as_ref().expect("token cached above");
         Ok(request.bearer_auth(token))
     }
+
+    async fn invalidate(&self) -> bool {
+        *self.cached.lock().await = None;
+        true
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -183,7 +189,8 @@ pub fn get_conveyor_environment_servers() -> Result<Vec<AirflowConfig>> {
         .map(|env| {
             let version = match env.airflow_version.as_str() {
                 "AirflowVersion_V3" => crate::config::AirflowVersion::V3,
-                _ => crate::config::AirflowVersion::V2,
+                "AirflowVersion_V2" => crate::config::AirflowVersion::V2,
+                _ => crate::config::AirflowVersion::Auto,
             };
             AirflowConfig {
                 name: env.name.clone(),
@@ -193,6 +200,11 @@ pub fn get_conveyor_environment_servers() -> Result<Vec<AirflowConfig>> {
                 version,
                 timeout_secs: 30,
                 insecure: false,
+                ca_cert: None,
+                client_cert: None,
+                client_key: None,
+                proxy: None,
+                headers: BTreeMap::new(),
             }
         })
         .collect();
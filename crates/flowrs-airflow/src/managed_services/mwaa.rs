@@ -8,6 +8,7 @@ use aws_sdk_mwaa as mwaa;
 use log::info;
 use reqwest::RequestBuilder;
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// MWAA client for managing authentication and environment discovery
 #[derive(Debug, Clone)]
@@ -99,6 +100,9 @@ impl MwaaClient {
         let (login_path, cookie_name) = match version {
             AirflowVersion::V2 => ("aws_mwaa/login", "session"),
             AirflowVersion::V3 => ("pluginsv2/aws_mwaa/login", "_token"),
+            AirflowVersion::Auto => {
+                anyhow::bail!("MWAA login requires a known Airflow version")
+            }
         };
 
         let login_url = format!("https://{}/{}", web_token.hostname, login_path);
@@ -134,9 +138,10 @@ impl MwaaClient {
             if let Some(cookie_part) = cookie_str.split(';').next() {
                 if let Some((name, value)) = cookie_part.split_once('=') {
                     if name == cookie_name {
-                        return Ok(match version {
-                            AirflowVersion::V2 => MwaaTokenType::SessionCookie(value.to_string()),
-                            AirflowVersion::V3 => MwaaTokenType::JwtToken(value.to_string()),
+                        return Ok(if *version == AirflowVersion::V3 {
+                            MwaaTokenType::JwtToken(value.to_string())
+                        } else {
+                            MwaaTokenType::SessionCookie(value.to_string())
                         });
                     }
                 }
@@ -162,9 +167,14 @@ pub struct MwaaWebToken {
     pub hostname: String,
 }
 
+/// Authenticates with the MWAA web login obtained during discovery. Once the
+/// session expires it is invalidated and a new web login token is created.
 pub struct MwaaAuthProvider {
-    token: MwaaTokenType,
+    /// The current session, `None` after it was rejected.
+    token: tokio::sync::Mutex<Option<MwaaTokenType>>,
     environment_name: String,
+    /// Which login endpoint to use when logging in again.
+    version: AirflowVersion,
 }
 
 impl std::fmt::Debug for MwaaAuthProvider {
@@ -172,15 +182,21 @@ impl std::fmt::Debug for MwaaAuthProvider {
         f.debug_struct("MwaaAuthProvider")
             .field("token", &"***redacted***")
             .field("environment_name", &self.environment_name)
+            .field("version", &self.version)
             .finish()
     }
 }
 
 impl From<&MwaaAuth> for MwaaAuthProvider {
     fn from(auth: &MwaaAuth) -> Self {
+        let version = match auth.token {
+            MwaaTokenType::SessionCookie(_) => AirflowVersion::V2,
+            MwaaTokenType::JwtToken(_) => AirflowVersion::V3,
+        };
         Self {
-            token: auth.token.clone(),
+            token: tokio::sync::Mutex::new(Some(auth.token.clone())),
             environment_name: auth.environment_name.clone(),
+            version,
         }
     }
 }
@@ -188,14 +204,32 @@ impl From<&MwaaAuth> for MwaaAuthProvider {
 #[async_trait]
 impl AuthProvider for MwaaAuthProvider {
     async fn authenticate(&self, request: RequestBuilder) -> Result<RequestBuilder> {
+        let mut token = self.token.lock().await;
+        if token.is_none() {
+            info!(
+                "🔑 MWAA Auth: logging in again to {}",
+                self.environment_name
+            );
+            let client = MwaaClient::new().await?;
+            let web_token = client
+                .create_web_login_token(&self.environment_name)
+                .await?;
+            *token = Some(client.get_auth_token(&web_token, &self.version).await?);
+        }
+
         info!("🔑 MWAA Auth: {}", self.environment_name);
-        match &self.token {
+        match token.as_ref().expect("token set above") {
             MwaaTokenType::SessionCookie(cookie) => {
                 Ok(request.header("Cookie", format!("session={cookie}")))
             }
             MwaaTokenType::JwtToken(token) => Ok(request.bearer_auth(token)),
         }
     }
+
+    async fn invalidate(&self) -> bool {
+        *self.token.lock().await = None;
+        true
+    }
 }
 
 #[derive(Serialize)]
@@ -250,6 +284,11 @@ pub async fn get_mwaa_environment_servers() -> Result<Vec<AirflowConfig>> {
             version,
             timeout_secs: 30,
             insecure: false,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            proxy: None,
+            headers: BTreeMap::new(),
         });
     }
 
@@ -267,10 +306,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_mwaa_session_cookie_provider() {
-        let provider = MwaaAuthProvider {
+        let provider = MwaaAuthProvider::from(&MwaaAuth {
             token: MwaaTokenType::SessionCookie("my-session".to_string()),
             environment_name: "test-env".to_string(),
-        };
+        });
         let request = provider.authenticate(test_request()).await.unwrap();
         let built = request.build().unwrap();
         let cookie = built.headers().get("Cookie").unwrap().to_str().unwrap();
@@ -279,10 +318,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_mwaa_jwt_token_provider() {
-        let provider = MwaaAuthProvider {
+        let provider = MwaaAuthProvider::from(&MwaaAuth {
             token: MwaaTokenType::JwtToken("jwt-token".to_string()),
             environment_name: "test-env".to_string(),
-        };
+        });
         let request = provider.authenticate(test_request()).await.unwrap();
         let built = request.build().unwrap();
         let auth_header = built
@@ -0,0 +1,327 @@
+use std::fmt;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// A credential value in the config file.
+///
+/// Either an inline string (which may contain `${ENV_VAR}` placeholders, with
+/// `$${` for a literal `${`) or a reference to where the secret lives.
+/// References are resolved only when a client is created, so the secret
+/// itself is never written back to the config file.
+///
+/// ```toml
+/// password = "${AIRFLOW_PW}"
+/// password = "pa$${word"   # the literal password `pa${word`
+/// password = { env = "AIRFLOW_PW" }
+/// password = { cmd = "pass show airflow/prod" }
+/// password = { keyring = "prod/password" }
+/// password = { keyring = "prod/password", backend = "file" }
+/// ```
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum SecretValue {
+    Plain(String),
+    Env {
+        env: String,
+    },
+    Command {
+        cmd: String,
+    },
+    Keyring {
+        keyring: String,
+        #[serde(default, skip_serializing_if = "KeyringBackend::is_system")]
+        backend: KeyringBackend,
+    },
+}
+
+/// Where a `{ keyring = .. }` secret is stored.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyringBackend {
+    /// The OS credential store: Secret Service on Linux, Keychain on macOS,
+    /// Credential Manager on Windows.
+    #[default]
+    System,
+    /// A file under the flowrs data directory, for headless machines without
+    /// a Secret Service daemon.
+    File,
+}
+
+impl KeyringBackend {
+    #[expect(
+        clippy::trivially_copy_pass_by_ref,
+        reason = "signature required by serde's skip_serializing_if"
+    )]
+    fn is_system(&self) -> bool {
+        *self == KeyringBackend::System
+    }
+}
+
+impl SecretValue {
+    /// An inline value that resolves to exactly `value`, escaping any `${`.
+    pub fn literal(value: &str) -> Self {
+        SecretValue::Plain(value.replace("${", "$${"))
+    }
+
+    /// Resolve the secret to its actual value.
+    pub fn resolve(&self) -> Result<String> {
+        match self {
+            SecretValue::Plain(value) => expand_env_vars(value),
+            SecretValue::Env { env } => std::env::var(env)
+                .with_context(|| format!("Environment variable '{env}' is not set")),
+            SecretValue::Command { cmd } => run_secret_command(cmd),
+            SecretValue::Keyring { keyring, backend } => resolve_keyring(keyring, *backend),
+        }
+    }
+
+    /// Human-readable description of where the secret lives, for `config list`.
+    pub fn location(&self) -> String {
+        match self {
+            SecretValue::Plain(_) if self.is_literal() => "config file".to_string(),
+            SecretValue::Plain(value) => format!("environment ({value})"),
+            SecretValue::Env { env } => format!("environment (${env})"),
+            SecretValue::Command { cmd } => format!("command ({cmd})"),
+            SecretValue::Keyring { keyring, backend } => match backend {
+                KeyringBackend::System => format!("system keyring ({keyring})"),
+                KeyringBackend::File => format!("file keyring ({keyring})"),
+            },
+        }
+    }
+
+    /// Whether the secret is stored literally in the config, i.e. it is a
+    /// plain string without any `${ENV_VAR}` placeholder.
+    pub fn is_literal(&self) -> bool {
+        matches!(self, SecretValue::Plain(value)
+            if !placeholders(value).iter().any(|part| matches!(part, Part::Var(_))))
+    }
+}
+
+impl From<String> for SecretValue {
+    fn from(value: String) -> Self {
+        SecretValue::Plain(value)
+    }
+}
+
+impl From<&str> for SecretValue {
+    fn from(value: &str) -> Self {
+        SecretValue::Plain(value.to_string())
+    }
+}
+
+impl fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // References are safe to show; inline values never are.
+        match self {
+            SecretValue::Plain(_) => write!(f, "<redacted>"),
+            SecretValue::Env { env } => f.debug_struct("Env").field("env", env).finish(),
+            SecretValue::Command { cmd } => f.debug_struct("Command").field("cmd", cmd).finish(),
+            SecretValue::Keyring { keyring, backend } => f
+                .debug_struct("Keyring")
+                .field("keyring", keyring)
+                .field("backend", backend)
+                .finish(),
+        }
+    }
+}
+
+/// A piece of an inline value: literal text or a `${NAME}` placeholder
+enum Part<'a> {
+    Text(&'a str),
+    Var(&'a str),
+}
+
+/// Split `value` into text and placeholders. `$${` is an escaped, literal
+/// `${`, and a `${` without a closing `}` is kept as text.
+fn placeholders(value: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        if rest[..start].ends_with('$') {
+            parts.push(Part::Text(&rest[..start - 1]));
+            parts.push(Part::Text("${"));
+            rest = &rest[start + 2..];
+            continue;
+        }
+        let Some(len) = rest[start + 2..].find('}') else {
+            break;
+        };
+        parts.push(Part::Text(&rest[..start]));
+        parts.push(Part::Var(&rest[start + 2..start + 2 + len]));
+        rest = &rest[start + 3 + len..];
+    }
+    parts.push(Part::Text(rest));
+    parts
+}
+
+/// Replace every `${NAME}` in `value` with the value of environment variable
+/// `NAME`, and every `$${` with `${`. An unset variable is an error rather
+/// than an empty string, so a missing export doesn't silently turn into an
+/// empty password.
+pub fn expand_env_vars(value: &str) -> Result<String> {
+    let mut out = String::with_capacity(value.len());
+    for part in placeholders(value) {
+        match part {
+            Part::Text(text) => out.push_str(text),
+            Part::Var(name) => out.push_str(
+                &std::env::var(name)
+                    .with_context(|| format!("Environment variable '{name}' is not set"))?,
+            ),
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "keyring")]
+fn resolve_keyring(key: &str, backend: KeyringBackend) -> Result<String> {
+    crate::keyring::get_secret(key, backend)
+}
+
+#[cfg(not(feature = "keyring"))]
+fn resolve_keyring(_key: &str, _backend: KeyringBackend) -> Result<String> {
+    anyhow::bail!("Keyring support not compiled. Enable the 'keyring' feature.")
+}
+
+/// Run a secret helper command through `sh -c` and return its trimmed stdout.
+fn run_secret_command(cmd: &str) -> Result<String> {
+    let output = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .output()
+        .with_context(|| format!("Failed to run secret command: {cmd}"))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Secret command '{cmd}' failed with exit code {:?}: {}",
+            output.status.code(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let value =
+        String::from_utf8(output.stdout).context("Secret command returned invalid UTF-8")?;
+    Ok(value.trim_end_matches(['\n', '\r']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize, Serialize)]
+    struct Holder {
+        password: SecretValue,
+    }
+
+    fn parse(toml_str: &str) -> SecretValue {
+        toml::from_str::<Holder>(toml_str).unwrap().password
+    }
+
+    #[test]
+    fn parses_all_forms() {
+        assert_eq!(parse(r#"password = "pw""#), SecretValue::from("pw"));
+        assert_eq!(
+            parse(r#"password = { env = "PW" }"#),
+            SecretValue::Env {
+                env: "PW".to_string()
+            }
+        );
+        assert_eq!(
+            parse(r#"password = { cmd = "echo pw" }"#),
+            SecretValue::Command {
+                cmd: "echo pw".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parses_keyring_references() {
+        assert_eq!(
+            parse(r#"password = { keyring = "prod/password" }"#),
+            SecretValue::Keyring {
+                keyring: "prod/password".to_string(),
+                backend: KeyringBackend::System,
+            }
+        );
+        let file = parse(r#"password = { keyring = "prod/password", backend = "file" }"#);
+        assert_eq!(file.location(), "file keyring (prod/password)");
+    }
+
+    #[test]
+    fn system_backend_is_omitted_when_serializing() {
+        let holder = Holder {
+            password: SecretValue::Keyring {
+                keyring: "prod/password".to_string(),
+                backend: KeyringBackend::System,
+            },
+        };
+        let serialized = toml::to_string(&holder).unwrap();
+        assert!(!serialized.contains("backend"));
+    }
+
+    #[test]
+    fn references_round_trip_unresolved() {
+        let holder = Holder {
+            password: SecretValue::Command {
+                cmd: "pass show airflow/prod".to_string(),
+            },
+        };
+        let serialized = toml::to_string(&holder).unwrap();
+        assert!(serialized.contains(r#"cmd = "pass show airflow/prod""#));
+    }
+
+    #[test]
+    fn expands_env_placeholders() {
+        // PATH is set in every test environment.
+        let path = std::env::var("PATH").unwrap();
+        assert_eq!(
+            expand_env_vars("a-${PATH}-b").unwrap(),
+            format!("a-{path}-b")
+        );
+        assert_eq!(
+            expand_env_vars("no placeholders").unwrap(),
+            "no placeholders"
+        );
+        assert_eq!(expand_env_vars("open ${ only").unwrap(), "open ${ only");
+        assert_eq!(expand_env_vars("pa$${PATH}word").unwrap(), "pa${PATH}word");
+    }
+
+    #[test]
+    fn missing_env_var_is_an_error() {
+        let err = expand_env_vars("${FLOWRS_TEST_SURELY_UNSET_VAR}").unwrap_err();
+        assert!(err.to_string().contains("FLOWRS_TEST_SURELY_UNSET_VAR"));
+        assert!(SecretValue::Env {
+            env: "FLOWRS_TEST_SURELY_UNSET_VAR".to_string()
+        }
+        .resolve()
+        .is_err());
+    }
+
+    #[test]
+    fn resolves_command() {
+        let secret = SecretValue::Command {
+            cmd: "printf 'pw with space\\n'".to_string(),
+        };
+        assert_eq!(secret.resolve().unwrap(), "pw with space");
+        assert!(SecretValue::Command {
+            cmd: "false".to_string()
+        }
+        .resolve()
+        .is_err());
+    }
+
+    #[test]
+    fn only_plain_values_without_placeholders_are_literal() {
+        assert!(SecretValue::from("pw").is_literal());
+        assert!(!SecretValue::from("${PW}").is_literal());
+        assert!(SecretValue::from("pa$${PW}").is_literal());
+        assert_eq!(SecretValue::literal("a${B}").resolve().unwrap(), "a${B}");
+        assert!(SecretValue::from("open ${ only").is_literal());
+        assert!(!SecretValue::Env {
+            env: "PW".to_string()
+        }
+        .is_literal());
+    }
+
+    #[test]
+    fn debug_redacts_inline_values() {
+        assert_eq!(format!("{:?}", SecretValue::from("hunter2")), "<redacted>");
+    }
+}
@@ -1,4 +1,5 @@
 // Auth types are owned by flowrs-airflow. Re-exported here for backward compatibility.
 pub use flowrs_airflow::{
-    AirflowAuth, AstronomerAuth, BasicAuth, ComposerAuth, MwaaAuth, MwaaTokenType, TokenSource,
+    AirflowAuth, AstronomerAuth, BasicAuth, ComposerAuth, KeyringBackend, MwaaAuth, MwaaTokenType,
+    OAuth2Auth, OAuth2Flow, SecretValue, TokenSource,
 };
@@ -0,0 +1,351 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::{AirflowAuth, AirflowConfig, FlowrsConfig, SecretValue, TokenSource};
+
+/// How imported servers are combined with the existing configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportMode {
+    /// Add the imported servers next to the existing ones. Servers with the
+    /// same name must be identical, otherwise the import is rejected.
+    #[default]
+    Merge,
+    /// Drop all existing (non-managed) servers and use the imported ones.
+    Replace,
+}
+
+/// Outcome of [`FlowrsConfig::import`], used to report back to the user.
+#[derive(Debug, Default)]
+pub struct ImportSummary {
+    /// Names of servers that were added.
+    pub added: Vec<String>,
+    /// Names of servers that were already present with the same definition.
+    pub unchanged: Vec<String>,
+    /// Names of added servers whose credentials were stripped on export.
+    pub missing_secrets: Vec<String>,
+}
+
+/// Blank out credentials that are stored inline: basic-auth and login
+/// passwords, static tokens, OAuth 2.0 client secrets and refresh tokens, and
+/// the values of extra headers such as `Authorization`.
+/// Token commands and secret references (`${ENV_VAR}`, `{ env = .. }`,
+/// `{ cmd = .. }`, `{ keyring = .. }`) are kept, since they contain no secret.
+pub fn strip_secrets(server: &mut AirflowConfig) {
+    for secret in secrets_mut(server) {
+        if secret.is_literal() {
+            *secret = SecretValue::Plain(String::new());
+        }
+    }
+}
+
+/// The credentials of `server` that may be stored outside the config: those
+/// [`strip_secrets`] looks at, in the same order.
+pub fn secrets(server: &AirflowConfig) -> Vec<SecretValue> {
+    secrets_mut(&mut server.clone())
+        .into_iter()
+        .map(|secret| secret.clone())
+        .collect()
+}
+
+/// The credentials [`strip_secrets`] looks at, always in the same order for
+/// the same kind of auth and the same header names.
+fn secrets_mut(server: &mut AirflowConfig) -> Vec<&mut SecretValue> {
+    let mut secrets = match &mut server.auth {
+        AirflowAuth::Basic(basic) | AirflowAuth::Jwt(basic) => vec![&mut basic.password],
+        AirflowAuth::Token(TokenSource::Static { token }) => vec![token],
+        AirflowAuth::OAuth2(oauth2) => oauth2
+            .client_secret
+            .iter_mut()
+            .chain(oauth2.refresh_token.iter_mut())
+            .collect(),
+        _ => vec![],
+    };
+    secrets.extend(server.headers.values_mut());
+    secrets
+}
+
+fn is_stripped(secret: &SecretValue) -> bool {
+    matches!(secret, SecretValue::Plain(value) if value.is_empty())
+}
+
+/// Whether `server` carries an inline credential that has been stripped.
+fn has_stripped_secret(server: &AirflowConfig) -> bool {
+    secrets(server).iter().any(is_stripped)
+}
+
+/// Structural equality via the serialized form, since the config types don't
+/// implement `PartialEq`. A secret stripped from `imported` on export matches
+/// any local value, so a server shared without secrets equals its source.
+fn same_definition(local: &AirflowConfig, imported: &AirflowConfig) -> bool {
+    let mut imported = imported.clone();
+    let mut local_secrets = local.clone();
+    for (secret, local_secret) in secrets_mut(&mut imported)
+        .into_iter()
+        .zip(secrets_mut(&mut local_secrets))
+    {
+        if is_stripped(secret) {
+            *secret = local_secret.clone();
+        }
+    }
+    toml::to_string(local).ok() == toml::to_string(&imported).ok()
+}
+
+impl FlowrsConfig {
+    /// Serialize the configuration for sharing with a team.
+    ///
+    /// Managed-service servers are never exported (they are rediscovered on
+    /// startup), nor is the personal `active_server` selection. Unless
+    /// `include_secrets` is set, inline credentials are blanked, see
+    /// [`strip_secrets`].
+    pub fn export(&self, include_secrets: bool) -> Result<String> {
+        let mut exported = self.clone();
+        exported.active_server = None;
+        exported.servers.retain(|server| server.managed.is_none());
+        if !include_secrets {
+            exported.servers.iter_mut().for_each(strip_secrets);
+        }
+        exported.to_str()
+    }
+
+    /// Combine the servers of an exported configuration into this one.
+    ///
+    /// Name collisions are detected up front: duplicate names inside `imported`
+    /// are always an error, and in [`ImportMode::Merge`] an existing server with
+    /// the same name but a different definition is an error too. Nothing is
+    /// modified when an error is returned.
+    pub fn import(&mut self, imported: FlowrsConfig, mode: ImportMode) -> Result<ImportSummary> {
+        let mut seen = HashSet::new();
+        let duplicates: Vec<&str> = imported
+            .servers
+            .iter()
+            .filter(|server| !seen.insert(server.name.as_str()))
+            .map(|server| server.name.as_str())
+            .collect();
+        if !duplicates.is_empty() {
+            anyhow::bail!(
+                "Imported file defines these servers more than once: {}",
+                duplicates.join(", ")
+            );
+        }
+
+        if mode == ImportMode::Merge {
+            let conflicts: Vec<&str> = imported
+                .servers
+                .iter()
+                .filter(|new| {
+                    self.servers
+                        .iter()
+                        .any(|old| old.name == new.name && !same_definition(old, new))
+                })
+                .map(|server| server.name.as_str())
+                .collect();
+            if !conflicts.is_empty() {
+                anyhow::bail!(
+                    "Servers already exist with a different definition: {}. \
+                     Remove or rename them first, or import with --replace.",
+                    conflicts.join(", ")
+                );
+            }
+        }
+
+        let mut summary = ImportSummary::default();
+        if mode == ImportMode::Replace {
+            self.servers.retain(|server| server.managed.is_some());
+        }
+
+        for server in imported.servers {
+            if server.managed.is_some() {
+                continue;
+            }
+            if self.servers.iter().any(|old| old.name == server.name) {
+                summary.unchanged.push(server.name);
+                continue;
+            }
+            if has_stripped_secret(&server) {
+                summary.missing_secrets.push(server.name.clone());
+            }
+            summary.added.push(server.name.clone());
+            self.servers.push(server);
+        }
+
+        for service in imported.managed_services {
+            if !self.managed_services.contains(&service) {
+                self.managed_services.push(service);
+            }
+        }
+        // Only take the Composer settings if we have none or are replacing.
+        if let Some(gcc) = imported.gcc {
+            if self.gcc.is_none() || mode == ImportMode::Replace {
+                self.gcc = Some(gcc);
+            }
+        }
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{AirflowVersion, BasicAuth, ManagedService};
+
+    fn server(name: &str, password: &str) -> AirflowConfig {
+        AirflowConfig {
+            name: name.to_string(),
+            endpoint: "http://localhost:8080".to_string(),
+            auth: AirflowAuth::Basic(BasicAuth {
+                username: "airflow".to_string(),
+                password: password.into(),
+            }),
+            managed: None,
+            version: AirflowVersion::V2,
+            timeout_secs: 30,
+            insecure: false,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            proxy: None,
+            headers: BTreeMap::new(),
+        }
+    }
+
+    fn config(servers: Vec<AirflowConfig>) -> FlowrsConfig {
+        let mut config = FlowrsConfig::parse_toml("").unwrap();
+        config.servers = servers;
+        config
+    }
+
+    #[test]
+    fn export_strips_secrets_by_default() {
+        let mut source = config(vec![server("prod", "hunter2")]);
+        source.servers.push(AirflowConfig {
+            auth: AirflowAuth::Token(TokenSource::Static {
+                token: "s3cr3t".into(),
+            }),
+            ..server("token", "")
+        });
+        source.servers.push(AirflowConfig {
+            headers: BTreeMap::from([
+                ("Authorization".to_string(), "Bearer h34d3r".into()),
+                ("X-Tenant".to_string(), "${TENANT}".into()),
+            ]),
+            ..server("headers", "${AIRFLOW_PW}")
+        });
+        source.active_server = Some("prod".to_string());
+
+        let exported = source.export(false).unwrap();
+        assert!(!exported.contains("hunter2"));
+        assert!(!exported.contains("s3cr3t"));
+        assert!(!exported.contains("h34d3r"));
+        assert!(exported.contains("${TENANT}"));
+        assert!(!exported.contains("active_server"));
+
+        let with_secrets = source.export(true).unwrap();
+        assert!(with_secrets.contains("hunter2"));
+        assert!(with_secrets.contains("s3cr3t"));
+        assert!(with_secrets.contains("h34d3r"));
+    }
+
+    #[test]
+    fn export_keeps_secret_references() {
+        let mut source = config(vec![server("env", "${AIRFLOW_PW}")]);
+        source.servers.push(AirflowConfig {
+            auth: AirflowAuth::Basic(BasicAuth {
+                username: "airflow".to_string(),
+                password: SecretValue::Command {
+                    cmd: "pass show airflow".to_string(),
+                },
+            }),
+            ..server("cmd", "")
+        });
+
+        let exported = source.export(false).unwrap();
+        assert!(exported.contains("${AIRFLOW_PW}"));
+        assert!(exported.contains("pass show airflow"));
+    }
+
+    #[test]
+    fn export_skips_managed_servers() {
+        let mut managed = server("mwaa", "x");
+        managed.managed = Some(ManagedService::Mwaa);
+        let exported = config(vec![managed, server("local", "x")])
+            .export(false)
+            .unwrap();
+        let parsed = FlowrsConfig::parse_toml(&exported).unwrap();
+        assert_eq!(parsed.servers.len(), 1);
+        assert_eq!(parsed.servers[0].name, "local");
+    }
+
+    #[test]
+    fn merge_adds_new_servers_and_reports_missing_secrets() {
+        let mut target = config(vec![server("dev", "pw")]);
+        let imported = config(vec![server("dev", "pw"), server("prod", "")]);
+
+        let summary = target.import(imported, ImportMode::Merge).unwrap();
+        assert_eq!(summary.added, vec!["prod"]);
+        assert_eq!(summary.unchanged, vec!["dev"]);
+        assert_eq!(summary.missing_secrets, vec!["prod"]);
+        assert_eq!(target.servers.len(), 2);
+    }
+
+    #[test]
+    fn merge_accepts_its_own_export_without_secrets() {
+        let mut target = config(vec![server("dev", "pw")]);
+        let exported = target.export(false).unwrap();
+        let imported = FlowrsConfig::parse_toml(&exported).unwrap();
+
+        let summary = target.import(imported, ImportMode::Merge).unwrap();
+        assert_eq!(summary.unchanged, vec!["dev"]);
+        assert!(summary.missing_secrets.is_empty());
+        let AirflowAuth::Basic(basic) = &target.servers[0].auth else {
+            unreachable!()
+        };
+        assert_eq!(basic.password, SecretValue::from("pw"));
+    }
+
+    #[test]
+    fn merge_rejects_conflicting_names() {
+        let mut target = config(vec![server("dev", "pw")]);
+        let imported = config(vec![server("dev", "other")]);
+
+        let err = target.import(imported, ImportMode::Merge).unwrap_err();
+        assert!(err.to_string().contains("dev"));
+        assert_eq!(target.servers.len(), 1);
+    }
+
+    #[test]
+    fn secrets_include_header_values() {
+        let server = AirflowConfig {
+            headers: BTreeMap::from([("X-Tenant".to_string(), "${TENANT}".into())]),
+            ..server("headers", "hunter2")
+        };
+        assert_eq!(
+            secrets(&server),
+            vec![SecretValue::from("hunter2"), SecretValue::from("${TENANT}")]
+        );
+    }
+
+    #[test]
+    fn import_rejects_duplicate_names_in_file() {
+        let mut target = config(vec![]);
+        let imported = config(vec![server("dev", "a"), server("dev", "b")]);
+        assert!(target.import(imported, ImportMode::Replace).is_err());
+    }
+
+    #[test]
+    fn replace_swaps_server_list() {
+        let mut target = config(vec![server("dev", "pw")]);
+        let imported = config(vec![server("dev", "other"), server("prod", "pw")]);
+
+        let summary = target.import(imported, ImportMode::Replace).unwrap();
+        assert_eq!(summary.added, vec!["dev", "prod"]);
+        let BasicAuth { password, .. } = match &target.servers[0].auth {
+            AirflowAuth::Basic(basic) => basic.clone(),
+            _ => unreachable!(),
+        };
+        assert_eq!(password, SecretValue::from("other"));
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// The keys bound to an action in the `[keys]` section: one key such as
+/// `"ctrl-n"`, or a list like `["j", "Down"]`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum KeyBinding {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyBinding {
+    pub fn keys(&self) -> &[String] {
+        match self {
+            KeyBinding::One(key) => std::slice::from_ref(key),
+            KeyBinding::Many(keys) => keys,
+        }
+    }
+}
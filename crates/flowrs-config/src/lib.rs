@@ -1,14 +1,21 @@
 pub mod auth;
+pub mod export;
+pub mod keys;
 pub mod paths;
 pub mod server;
 pub mod theme;
 
 // Re-export all public types at crate root for ergonomic imports
-pub use auth::{AirflowAuth, BasicAuth, TokenSource};
+pub use auth::{
+    AirflowAuth, BasicAuth, KeyringBackend, OAuth2Auth, OAuth2Flow, SecretValue, TokenSource,
+};
+pub use export::{ImportMode, ImportSummary};
+pub use keys::KeyBinding;
 pub use paths::ConfigPaths;
-pub use server::{AirflowConfig, AirflowVersion, GccConfig, ManagedService};
+pub use server::{AirflowConfig, AirflowVersion, GccConfig, ManagedService, ProxyConfig};
 pub use theme::Theme;
 
+use std::collections::BTreeMap;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::PathBuf;
@@ -28,6 +35,10 @@ const fn default_poll_interval_ms() -> u64 {
     2000
 }
 
+const fn default_max_log_lines() -> usize {
+    100_000
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct FlowrsConfig {
     #[serde(default)]
@@ -39,10 +50,22 @@ pub struct FlowrsConfig {
     /// data from the Airflow API. Minimum 500ms, default 2000ms.
     #[serde(default = "default_poll_interval_ms")]
     pub poll_interval_ms: u64,
+    /// Maximum number of lines kept per task log. Older lines are dropped when
+    /// following a log beyond this size. Default 100000.
+    #[serde(default = "default_max_log_lines")]
+    pub max_log_lines: usize,
+    /// Directory task logs are saved to from the log viewer. Defaults to
+    /// `flowrs/logs` in the user's data directory.
+    #[serde(default)]
+    pub log_dir: Option<PathBuf>,
     /// Theme: "auto" (detect terminal background), "dark", "light", "catppuccin-latte",
     /// "catppuccin-frappe", "catppuccin-macchiato", or "catppuccin-mocha".
     #[serde(default)]
     pub theme: Theme,
+    /// Key bindings replacing the defaults, by action name, e.g.
+    /// `next = ["j", "Down", "ctrl-n"]`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<String, KeyBinding>,
     #[serde(default)]
     pub gcc: Option<GccConfig>,
     #[serde(skip_serializing)]
@@ -63,12 +86,26 @@ impl FlowrsConfig {
             managed_services: Vec::new(),
             active_server: None,
             poll_interval_ms: default_poll_interval_ms(),
+            max_log_lines: default_max_log_lines(),
+            log_dir: None,
             theme: Theme::default(),
+            keys: BTreeMap::new(),
             gcc: None,
             path: Some(config_paths.write_path.clone()),
         }
     }
 
+    /// The directory task logs are saved to: `log_dir` if set, else
+    /// `flowrs/logs` in the data directory (`~/.local/share` on Linux).
+    pub fn log_dir(&self) -> PathBuf {
+        self.log_dir.clone().unwrap_or_else(|| {
+            dirs::data_dir()
+                .unwrap_or_default()
+                .join("flowrs")
+                .join("logs")
+        })
+    }
+
     /// Compute the tick multiplier for API polling based on `poll_interval_ms`.
     /// Clamps values below the minimum and logs a warning.
     pub fn poll_tick_multiplier(&self) -> u32 {
@@ -180,6 +217,8 @@ impl FlowrsConfig {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::*;
 
     const TEST_CONFIG: &str = r#"[[servers]]
@@ -201,6 +240,7 @@ mod tests {
     const TEST_CONFIG_CONVEYOR: &str = r#"
 managed_services = ["Conveyor"]
 poll_interval_ms = 2000
+max_log_lines = 100000
 theme = "auto"
 
 [[servers]]
@@ -231,17 +271,25 @@ password = "airflow"
                 endpoint: "http://localhost:8080".to_string(),
                 auth: AirflowAuth::Basic(BasicAuth {
                     username: "airflow".to_string(),
-                    password: "airflow".to_string(),
+                    password: "airflow".into(),
                 }),
                 managed: None,
                 version: AirflowVersion::V2,
                 timeout_secs: default_timeout(),
                 insecure: false,
+                ca_cert: None,
+                client_cert: None,
+                client_key: None,
+                proxy: None,
+                headers: BTreeMap::new(),
             }],
             managed_services: vec![ManagedService::Conveyor],
             active_server: None,
             poll_interval_ms: default_poll_interval_ms(),
+            max_log_lines: default_max_log_lines(),
+            log_dir: None,
             theme: Theme::default(),
+            keys: BTreeMap::new(),
             gcc: None,
             path: None,
         };
@@ -250,6 +298,52 @@ password = "airflow"
         assert_eq!(serialized_config.trim(), TEST_CONFIG_CONVEYOR.trim());
     }
 
+    #[test]
+    fn test_version_auto() {
+        let toml = r#"
+[[servers]]
+name = "test"
+endpoint = "http://localhost:8080"
+version = "auto"
+
+[servers.auth.Basic]
+username = "airflow"
+password = "airflow"
+"#;
+        let config = FlowrsConfig::parse_toml(toml).unwrap();
+        assert_eq!(config.servers[0].version, AirflowVersion::Auto);
+        // Written like the other versions, and read back in that spelling too
+        let written = config.to_str().unwrap();
+        assert!(written.contains(r#"version = "Auto""#));
+        let reread = FlowrsConfig::parse_toml(&written).unwrap();
+        assert_eq!(reread.servers[0].version, AirflowVersion::Auto);
+    }
+
+    #[test]
+    fn test_oauth2_auth() {
+        let toml = r#"
+[[servers]]
+name = "keycloak"
+endpoint = "http://localhost:8080"
+version = "V3"
+
+[servers.auth.OAuth2]
+flow = "device_code"
+token_url = "https://sso.example.com/realms/data/protocol/openid-connect/token"
+device_authorization_url = "https://sso.example.com/realms/data/protocol/openid-connect/auth/device"
+client_id = "flowrs"
+scopes = ["openid", "offline_access"]
+refresh_token = { keyring = "keycloak/refresh_token" }
+"#;
+        let config = FlowrsConfig::parse_toml(toml).unwrap();
+        let AirflowAuth::OAuth2(oauth2) = &config.servers[0].auth else {
+            panic!("expected OAuth2 auth");
+        };
+        assert_eq!(oauth2.flow, OAuth2Flow::DeviceCode);
+        assert!(oauth2.client_secret.is_none());
+        assert!(config.to_str().unwrap().contains(r#"flow = "device_code""#));
+    }
+
     #[test]
     fn non_existing_path() {
         let config_paths = ConfigPaths::resolve();
@@ -296,6 +390,23 @@ password = "airflow"
         assert_eq!(config.poll_tick_multiplier(), 25);
     }
 
+    #[test]
+    fn test_keys() {
+        let config = FlowrsConfig::parse_toml(
+            r#"
+            [keys]
+            next = ["j", "ctrl-n"]
+            quit = "x"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.keys["next"].keys(), ["j", "ctrl-n"]);
+        assert_eq!(config.keys["quit"].keys(), ["x"]);
+
+        let reparsed = FlowrsConfig::parse_toml(&config.to_str().unwrap()).unwrap();
+        assert_eq!(reparsed.keys, config.keys);
+    }
+
     fn unique_temp_path(tag: &str) -> PathBuf {
         use std::sync::atomic::{AtomicU64, Ordering};
         static COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -312,12 +423,17 @@ password = "airflow"
             endpoint: "http://localhost:8080".to_string(),
             auth: AirflowAuth::Basic(BasicAuth {
                 username: "airflow".to_string(),
-                password: "airflow".to_string(),
+                password: "airflow".into(),
             }),
             managed,
             version: AirflowVersion::V2,
             timeout_secs: server::default_timeout(),
             insecure: false,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            proxy: None,
+            headers: BTreeMap::new(),
         }
     }
 
@@ -327,7 +443,10 @@ password = "airflow"
             managed_services: Vec::new(),
             active_server: None,
             poll_interval_ms: default_poll_interval_ms(),
+            max_log_lines: default_max_log_lines(),
+            log_dir: None,
             theme: Theme::default(),
+            keys: BTreeMap::new(),
             gcc: None,
             path: Some(path),
         }
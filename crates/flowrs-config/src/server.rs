@@ -1,4 +1,4 @@
 // Server config types are owned by flowrs-airflow. Re-exported here for backward compatibility.
-pub use flowrs_airflow::{AirflowConfig, AirflowVersion, GccConfig, ManagedService};
+pub use flowrs_airflow::{AirflowConfig, AirflowVersion, GccConfig, ManagedService, ProxyConfig};
 
 pub use flowrs_airflow::config::default_timeout;
@@ -153,10 +153,10 @@ pub(crate) fn v1_task_collection_to_list(
 }
 
 pub(crate) fn v1_log_to_log(value: flowrs_airflow::client::v1::model::log::Log) -> Log {
-    Log {
-        continuation_token: value.continuation_token,
-        content: flowrs_airflow::client::v1::log::parse_v1_log_content(&value.content),
-    }
+    Log::from_text(
+        value.continuation_token,
+        &flowrs_airflow::client::v1::log::parse_v1_log_content(&value.content),
+    )
 }
 
 pub(crate) fn v1_dagstats_to_response(
@@ -2,9 +2,10 @@ use crate::airflow::model::common::dagrun::{DagRunState, RunType};
 use crate::airflow::model::common::dagstats::{DagStatistic, DagStatistics};
 use crate::airflow::model::common::taskinstance::TaskInstanceState;
 use crate::airflow::model::common::{
-    Dag, DagList, DagRun, DagRunList, DagStatsResponse, Log, Tag, Task, TaskInstance,
+    Dag, DagList, DagRun, DagRunList, DagStatsResponse, Log, LogLine, Tag, Task, TaskInstance,
     TaskInstanceList, TaskList, TaskTryGantt,
 };
+use flowrs_airflow::client::v2::model::log::LogContent;
 
 pub(crate) fn v2_dag_to_dag(value: flowrs_airflow::client::v2::model::dag::Dag) -> Dag {
     Dag {
@@ -151,7 +152,20 @@ pub(crate) fn v2_task_collection_to_list(
 pub(crate) fn v2_log_to_log(value: flowrs_airflow::client::v2::model::log::Log) -> Log {
     Log {
         continuation_token: value.continuation_token,
-        content: value.content.to_string(),
+        lines: match value.content {
+            LogContent::Structured(messages) => messages
+                .into_iter()
+                .flat_map(|message| {
+                    LogLine::from_event(
+                        message.timestamp,
+                        &message.event,
+                        message.additional_fields,
+                    )
+                })
+                .collect(),
+            LogContent::Plain(lines) => LogLine::parse_text(&lines.join("\n")),
+        },
+        unterminated: false,
     }
 }
 
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use flowrs_airflow::DagFilter;
 
 use crate::airflow::client::convert_v1::v1_dag_collection_to_dag_list;
 use crate::airflow::client::convert_v2::v2_dag_list_to_dag_list;
@@ -9,14 +10,14 @@ use crate::airflow::traits::DagOperations;
 
 #[async_trait]
 impl DagOperations for FlowrsClient {
-    async fn list_dags(&self) -> Result<DagList> {
+    async fn list_dags(&self, filter: &DagFilter) -> Result<DagList> {
         match self {
             Self::V1(client) => {
-                let response = client.fetch_dags().await?;
+                let response = client.fetch_dags(filter).await?;
                 Ok(v1_dag_collection_to_dag_list(response))
             }
             Self::V2(client) => {
-                let response = client.fetch_dags().await?;
+                let response = client.fetch_dags(filter).await?;
                 Ok(v2_dag_list_to_dag_list(response))
             }
         }
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use flowrs_airflow::DagRunFilter;
 
 use crate::airflow::client::convert_v1::v1_dagrun_collection_to_list;
 use crate::airflow::client::convert_v2::v2_dagrun_list_to_list;
@@ -9,14 +10,20 @@ use crate::airflow::traits::DagRunOperations;
 
 #[async_trait]
 impl DagRunOperations for FlowrsClient {
-    async fn list_dagruns(&self, dag_id: &str) -> Result<DagRunList> {
+    async fn list_dagruns(
+        &self,
+        dag_id: &str,
+        filter: &DagRunFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<DagRunList> {
         match self {
             Self::V1(client) => {
-                let response = client.fetch_dagruns(dag_id).await?;
+                let response = client.fetch_dagruns(dag_id, filter, offset, limit).await?;
                 Ok(v1_dagrun_collection_to_list(response))
             }
             Self::V2(client) => {
-                let response = client.fetch_dagruns(dag_id).await?;
+                let response = client.fetch_dagruns(dag_id, filter, offset, limit).await?;
                 Ok(v2_dagrun_list_to_list(response))
             }
         }
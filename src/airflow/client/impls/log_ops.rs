@@ -15,17 +15,18 @@ impl LogOperations for FlowrsClient {
         dag_run_id: &str,
         task_id: &str,
         task_try: u32,
+        continuation_token: Option<&str>,
     ) -> Result<Log> {
         match self {
             Self::V1(client) => {
                 let response = client
-                    .fetch_task_logs(dag_id, dag_run_id, task_id, task_try)
+                    .fetch_task_logs(dag_id, dag_run_id, task_id, task_try, continuation_token)
                     .await?;
                 Ok(v1_log_to_log(response))
             }
             Self::V2(client) => {
                 let response = client
-                    .fetch_task_logs(dag_id, dag_run_id, task_id, task_try)
+                    .fetch_task_logs(dag_id, dag_run_id, task_id, task_try, continuation_token)
                     .await?;
                 Ok(v2_log_to_log(response))
             }
@@ -5,12 +5,14 @@ mod open_url;
 
 use anyhow::Result;
 
+use flowrs_airflow::client::version::{resolve_version, with_resolved_version};
 use flowrs_airflow::client::{BaseClient, V1Client, V2Client};
 use flowrs_airflow::{AirflowConfig, AirflowVersion};
 
 use crate::airflow::model::common::OpenItem;
 use crate::airflow::traits::AirflowClient;
 
+pub use open_url::parse_open_url;
 use open_url::{build_v1_open_url, build_v2_open_url};
 
 /// Wrapper enum that owns a versioned Airflow HTTP client and implements the TUI trait layer.
@@ -22,13 +24,28 @@ pub enum FlowrsClient {
 
 impl FlowrsClient {
     /// Create a new `FlowrsClient` from an `AirflowConfig`.
+    ///
+    /// `version = "Auto"` servers use the version detected by
+    /// [`FlowrsClient::connect`]; creating a client for an unprobed server fails.
     pub fn new(config: &AirflowConfig) -> Result<Self> {
-        let base = BaseClient::new(config.clone())?;
-        match config.version {
+        let config = with_resolved_version(config)?;
+        let version = config.version.clone();
+        let base = BaseClient::new(config)?;
+        match version {
             AirflowVersion::V2 => Ok(Self::V1(V1Client::new(base))),
             AirflowVersion::V3 => Ok(Self::V2(V2Client::new(base))),
+            AirflowVersion::Auto => unreachable!("version resolved above"),
         }
     }
+
+    /// [`FlowrsClient::new`] on the blocking pool, since resolving
+    /// `{ cmd = .. }` secrets runs a shell command. A `version = "Auto"`
+    /// server is probed for its version first, once per process.
+    pub async fn connect(config: &AirflowConfig) -> Result<Self> {
+        let mut config = config.clone();
+        config.version = resolve_version(&config).await?;
+        tokio::task::spawn_blocking(move || Self::new(&config)).await?
+    }
 }
 
 impl AirflowClient for FlowrsClient {
@@ -1,4 +1,7 @@
-use anyhow::Result;
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use percent_encoding::percent_decode_str;
 use url::{form_urlencoded, Url};
 
 use crate::airflow::model::common::OpenItem;
@@ -100,3 +103,156 @@ pub(super) fn build_v2_open_url(endpoint: &str, item: &OpenItem) -> Result<Strin
 
     Ok(base_url.to_string())
 }
+
+/// Parse a web UI URL back into the item it shows. Both the Airflow 2 (`grid`)
+/// and Airflow 3 URL formats built above are accepted, whatever the version of
+/// the server. Returns `None` when the URL is not on the server at `endpoint`.
+pub fn parse_open_url(endpoint: &str, url: &str) -> Result<Option<OpenItem>> {
+    let base = Url::parse(endpoint)?;
+    let url = Url::parse(url)?;
+    let base_path = base.path().trim_end_matches('/');
+    let path = match url.path().strip_prefix(base_path) {
+        Some(path) if path.is_empty() || path.starts_with('/') => path,
+        _ => return Ok(None),
+    };
+    if url.scheme() != base.scheme()
+        || url.host() != base.host()
+        || url.port_or_known_default() != base.port_or_known_default()
+    {
+        return Ok(None);
+    }
+
+    let segments: Vec<String> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(decode_segment)
+        .collect();
+    let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    let item = match segments.as_slice() {
+        ["dags", dag_id, "runs", dag_run_id, "tasks", task_id, ..] => {
+            let task_try = query
+                .get("try_number")
+                .filter(|_| query.get("tab").is_some_and(|tab| tab == "logs"))
+                .and_then(|task_try| task_try.parse().ok());
+            task_or_log(dag_id, dag_run_id, task_id, task_try)
+        }
+        ["dags", dag_id, "runs", dag_run_id, ..] => OpenItem::DagRun {
+            dag_id: (*dag_id).into(),
+            dag_run_id: (*dag_run_id).into(),
+        },
+        ["dags", dag_id, ..] => match (query.get("dag_run_id"), query.get("task_id")) {
+            (Some(dag_run_id), Some(task_id)) => task_or_log(dag_id, dag_run_id, task_id, None),
+            (Some(dag_run_id), None) => OpenItem::DagRun {
+                dag_id: (*dag_id).into(),
+                dag_run_id: dag_run_id.as_str().into(),
+            },
+            _ => OpenItem::Dag {
+                dag_id: (*dag_id).into(),
+            },
+        },
+        _ => bail!("{url} does not point at a DAG, DAG run or task"),
+    };
+    Ok(Some(item))
+}
+
+/// Ids in the path are percent-encoded. A `+` is kept: Airflow 3 leaves it
+/// unescaped in run ids such as `scheduled__2024-01-01T00:00:00+00:00`.
+fn decode_segment(segment: &str) -> String {
+    percent_decode_str(segment).decode_utf8_lossy().into_owned()
+}
+
+fn task_or_log(dag_id: &str, dag_run_id: &str, task_id: &str, task_try: Option<u32>) -> OpenItem {
+    match task_try {
+        Some(task_try) => OpenItem::Log {
+            dag_id: dag_id.into(),
+            dag_run_id: dag_run_id.into(),
+            task_id: task_id.into(),
+            task_try,
+        },
+        None => OpenItem::TaskInstance {
+            dag_id: dag_id.into(),
+            dag_run_id: dag_run_id.into(),
+            task_id: task_id.into(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENDPOINT: &str = "https://airflow.example.com/team/";
+    const RUN_ID: &str = "scheduled__2024-01-01T00:00:00+00:00";
+
+    fn items() -> Vec<OpenItem> {
+        vec![
+            OpenItem::Dag {
+                dag_id: "etl".into(),
+            },
+            OpenItem::DagRun {
+                dag_id: "etl".into(),
+                dag_run_id: RUN_ID.into(),
+            },
+            OpenItem::TaskInstance {
+                dag_id: "etl".into(),
+                dag_run_id: RUN_ID.into(),
+                task_id: "load.orders".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn parses_the_urls_it_builds() {
+        for item in items() {
+            for url in [
+                build_v1_open_url(ENDPOINT, &item).unwrap(),
+                build_v2_open_url(ENDPOINT, &item).unwrap(),
+            ] {
+                let parsed = parse_open_url(ENDPOINT, &url).unwrap().unwrap();
+                assert_eq!(format!("{parsed:?}"), format!("{item:?}"), "{url}");
+            }
+        }
+
+        let log = OpenItem::Log {
+            dag_id: "etl".into(),
+            dag_run_id: RUN_ID.into(),
+            task_id: "load".into(),
+            task_try: 3,
+        };
+        let url = build_v2_open_url(ENDPOINT, &log).unwrap();
+        let parsed = parse_open_url(ENDPOINT, &url).unwrap().unwrap();
+        assert_eq!(format!("{parsed:?}"), format!("{log:?}"));
+        // Airflow 2 log URLs do not carry the try
+        let url = build_v1_open_url(ENDPOINT, &log).unwrap();
+        let parsed = parse_open_url(ENDPOINT, &url).unwrap().unwrap();
+        assert!(matches!(parsed, OpenItem::TaskInstance { .. }));
+    }
+
+    #[test]
+    fn keeps_plus_signs_in_run_ids() {
+        for url in [
+            "https://airflow.example.com/team/dags/etl/runs/scheduled__2024-01-01T00:00:00+00:00",
+            "https://airflow.example.com/team/dags/etl/runs/scheduled__2024-01-01T00%3A00%3A00%2B00%3A00",
+        ] {
+            let parsed = parse_open_url(ENDPOINT, url).unwrap().unwrap();
+            assert!(
+                matches!(&parsed, OpenItem::DagRun { dag_run_id, .. } if **dag_run_id == *RUN_ID),
+                "{url}: {parsed:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_urls_of_other_servers_and_pages() {
+        for url in [
+            "https://other.example.com/team/dags/etl",
+            "http://airflow.example.com/team/dags/etl",
+            "https://airflow.example.com/teams/dags/etl",
+        ] {
+            assert!(parse_open_url(ENDPOINT, url).unwrap().is_none(), "{url}");
+        }
+        assert!(parse_open_url(ENDPOINT, "https://airflow.example.com/team/home").is_err());
+    }
+}
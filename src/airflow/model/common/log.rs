@@ -1,8 +1,432 @@
+use std::collections::VecDeque;
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
-/// Common Log model used by the application
+/// Common Log model used by the application: one response of the log
+/// endpoint, either a full log or the part after a continuation token
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Log {
     pub continuation_token: Option<String>,
-    pub content: String,
+    pub lines: Vec<LogLine>,
+    /// Whether the last line is cut off at the end of the chunk, to be
+    /// continued by the next one
+    #[serde(default)]
+    pub unterminated: bool,
+}
+
+impl Log {
+    /// A chunk of plain text log content
+    pub fn from_text(continuation_token: Option<String>, content: &str) -> Self {
+        Self {
+            continuation_token,
+            lines: LogLine::parse_text(content),
+            unterminated: !content.is_empty() && !content.ends_with('\n'),
+        }
+    }
+}
+
+/// Severity of a log record, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LogLevel {
+    Debug,
+    Info,
+    Warning,
+    Error,
+    Critical,
+}
+
+impl LogLevel {
+    pub fn parse(level: &str) -> Option<Self> {
+        match level.to_ascii_lowercase().as_str() {
+            "debug" => Some(Self::Debug),
+            "info" => Some(Self::Info),
+            "warning" | "warn" => Some(Self::Warning),
+            "error" | "exception" => Some(Self::Error),
+            "critical" | "fatal" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+
+    /// The next more severe level, wrapping around to `None` (all levels)
+    /// after `Critical`
+    pub const fn next(level: Option<Self>) -> Option<Self> {
+        match level {
+            None => Some(Self::Debug),
+            Some(Self::Debug) => Some(Self::Info),
+            Some(Self::Info) => Some(Self::Warning),
+            Some(Self::Warning) => Some(Self::Error),
+            Some(Self::Error) => Some(Self::Critical),
+            Some(Self::Critical) => None,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warning => "WARNING",
+            Self::Error => "ERROR",
+            Self::Critical => "CRITICAL",
+        })
+    }
+}
+
+/// One displayed line of a task log.
+///
+/// Structured logs (Airflow 3) fill in the columns from the log event; text
+/// logs (Airflow 2) from the standard `[time] {logger} LEVEL - message`
+/// prefix. Lines that do not start a record, such as traceback lines or the
+/// rest of a multi-line message, are continuations: they carry the level of
+/// their record for filtering but no other columns.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLine {
+    pub timestamp: Option<String>,
+    pub level: Option<LogLevel>,
+    pub logger: Option<String>,
+    pub event: String,
+    /// Remaining key-value fields of a structured event, sorted by key
+    pub extra: Vec<(String, String)>,
+    pub continuation: bool,
+}
+
+impl LogLine {
+    /// A line that continues the previous record
+    fn continuation(event: &str, level: Option<LogLevel>) -> Self {
+        Self {
+            level,
+            event: event.to_string(),
+            continuation: true,
+            ..Self::default()
+        }
+    }
+
+    /// Parse plain text log content, recognising the standard Airflow
+    /// `[time] {logger} LEVEL - message` record prefix.
+    pub fn parse_text(content: &str) -> Vec<Self> {
+        let mut level = None;
+        content
+            .lines()
+            .map(|line| match parse_record_prefix(line) {
+                Some(record) => {
+                    level = record.level;
+                    record
+                }
+                None => Self::continuation(line, level),
+            })
+            .collect()
+    }
+
+    /// Build the lines of a structured log event. A multi-line event becomes
+    /// one record line followed by continuations.
+    pub fn from_event(
+        timestamp: Option<String>,
+        event: &str,
+        fields: impl IntoIterator<Item = (String, serde_json::Value)>,
+    ) -> Vec<Self> {
+        let mut record = Self {
+            timestamp,
+            ..Self::default()
+        };
+        for (key, value) in fields {
+            let text = match value {
+                serde_json::Value::String(text) => text,
+                other => other.to_string(),
+            };
+            match key.as_str() {
+                "level" => record.level = LogLevel::parse(&text),
+                "logger" => record.logger = Some(text),
+                _ => record.extra.push((key, text)),
+            }
+        }
+        record.extra.sort();
+
+        let mut lines = event.lines();
+        record.event = lines.next().unwrap_or_default().to_string();
+        let level = record.level;
+        std::iter::once(record)
+            .chain(lines.map(|line| Self::continuation(line, level)))
+            .collect()
+    }
+}
+
+/// Formats the line in Airflow's text log format, for saving and exporting
+impl fmt::Display for LogLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.continuation {
+            return f.write_str(&self.event);
+        }
+        if let Some(timestamp) = &self.timestamp {
+            write!(f, "[{timestamp}] ")?;
+        }
+        if let Some(logger) = &self.logger {
+            write!(f, "{{{logger}}} ")?;
+        }
+        if let Some(level) = self.level {
+            write!(f, "{level} - ")?;
+        }
+        f.write_str(&self.event)?;
+        for (key, value) in &self.extra {
+            write!(f, " {key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse `[2025-10-12T01:24:16.754+0000] {taskinstance.py:123} INFO - message`.
+/// The logger part is optional.
+fn parse_record_prefix(line: &str) -> Option<LogLine> {
+    let rest = line.strip_prefix('[')?;
+    let (timestamp, rest) = rest.split_once("] ")?;
+    let (logger, rest) = match rest.strip_prefix('{') {
+        Some(rest) => {
+            let (logger, rest) = rest.split_once("} ")?;
+            (Some(logger.to_string()), rest)
+        }
+        None => (None, rest),
+    };
+    let (level, event) = rest.split_once(" - ")?;
+    let level = LogLevel::parse(level)?;
+    Some(LogLine {
+        timestamp: Some(timestamp.to_string()),
+        level: Some(level),
+        logger,
+        event: event.to_string(),
+        extra: Vec::new(),
+        continuation: false,
+    })
+}
+
+/// The log of one task try as loaded so far, grown chunk by chunk.
+///
+/// Only the most recent `max_lines` lines are kept, so following a very large
+/// log does not grow memory without bound.
+#[derive(Debug, Clone, Default)]
+pub struct TaskLog {
+    lines: VecDeque<LogLine>,
+    max_lines: usize,
+    /// Number of lines dropped from the start to stay within `max_lines`
+    dropped: usize,
+    /// Token for fetching what is written after the loaded lines
+    pub continuation_token: Option<String>,
+    /// Unterminated last line of the previous chunk, held back until the
+    /// next chunk completes it
+    tail: Option<LogLine>,
+}
+
+impl TaskLog {
+    pub fn new(max_lines: usize) -> Self {
+        Self {
+            max_lines: max_lines.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Apply a fetched chunk. `since` is the continuation token it was fetched
+    /// with: `None` means the chunk is the full log and replaces the loaded
+    /// lines. A chunk fetched with a token other than the current one is stale
+    /// and ignored, so lines are never appended twice.
+    ///
+    /// A line cut off at the end of a chunk is held back and joined with the
+    /// start of the next chunk, so it is parsed as the one line it is.
+    pub fn apply(&mut self, since: Option<&str>, chunk: Log) {
+        match since {
+            None => {
+                self.lines.clear();
+                self.dropped = 0;
+                self.tail = None;
+            }
+            Some(token) if self.continuation_token.as_deref() != Some(token) => return,
+            Some(_) => {}
+        }
+        let mut lines = chunk.lines;
+        if let Some(tail) = self.tail.take() {
+            match lines.first() {
+                Some(first) => {
+                    let joined = LogLine::parse_text(&format!("{tail}{first}"));
+                    lines.splice(..1, joined);
+                }
+                None => self.tail = Some(tail),
+            }
+        }
+        if chunk.unterminated {
+            self.tail = lines.pop();
+        }
+        // Continuations at the start of a chunk belong to the last loaded record
+        let mut level = self.lines.back().and_then(|line| line.level);
+        for mut line in lines {
+            if line.continuation && line.level.is_none() {
+                line.level = level;
+            }
+            level = line.level;
+            if self.lines.len() == self.max_lines {
+                self.lines.pop_front();
+                self.dropped += 1;
+            }
+            self.lines.push_back(line);
+        }
+        self.continuation_token = chunk.continuation_token;
+    }
+
+    /// The loaded lines, oldest first
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &LogLine> + ExactSizeIterator {
+        self.lines.iter()
+    }
+
+    /// Number of loaded lines
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Number of lines dropped from the start of the log to respect the cap
+    pub const fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The loaded lines as text, one per line
+    pub fn text(&self) -> String {
+        self.lines.iter().fold(String::new(), |mut text, line| {
+            text.push_str(&line.to_string());
+            text.push('\n');
+            text
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunk(content: &str, token: &str) -> Log {
+        Log::from_text(Some(token.to_string()), content)
+    }
+
+    fn events(log: &TaskLog) -> Vec<&str> {
+        log.lines().map(|line| line.event.as_str()).collect()
+    }
+
+    #[test]
+    fn appends_chunks_after_the_current_token() {
+        let mut log = TaskLog::new(10);
+        log.apply(None, chunk("a\nb\n", "t1"));
+        log.apply(Some("t1"), chunk("c\n", "t2"));
+        assert_eq!(events(&log), ["a", "b", "c"]);
+        assert_eq!(log.continuation_token.as_deref(), Some("t2"));
+
+        // A late response for an old token must not duplicate lines
+        log.apply(Some("t1"), chunk("c\n", "t2"));
+        assert_eq!(events(&log), ["a", "b", "c"]);
+
+        // A full fetch replaces everything
+        log.apply(None, chunk("x\n", "t9"));
+        assert_eq!(events(&log), ["x"]);
+    }
+
+    #[test]
+    fn drops_oldest_lines_beyond_the_cap() {
+        let mut log = TaskLog::new(3);
+        log.apply(None, chunk("1\n2\n", "t1"));
+        log.apply(Some("t1"), chunk("3\n4\n5\n", "t2"));
+        assert_eq!(events(&log), ["3", "4", "5"]);
+        assert_eq!(log.dropped(), 2);
+        assert_eq!(log.len(), 3);
+    }
+
+    #[test]
+    fn joins_a_line_split_across_chunks() {
+        let mut log = TaskLog::new(10);
+        log.apply(None, chunk("a\n[t] {x.py:1} ERR", "t1"));
+        assert_eq!(events(&log), ["a"]);
+
+        log.apply(Some("t1"), chunk("", "t2"));
+        log.apply(Some("t2"), chunk("OR - failed\nb\n", "t3"));
+        assert_eq!(events(&log), ["a", "failed", "b"]);
+        let levels: Vec<_> = log.lines().map(|line| line.level).collect();
+        assert_eq!(levels, [None, Some(LogLevel::Error), Some(LogLevel::Error)]);
+    }
+
+    #[test]
+    fn parses_airflow_text_records_and_continuations() {
+        let lines = LogLine::parse_text(
+            "*** Found local files:\n\
+             [2025-10-12T01:24:16.754+0000] {taskinstance.py:1157} ERROR - Task failed\n\
+             Traceback (most recent call last):\n\
+             [2025-10-12T01:24:17.000+0000] INFO - Done",
+        );
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].continuation);
+        assert_eq!(lines[0].level, None);
+
+        assert_eq!(
+            lines[1].timestamp.as_deref(),
+            Some("2025-10-12T01:24:16.754+0000")
+        );
+        assert_eq!(lines[1].logger.as_deref(), Some("taskinstance.py:1157"));
+        assert_eq!(lines[1].level, Some(LogLevel::Error));
+        assert_eq!(lines[1].event, "Task failed");
+
+        assert!(lines[2].continuation);
+        assert_eq!(lines[2].level, Some(LogLevel::Error));
+
+        assert_eq!(lines[3].logger, None);
+        assert_eq!(lines[3].level, Some(LogLevel::Info));
+    }
+
+    #[test]
+    fn continuations_at_chunk_start_inherit_the_last_level() {
+        let mut log = TaskLog::new(10);
+        log.apply(None, chunk("[t] {x.py:1} WARNING - careful\n", "t1"));
+        log.apply(Some("t1"), chunk("  more detail\n", "t2"));
+        let levels: Vec<_> = log.lines().map(|line| line.level).collect();
+        assert_eq!(levels, [Some(LogLevel::Warning), Some(LogLevel::Warning)]);
+    }
+
+    #[test]
+    fn formats_lines_as_airflow_text() {
+        let content = "[2025-10-12T01:24:16.754+0000] {taskinstance.py:1157} ERROR - Task failed\n\
+                       Traceback (most recent call last):\n";
+        let mut log = TaskLog::new(10);
+        log.apply(None, chunk(content, "t1"));
+        assert_eq!(log.text(), content);
+
+        let structured = LogLine::from_event(
+            None,
+            "done",
+            [("chan".to_string(), serde_json::json!("stdout"))],
+        );
+        assert_eq!(structured[0].to_string(), "done chan=stdout");
+    }
+
+    #[test]
+    fn builds_structured_event_lines() {
+        let lines = LogLine::from_event(
+            Some("2025-01-01T00:00:00Z".to_string()),
+            "first\nsecond",
+            [
+                ("level".to_string(), serde_json::json!("warning")),
+                ("logger".to_string(), serde_json::json!("airflow.task")),
+                ("lineno".to_string(), serde_json::json!(42)),
+                ("chan".to_string(), serde_json::json!("stdout")),
+            ],
+        );
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].level, Some(LogLevel::Warning));
+        assert_eq!(lines[0].logger.as_deref(), Some("airflow.task"));
+        assert_eq!(
+            lines[0].extra,
+            [
+                ("chan".to_string(), "stdout".to_string()),
+                ("lineno".to_string(), "42".to_string())
+            ]
+        );
+        assert!(lines[1].continuation);
+        assert_eq!(lines[1].level, Some(LogLevel::Warning));
+        assert_eq!(lines[1].event, "second");
+    }
 }
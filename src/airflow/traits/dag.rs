@@ -1,13 +1,14 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use flowrs_airflow::DagFilter;
 
 use crate::airflow::model::common::{Dag, DagList};
 
 /// Trait for DAG operations
 #[async_trait]
 pub trait DagOperations: Send + Sync {
-    /// List all DAGs
-    async fn list_dags(&self) -> Result<DagList>;
+    /// List all DAGs matching `filter`, which the API applies server-side
+    async fn list_dags(&self, filter: &DagFilter) -> Result<DagList>;
 
     /// Toggle a DAG's paused state
     async fn toggle_dag(&self, dag_id: &str, is_paused: bool) -> Result<()>;
@@ -1,13 +1,21 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use flowrs_airflow::DagRunFilter;
 
 use crate::airflow::model::common::DagRunList;
 
 /// Trait for DAG Run operations
 #[async_trait]
 pub trait DagRunOperations: Send + Sync {
-    /// List DAG runs for a specific DAG
-    async fn list_dagruns(&self, dag_id: &str) -> Result<DagRunList>;
+    /// List a page of DAG runs for a specific DAG, newest first, filtered
+    /// server-side. `total_entries` counts all runs matching `filter`.
+    async fn list_dagruns(
+        &self,
+        dag_id: &str,
+        filter: &DagRunFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Result<DagRunList>;
 
     /// List all DAG runs across all DAGs
     #[allow(unused, reason = "trait method kept for API completeness")]
@@ -6,12 +6,14 @@ use crate::airflow::model::common::Log;
 /// Trait for Log operations
 #[async_trait]
 pub trait LogOperations: Send + Sync {
-    /// Get task logs for a specific task instance and try number
+    /// Get task logs for a specific task instance and try number: the full
+    /// log, or only what was written after `continuation_token`
     async fn get_task_logs(
         &self,
         dag_id: &str,
         dag_run_id: &str,
         task_id: &str,
         task_try: u32,
+        continuation_token: Option<&str>,
     ) -> Result<Log>;
 }
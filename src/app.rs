@@ -2,23 +2,41 @@ use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyModifiers};
-use events::{custom::FlowrsEvent, generator::EventGenerator};
+use events::custom::{is_click, is_click_in, FlowrsEvent};
+use events::generator::EventGenerator;
+use flowrs_airflow::client::version::cached_version;
+use flowrs_config::AirflowVersion;
+use futures::future::join_all;
 use log::debug;
 use ratatui::{prelude::Backend, Terminal};
-use state::App;
+use state::{App, Panel};
 use worker::{Dispatcher, WorkerMessage};
 
 use crate::airflow::client::FlowrsClient;
 use crate::airflow::model::common::EnvironmentKey;
+use crate::app::actions::ActionId;
+use crate::app::keymap::keymap;
+use crate::app::model::popup::error::error_message;
+use crate::app::model::popup::jump::JumpResult;
+use crate::app::model::popup::palette::PaletteResult;
+use crate::ui::tabs::TabBar;
 
-use crate::{ui::draw_ui, CONFIG_PATHS};
+use crate::ui::draw_ui;
 
+pub mod actions;
 pub mod events;
+pub mod keymap;
 pub mod model;
+pub mod session;
 pub mod state;
+pub mod terminal;
 pub mod worker;
 
-pub async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: Arc<Mutex<App>>) -> Result<()>
+pub async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: Arc<Mutex<App>>,
+    startup: Vec<WorkerMessage>,
+) -> Result<()>
 where
     <B as Backend>::Error: 'static + std::marker::Send + std::marker::Sync,
 {
@@ -30,28 +48,53 @@ where
 
     log::info!("Initializing environment state");
     {
+        // Clone servers to avoid holding the lock while the clients connect
+        let (servers, active_server_name) = {
+            let app = app.lock().unwrap();
+            (
+                app.config.servers.clone(),
+                app.nav_context.environment().cloned(),
+            )
+        };
+        // Servers whose version still has to be probed connect when they are
+        // first selected, so only the active one can hold up startup
+        let servers: Vec<_> = servers
+            .into_iter()
+            .filter(|server| {
+                server.version != AirflowVersion::Auto
+                    || cached_version(&server.endpoint).is_some()
+                    || active_server_name.as_ref() == Some(&server.name)
+            })
+            .collect();
+        let clients = join_all(servers.iter().map(FlowrsClient::connect)).await;
         let mut app = app.lock().unwrap();
 
-        // Clone servers to avoid borrow checker issues
-        let servers = app.config.servers.clone();
-        let active_server_name = app.config.active_server.clone();
-
         // Initialize all environments with their clients
-        for server_config in servers {
-            if let Ok(client) = FlowrsClient::new(&server_config) {
-                let env_data = state::environment_state::EnvironmentData::new(Arc::new(client));
-                app.environment_state
-                    .environments
-                    .insert(EnvironmentKey::from(server_config.name.clone()), env_data);
-            } else {
-                log::error!(
-                    "Failed to create client for server '{}'; skipping",
-                    server_config.name
-                );
+        for (server_config, client) in servers.into_iter().zip(clients) {
+            match client {
+                Ok(client) => {
+                    let env_data = state::environment_state::EnvironmentData::new(Arc::new(client));
+                    app.environment_state
+                        .environments
+                        .insert(EnvironmentKey::from(server_config.name.clone()), env_data);
+                }
+                Err(e) => {
+                    log::error!(
+                        "Failed to create client for server '{}'; skipping: {e}",
+                        server_config.name
+                    );
+                    if active_server_name.as_ref() == Some(&server_config.name) {
+                        app.show_error(vec![format!(
+                            "Failed to connect to '{}': {}",
+                            server_config.name,
+                            error_message(&e)
+                        )]);
+                    }
+                }
             }
         }
 
-        // Set the active environment if one was configured
+        // Set the active environment if one was configured or opened
         if let Some(active_server_name) = active_server_name {
             app.environment_state
                 .set_active_environment(EnvironmentKey::from(active_server_name));
@@ -61,6 +104,13 @@ where
     log::info!("Spawning dispatcher");
     tokio::spawn(async move { Dispatcher::new(worker_app).run(rx_worker).await });
 
+    // Fetches for the DAG, run or task opened from the command line
+    for message in startup {
+        if let Err(e) = tx_worker.send(message).await {
+            log::error!("Failed to send message to worker: {e}");
+        }
+    }
+
     loop {
         terminal.draw(|f| {
             debug!("Drawing UI");
@@ -100,6 +150,85 @@ where
                     continue;
                 }
             }
+            // The jump prompt takes all input while it is open. Jumping moves
+            // the navigation context and fetches the target's data.
+            let jump_messages = match &event {
+                FlowrsEvent::Key(key) => {
+                    let mut app = app.lock().unwrap();
+                    match app.jump.as_mut().map(|jump| jump.update(key)) {
+                        None => None,
+                        Some(JumpResult::Open) => continue,
+                        Some(JumpResult::Closed) => {
+                            app.jump = None;
+                            continue;
+                        }
+                        Some(JumpResult::Jump(target)) => {
+                            app.jump = None;
+                            Some(app.jump_to(&target))
+                        }
+                    }
+                }
+                FlowrsEvent::Mouse(_) if app.lock().unwrap().jump.is_some() => continue,
+                _ => None,
+            };
+            if let Some(messages) = jump_messages {
+                for message in messages {
+                    if let Err(e) = tx_worker.send(message).await {
+                        log::error!("Failed to send message to worker: {e}");
+                    }
+                }
+                continue;
+            }
+            // The command palette takes all input while it is open. Running an
+            // action replays its key on the active panel.
+            let event = match &event {
+                FlowrsEvent::Key(key) => {
+                    let mut app = app.lock().unwrap();
+                    if let Some(palette) = &mut app.palette {
+                        match palette.update(key) {
+                            PaletteResult::Open => continue,
+                            PaletteResult::Closed => {
+                                app.palette = None;
+                                continue;
+                            }
+                            PaletteResult::Run(action) => {
+                                app.palette = None;
+                                app.palette_event(action.id)
+                            }
+                        }
+                    } else if !key.modifiers.is_empty() && keymap().matches(ActionId::Palette, key)
+                    {
+                        app.open_palette();
+                        continue;
+                    } else if !key.modifiers.is_empty() && keymap().matches(ActionId::Jump, key) {
+                        app.open_jump();
+                        continue;
+                    } else {
+                        event
+                    }
+                }
+                FlowrsEvent::Mouse(_) if app.lock().unwrap().palette.is_some() => continue,
+                _ => event,
+            };
+            if let FlowrsEvent::Mouse(mouse) = &event {
+                let mut app = app.lock().unwrap();
+                if app.warning_popup.is_some() {
+                    if is_click(*mouse) {
+                        app.warning_popup = None;
+                    }
+                    continue;
+                }
+                // Clicking a tab switches to its panel, unless a popup of the
+                // active panel is open: the popup gets the click instead
+                if !app.has_open_popup() && is_click_in(*mouse, app.tab_area) {
+                    if let Some(index) = TabBar::tab_at(app.tab_area, mouse.column) {
+                        let panel = Panel::ALL[index].clone();
+                        app.active_panel = panel.clone();
+                        app.sync_panel(&panel);
+                    }
+                    continue;
+                }
+            }
 
             // Then handle panel specific events, and send messages to the event channel
             let (fall_through_event, messages) = app.lock().unwrap().update_active_panel(&event);
@@ -120,6 +249,13 @@ where
                     log::error!("Failed to send message to worker: {e}");
                 }
             }
+            // Requests that need the terminal, such as opening a pager
+            let terminal_request = app.lock().unwrap().logs.terminal_request.take();
+            if let Some(request) = terminal_request {
+                if let Err(e) = terminal::handle_request(terminal, &events, request).await {
+                    app.lock().unwrap().show_error(vec![error_message(&e)]);
+                }
+            }
             if fall_through_event.is_none() {
                 continue;
             }
@@ -134,26 +270,35 @@ where
                 // Handle exit key events
                 if key.modifiers == KeyModifiers::CONTROL {
                     if let KeyCode::Char('c' | 'd') = key.code {
+                        app.save_session();
                         return Ok(());
                     }
                 }
-                // Handle other key events
-                match key.code {
-                    KeyCode::Char('q') => {
+                // Handle other key events; Esc always goes back
+                let keymap = keymap();
+                match keymap.action(&app.active_panel, &key) {
+                    Some(ActionId::Palette) => app.open_palette(),
+                    Some(ActionId::Jump) => app.open_jump(),
+                    Some(ActionId::Quit) => {
                         // Persist config (e.g. active-server selection) on exit, but
                         // a save failure should not turn a normal quit into an error
                         // exit.
-                        if let Err(e) = app.config.write_to_file(&CONFIG_PATHS) {
+                        if let Err(e) = app.save_config() {
                             log::error!("Failed to save config on exit: {e}");
                         }
+                        app.save_session();
                         return Ok(());
                     }
-                    KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => {
+                    _ if keymap.matches(ActionId::Enter, &key)
+                        || keymap.matches(ActionId::NextTab, &key) =>
+                    {
                         app.next_panel();
                         let panel = app.active_panel.clone();
                         app.sync_panel(&panel);
                     }
-                    KeyCode::Esc | KeyCode::Left | KeyCode::Char('h') => {
+                    _ if key.code == KeyCode::Esc
+                        || keymap.matches(ActionId::PreviousTab, &key) =>
+                    {
                         app.previous_panel();
                         let panel = app.active_panel.clone();
                         app.sync_panel(&panel);
@@ -0,0 +1,677 @@
+//! Central registry of the actions a user can run.
+//!
+//! The panels look up which action a key stands for in the
+//! [`keymap`](super::keymap), so this list drives key handling, the help
+//! popups and the command palette alike. The palette runs an action by
+//! replaying its first key on the active panel, which keeps the behaviour
+//! identical to pressing the key.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use strum::{Display, EnumIter, EnumString};
+
+use super::state::{NavigationContext, Panel};
+
+/// An action, named in the `[keys]` config section by its snake case name
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Display, EnumIter, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum ActionId {
+    Enter,
+    Filter,
+    Open,
+    Next,
+    Previous,
+    Top,
+    Bottom,
+    NextTab,
+    PreviousTab,
+    Help,
+    Palette,
+    Jump,
+    Quit,
+    TogglePause,
+    ShowCode,
+    Trigger,
+    Clear,
+    Graph,
+    Visual,
+    Mark,
+    PreviousTry,
+    NextTry,
+    ScrollLeft,
+    ScrollRight,
+    LineStart,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    ToggleRegex,
+    FindTraceback,
+    FindError,
+    FindException,
+    MinLevel,
+    Follow,
+    Wrap,
+    LineNumbers,
+    Timestamps,
+    ExtraFields,
+    Colours,
+    Select,
+    Copy,
+    Save,
+    SaveAll,
+    Pager,
+    Editor,
+    Diff,
+    DiffLayout,
+}
+
+impl ActionId {
+    /// The keys bound when the config does not say otherwise
+    pub fn default_keys(self) -> Vec<KeyEvent> {
+        match self {
+            ActionId::Enter => vec![key(KeyCode::Enter)],
+            ActionId::Filter | ActionId::Search => vec![char('/')],
+            ActionId::Open => vec![char('o')],
+            ActionId::Next => vec![char('j'), key(KeyCode::Down)],
+            ActionId::Previous => vec![char('k'), key(KeyCode::Up)],
+            ActionId::Top => vec![char('g')],
+            ActionId::Bottom => vec![char('G')],
+            ActionId::NextTab | ActionId::NextTry => vec![char('l'), key(KeyCode::Right)],
+            ActionId::PreviousTab => vec![char('h'), key(KeyCode::Left), key(KeyCode::Esc)],
+            ActionId::Help => vec![char('?')],
+            ActionId::Palette => vec![char(':'), ctrl('p')],
+            ActionId::Jump => vec![ctrl('g')],
+            ActionId::Quit => vec![char('q')],
+            ActionId::TogglePause | ActionId::Pager => vec![char('p')],
+            ActionId::ShowCode | ActionId::Select => vec![char('v')],
+            ActionId::Trigger | ActionId::Timestamps => vec![char('t')],
+            ActionId::Clear | ActionId::Colours => vec![char('c')],
+            ActionId::Graph | ActionId::Diff => vec![char('d')],
+            ActionId::Visual => vec![char('V')],
+            ActionId::Mark => vec![char('m')],
+            ActionId::PreviousTry => vec![char('h'), key(KeyCode::Left)],
+            ActionId::ScrollLeft => vec![char('<')],
+            ActionId::ScrollRight => vec![char('>')],
+            ActionId::LineStart => vec![char('0')],
+            ActionId::NextMatch => vec![char('n')],
+            ActionId::PreviousMatch => vec![char('N')],
+            ActionId::ToggleRegex => vec![ctrl('r')],
+            ActionId::FindTraceback => vec![char('T')],
+            ActionId::FindError => vec![char('E')],
+            ActionId::FindException => vec![char('X')],
+            ActionId::MinLevel => vec![char('L')],
+            ActionId::Follow => vec![char('F')],
+            ActionId::Wrap => vec![char('w')],
+            ActionId::LineNumbers => vec![char('#')],
+            ActionId::ExtraFields => vec![char('e')],
+            ActionId::Copy => vec![char('y')],
+            ActionId::Save => vec![char('s')],
+            ActionId::SaveAll => vec![char('S')],
+            ActionId::Editor => vec![char('P')],
+            ActionId::DiffLayout => vec![char('u')],
+        }
+    }
+}
+
+const fn key(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::NONE)
+}
+
+const fn char(c: char) -> KeyEvent {
+    key(KeyCode::Char(c))
+}
+
+const fn ctrl(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+}
+
+/// What has to be selected before an action makes sense
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requires {
+    Nothing,
+    Environment,
+    Dag,
+    DagRun,
+    Task,
+}
+
+impl Requires {
+    pub fn is_met(self, ctx: &NavigationContext) -> bool {
+        match self {
+            Requires::Nothing => true,
+            Requires::Environment => ctx.environment().is_some(),
+            Requires::Dag => ctx.dag_id().is_some(),
+            Requires::DagRun => ctx.dag_run_id().is_some(),
+            Requires::Task => ctx.task_id().is_some(),
+        }
+    }
+}
+
+/// An action as offered on some panels. The same action can be listed for
+/// different panels with a description that fits each of them.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Action {
+    pub id: ActionId,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub panels: &'static [Panel],
+    pub requires: Requires,
+}
+
+impl Action {
+    /// Whether the action can run on `panel` in the given context
+    pub fn is_available(&self, panel: &Panel, ctx: &NavigationContext) -> bool {
+        self.panels.contains(panel) && self.requires.is_met(ctx)
+    }
+}
+
+const TABLES: &[Panel] = &[
+    Panel::Config,
+    Panel::Dag,
+    Panel::DAGRun,
+    Panel::TaskInstance,
+];
+const AIRFLOW_TABLES: &[Panel] = &[Panel::Dag, Panel::DAGRun, Panel::TaskInstance];
+
+/// All actions, grouped by panel with the actions shared by the tables last,
+/// which is the order the help popups list them in
+pub static ACTIONS: &[Action] = &[
+    // DAGs
+    Action {
+        id: ActionId::TogglePause,
+        name: "Toggle pause",
+        description: "Toggle pause/unpause a DAG",
+        panels: &[Panel::Dag],
+        requires: Requires::Environment,
+    },
+    Action {
+        id: ActionId::ShowCode,
+        name: "Show",
+        description: "Show DAG code",
+        panels: &[Panel::Dag],
+        requires: Requires::Environment,
+    },
+    Action {
+        id: ActionId::Trigger,
+        name: "Trigger",
+        description: "Trigger a DAG run",
+        panels: &[Panel::Dag],
+        requires: Requires::Environment,
+    },
+    // DAG runs
+    Action {
+        id: ActionId::Clear,
+        name: "Clear",
+        description: "Clear a DAG run",
+        panels: &[Panel::DAGRun],
+        requires: Requires::Dag,
+    },
+    Action {
+        id: ActionId::Graph,
+        name: "DAG Graph",
+        description: "Show DAG task dependency graph",
+        panels: &[Panel::DAGRun],
+        requires: Requires::Dag,
+    },
+    Action {
+        id: ActionId::ShowCode,
+        name: "Show",
+        description: "Show DAG code",
+        panels: &[Panel::DAGRun],
+        requires: Requires::Dag,
+    },
+    Action {
+        id: ActionId::Visual,
+        name: "Visual",
+        description: "Enter visual selection mode",
+        panels: &[Panel::DAGRun],
+        requires: Requires::Dag,
+    },
+    Action {
+        id: ActionId::Mark,
+        name: "Mark",
+        description: "Mark selected DAG run(s)",
+        panels: &[Panel::DAGRun],
+        requires: Requires::Dag,
+    },
+    Action {
+        id: ActionId::Trigger,
+        name: "Trigger",
+        description: "Trigger a DAG run",
+        panels: &[Panel::DAGRun],
+        requires: Requires::Dag,
+    },
+    // Task instances
+    Action {
+        id: ActionId::Clear,
+        name: "Clear",
+        description: "Clear a task instance",
+        panels: &[Panel::TaskInstance],
+        requires: Requires::DagRun,
+    },
+    Action {
+        id: ActionId::Visual,
+        name: "Visual",
+        description: "Enter visual selection mode",
+        panels: &[Panel::TaskInstance],
+        requires: Requires::DagRun,
+    },
+    Action {
+        id: ActionId::Mark,
+        name: "Mark",
+        description: "Mark selected task instance(s)",
+        panels: &[Panel::TaskInstance],
+        requires: Requires::DagRun,
+    },
+    Action {
+        id: ActionId::Graph,
+        name: "DAG Graph",
+        description: "Show DAG dependency graph",
+        panels: &[Panel::TaskInstance],
+        requires: Requires::DagRun,
+    },
+    // Config
+    Action {
+        id: ActionId::Open,
+        name: "Open",
+        description: "Open Airflow Web UI",
+        panels: &[Panel::Config],
+        requires: Requires::Nothing,
+    },
+    // Logs
+    Action {
+        id: ActionId::Next,
+        name: "Scroll down",
+        description: "Scroll the log down",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Previous,
+        name: "Scroll up",
+        description: "Scroll the log up",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Top,
+        name: "Top",
+        description: "Go to the start of the log",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Bottom,
+        name: "Bottom",
+        description: "Go to the end of the log and follow it",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::PreviousTry,
+        name: "Previous try",
+        description: "Show the log of the previous try",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::NextTry,
+        name: "Next try",
+        description: "Show the log of the next try",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::ScrollLeft,
+        name: "Scroll left",
+        description: "Scroll long lines left",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::ScrollRight,
+        name: "Scroll right",
+        description: "Scroll long lines right",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::LineStart,
+        name: "Line start",
+        description: "Scroll back to the start of the lines",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Search,
+        name: "Search",
+        description: "Search the log",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::NextMatch,
+        name: "Next match",
+        description: "Jump to the next search match",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::PreviousMatch,
+        name: "Previous match",
+        description: "Jump to the previous search match",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::ToggleRegex,
+        name: "Regex",
+        description: "Toggle regex search",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::FindTraceback,
+        name: "Find tracebacks",
+        description: "Search for \"Traceback\"",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::FindError,
+        name: "Find errors",
+        description: "Search for \"ERROR\"",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::FindException,
+        name: "Find exceptions",
+        description: "Search for \"Exception\"",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::MinLevel,
+        name: "Minimum level",
+        description: "Cycle the minimum log level shown",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Follow,
+        name: "Follow",
+        description: "Toggle following the end of the log",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Wrap,
+        name: "Wrap",
+        description: "Toggle wrapping long lines",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::LineNumbers,
+        name: "Line numbers",
+        description: "Toggle line numbers",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Timestamps,
+        name: "Timestamps",
+        description: "Toggle the time column",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::ExtraFields,
+        name: "Extra fields",
+        description: "Toggle the extra fields of structured events",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Colours,
+        name: "Colours",
+        description: "Toggle log colours",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Select,
+        name: "Select",
+        description: "Start or stop selecting lines",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Copy,
+        name: "Copy",
+        description: "Copy the selected lines to the clipboard",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Save,
+        name: "Save",
+        description: "Save this try's log to a file",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::SaveAll,
+        name: "Save all",
+        description: "Save the logs of all tries to files",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Pager,
+        name: "Pager",
+        description: "Open the log in $PAGER",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Editor,
+        name: "Editor",
+        description: "Open the log in $EDITOR",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Diff,
+        name: "Diff",
+        description: "Compare the log with another try",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::DiffLayout,
+        name: "Diff layout",
+        description: "Switch the diff between unified and side by side",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    Action {
+        id: ActionId::Open,
+        name: "Open",
+        description: "Open the log in the browser",
+        panels: &[Panel::Logs],
+        requires: Requires::Task,
+    },
+    // Shared by the tables
+    Action {
+        id: ActionId::Enter,
+        name: "Enter",
+        description: "Open the selected item",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Filter,
+        name: "Filter",
+        description: "Filter items",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Open,
+        name: "Open",
+        description: "Open the selected item in the browser",
+        panels: AIRFLOW_TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Previous,
+        name: "Previous",
+        description: "Move to the previous item",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Next,
+        name: "Next",
+        description: "Move to the next item",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Top,
+        name: "First",
+        description: "Move to the first item",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Bottom,
+        name: "Last",
+        description: "Move to the last item",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::PreviousTab,
+        name: "Previous tab",
+        description: "Move to the previous tab",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::NextTab,
+        name: "Next tab",
+        description: "Move to the next tab",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Help,
+        name: "Help",
+        description: "Show help",
+        panels: TABLES,
+        requires: Requires::Nothing,
+    },
+    // Everywhere
+    Action {
+        id: ActionId::Palette,
+        name: "Command palette",
+        description: "Search and run commands",
+        panels: &Panel::ALL,
+        requires: Requires::Nothing,
+    },
+    Action {
+        id: ActionId::Jump,
+        name: "Jump to",
+        description: "Jump to a DAG, run or task by id",
+        panels: &Panel::ALL,
+        requires: Requires::Environment,
+    },
+    Action {
+        id: ActionId::Quit,
+        name: "Quit",
+        description: "Quit",
+        panels: &Panel::ALL,
+        requires: Requires::Nothing,
+    },
+];
+
+/// The actions listed in the help of `panel`
+pub fn for_panel(panel: &Panel) -> impl Iterator<Item = &'static Action> + '_ {
+    ACTIONS
+        .iter()
+        .filter(move |action| action.panels.contains(panel))
+}
+
+/// The actions that can run on `panel` in the given context
+pub fn available<'a>(
+    panel: &'a Panel,
+    ctx: &'a NavigationContext,
+) -> impl Iterator<Item = &'static Action> + 'a {
+    ACTIONS
+        .iter()
+        .filter(move |action| action.is_available(panel, ctx))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn every_action_is_listed_once_per_panel() {
+        for id in ActionId::iter() {
+            assert!(
+                ACTIONS.iter().any(|action| action.id == id),
+                "{id} is unlisted"
+            );
+        }
+        for panel in &Panel::ALL {
+            let mut ids = HashSet::new();
+            for action in for_panel(panel) {
+                assert!(
+                    ids.insert(action.id),
+                    "{} is listed twice on {panel:?}",
+                    action.id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn availability_follows_the_context() {
+        let environment = NavigationContext::Environment {
+            environment: "prod".into(),
+        };
+        let dag = NavigationContext::Dag {
+            environment: "prod".into(),
+            dag_id: "etl".into(),
+        };
+
+        let ids = |panel, ctx| {
+            available(panel, ctx)
+                .map(|action| action.id)
+                .collect::<Vec<_>>()
+        };
+        assert!(ids(&Panel::DAGRun, &environment).contains(&ActionId::Next));
+        assert!(!ids(&Panel::DAGRun, &environment).contains(&ActionId::Trigger));
+        assert!(ids(&Panel::DAGRun, &dag).contains(&ActionId::Trigger));
+        assert!(!ids(&Panel::Config, &dag).contains(&ActionId::Trigger));
+    }
+
+    #[test]
+    fn names_round_trip() {
+        assert_eq!(ActionId::PreviousTab.to_string(), "previous_tab");
+        assert_eq!(
+            "toggle_pause".parse::<ActionId>(),
+            Ok(ActionId::TogglePause)
+        );
+        assert!("no_such_action".parse::<ActionId>().is_err());
+    }
+}
@@ -1,10 +1,11 @@
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FlowrsEvent {
     Tick,
     Key(KeyEvent),
-    Mouse,
+    Mouse(MouseEvent),
     FocusGained,
     FocusLost,
 }
@@ -13,10 +14,39 @@ impl From<crossterm::event::Event> for FlowrsEvent {
     fn from(ev: crossterm::event::Event) -> Self {
         match ev {
             crossterm::event::Event::Key(key) => Self::Key(key),
-            crossterm::event::Event::Mouse(_) => Self::Mouse,
+            crossterm::event::Event::Mouse(mouse) => Self::Mouse(mouse),
             crossterm::event::Event::FocusGained => Self::FocusGained,
             crossterm::event::Event::FocusLost => Self::FocusLost,
             _ => Self::Tick,
         }
     }
 }
+
+impl FlowrsEvent {
+    /// A key press without modifiers, for mouse actions that behave like a key
+    pub fn key(code: KeyCode) -> Self {
+        Self::Key(KeyEvent::from(code))
+    }
+}
+
+/// Whether the left button was pressed
+pub fn is_click(mouse: MouseEvent) -> bool {
+    mouse.kind == MouseEventKind::Down(MouseButton::Left)
+}
+
+/// Whether the left button was pressed inside `area`
+pub fn is_click_in(mouse: MouseEvent, area: Rect) -> bool {
+    is_click(mouse) && area.contains(Position::new(mouse.column, mouse.row))
+}
+
+/// The arrow key a scroll wheel movement stands for, so views that scroll
+/// with the arrow keys scroll with the wheel too
+pub fn scroll_key(mouse: MouseEvent) -> Option<KeyCode> {
+    match mouse.kind {
+        MouseEventKind::ScrollDown => Some(KeyCode::Down),
+        MouseEventKind::ScrollUp => Some(KeyCode::Up),
+        MouseEventKind::ScrollRight => Some(KeyCode::Right),
+        MouseEventKind::ScrollLeft => Some(KeyCode::Left),
+        _ => None,
+    }
+}
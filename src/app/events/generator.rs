@@ -1,8 +1,9 @@
 use std::time::Duration;
 
-use crossterm::event::EventStream;
+use crossterm::event::{Event, EventStream};
 use futures::StreamExt;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::sync::oneshot;
 use tokio::time::{interval, MissedTickBehavior};
 
 use super::custom::FlowrsEvent;
@@ -12,11 +13,22 @@ pub struct EventGenerator {
     _tick_rate: Duration,
     pub rx_event: Receiver<FlowrsEvent>,
     _tx_event: Sender<FlowrsEvent>,
+    /// Suspends (`true`) or resumes (`false`) reading, acknowledged once done
+    tx_control: Sender<(bool, oneshot::Sender<()>)>,
+}
+
+/// The next terminal event, or never while reading is suspended
+async fn next_event(reader: &mut Option<EventStream>) -> Option<std::io::Result<Event>> {
+    match reader {
+        Some(reader) => reader.next().await,
+        None => std::future::pending().await,
+    }
 }
 
 impl EventGenerator {
     pub fn new(tick_rate: u16) -> Self {
         let (tx_event, rx_event) = channel::<FlowrsEvent>(500);
+        let (tx_control, mut rx_control) = channel::<(bool, oneshot::Sender<()>)>(1);
 
         let tick_rate = Duration::from_millis(u64::from(tick_rate));
         let tx_event_thread = tx_event.clone();
@@ -24,14 +36,21 @@ impl EventGenerator {
         // blocking read on its own thread), so this loop never blocks a tokio
         // worker. Ticks come from an independent timer running alongside it.
         tokio::spawn(async move {
-            let mut reader = EventStream::new();
+            let mut reader = Some(EventStream::new());
             let mut ticker = interval(tick_rate);
             // Don't fire a burst of catch-up ticks if the loop is ever delayed.
             ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
             loop {
                 let event = tokio::select! {
-                    _ = ticker.tick() => FlowrsEvent::Tick,
-                    maybe_event = reader.next() => match maybe_event {
+                    _ = ticker.tick(), if reader.is_some() => FlowrsEvent::Tick,
+                    Some((suspend, ack)) = rx_control.recv() => {
+                        // Dropping the stream stops crossterm from reading the
+                        // terminal, so a foreground program gets all input.
+                        reader = if suspend { None } else { Some(EventStream::new()) };
+                        let _ = ack.send(());
+                        continue;
+                    }
+                    maybe_event = next_event(&mut reader) => match maybe_event {
                         Some(Ok(ev)) => FlowrsEvent::from(ev),
                         // A read error (terminal unusable) or a closed source both
                         // mean there is nothing more to read, so stop the loop
@@ -51,6 +70,24 @@ impl EventGenerator {
             _tick_rate: tick_rate,
             rx_event,
             _tx_event: tx_event,
+            tx_control,
+        }
+    }
+
+    /// Stop reading terminal events and ticks, e.g. while a pager runs
+    pub async fn suspend(&self) {
+        self.set_suspended(true).await;
+    }
+
+    /// Start reading terminal events and ticks again
+    pub async fn resume(&self) {
+        self.set_suspended(false).await;
+    }
+
+    async fn set_suspended(&self, suspended: bool) {
+        let (ack, done) = oneshot::channel();
+        if self.tx_control.send((suspended, ack)).await.is_ok() {
+            let _ = done.await;
         }
     }
 
@@ -0,0 +1,359 @@
+//! Key bindings of the actions, with the defaults overridden by the `[keys]`
+//! section of the config.
+//!
+//! Call [`init_keymap`] once at startup, then look keys up anywhere via
+//! [`keymap()`]:
+//! ```ignore
+//! match keymap().action(&Panel::Dag, key) {
+//!     Some(ActionId::Trigger) => ...,
+//!     _ => ...,
+//! }
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::OnceLock;
+
+use anyhow::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use flowrs_config::KeyBinding;
+use strum::IntoEnumIterator;
+
+use super::actions::{self, ActionId};
+use super::state::Panel;
+
+/// Keys that always quit, so they cannot be bound to anything else
+const RESERVED: [KeyEvent; 2] = [
+    KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+    KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL),
+];
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: BTreeMap<ActionId, Vec<KeyEvent>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: ActionId::iter().map(|id| (id, id.default_keys())).collect(),
+        }
+    }
+}
+
+impl Keymap {
+    /// Build the keymap from the `[keys]` config section. Returns the errors
+    /// found in it; when any binding conflicts, the defaults are used.
+    pub fn from_config(overrides: &BTreeMap<String, KeyBinding>) -> (Self, Vec<String>) {
+        let mut keymap = Self::default();
+        let mut errors = Vec::new();
+        for (name, binding) in overrides {
+            let Ok(id) = name.parse::<ActionId>() else {
+                errors.push(format!("Unknown action `{name}` in [keys]"));
+                continue;
+            };
+            match binding.keys().iter().map(|key| parse_key(key)).collect() {
+                Ok(keys) => {
+                    let keys: Vec<KeyEvent> = keys;
+                    if keys.is_empty() {
+                        errors.push(format!("`{name}` in [keys] needs at least one key"));
+                    } else {
+                        keymap.bindings.insert(id, keys);
+                    }
+                }
+                Err(e) => errors.push(format!("Invalid key for `{name}` in [keys]: {e}")),
+            }
+        }
+
+        let conflicts = keymap.conflicts();
+        if !conflicts.is_empty() {
+            errors.extend(conflicts);
+            errors.push("Using the default key bindings until the conflicts are resolved".into());
+            keymap = Self::default();
+        }
+        (keymap, errors)
+    }
+
+    /// Keys bound to more than one action on the same panel, or to a key that
+    /// always quits
+    fn conflicts(&self) -> Vec<String> {
+        let mut conflicts = BTreeSet::new();
+        for (id, keys) in &self.bindings {
+            for key in keys.iter().filter(|key| RESERVED.contains(key)) {
+                conflicts.insert(format!(
+                    "`{}` always quits and cannot be bound to `{id}`",
+                    format_key(key)
+                ));
+            }
+        }
+        for panel in &Panel::ALL {
+            let ids: BTreeSet<ActionId> = actions::for_panel(panel).map(|a| a.id).collect();
+            let mut bound: HashMap<(KeyCode, KeyModifiers), ActionId> = HashMap::new();
+            for id in ids {
+                for key in self.keys(id) {
+                    if let Some(other) = bound.insert((key.code, key.modifiers), id) {
+                        conflicts.insert(format!(
+                            "`{}` is bound to both `{other}` and `{id}`",
+                            format_key(key)
+                        ));
+                    }
+                }
+            }
+        }
+        conflicts.into_iter().collect()
+    }
+
+    pub fn keys(&self, id: ActionId) -> &[KeyEvent] {
+        self.bindings.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// The first key bound to `id`, for replaying the action
+    pub fn key(&self, id: ActionId) -> KeyEvent {
+        self.keys(id)
+            .first()
+            .copied()
+            .unwrap_or_else(|| id.default_keys()[0])
+    }
+
+    /// The key presses that run `id`: Top is pressed twice, like `gg` in vim
+    pub fn presses(&self, id: ActionId) -> Vec<KeyEvent> {
+        let key = self.key(id);
+        if id == ActionId::Top {
+            vec![key, key]
+        } else {
+            vec![key]
+        }
+    }
+
+    /// Whether `key` is bound to `id`
+    pub fn matches(&self, id: ActionId, key: &KeyEvent) -> bool {
+        let key = normalize(*key);
+        self.keys(id)
+            .iter()
+            .any(|bound| bound.code == key.code && bound.modifiers == key.modifiers)
+    }
+
+    /// The action `key` stands for on `panel`
+    pub fn action(&self, panel: &Panel, key: &KeyEvent) -> Option<ActionId> {
+        actions::for_panel(panel)
+            .map(|action| action.id)
+            .find(|&id| self.matches(id, key))
+    }
+
+    /// The bindings of `id` as shown in help, e.g. `k / Up`
+    pub fn hint(&self, id: ActionId) -> String {
+        self.keys(id)
+            .iter()
+            .map(|key| {
+                let key = format_key(key);
+                // Top is pressed twice, like `gg` in vim
+                if id == ActionId::Top {
+                    format!("{key}{key}")
+                } else {
+                    key
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+static KEYMAP: OnceLock<Keymap> = OnceLock::new();
+
+/// Initialize the global keymap from the `[keys]` config section and return
+/// the problems found in it.
+///
+/// Idempotent: subsequent calls are silently ignored if the keymap is already set.
+pub fn init_keymap(overrides: &BTreeMap<String, KeyBinding>) -> Vec<String> {
+    let (keymap, errors) = Keymap::from_config(overrides);
+    KEYMAP.set(keymap).ok();
+    errors
+}
+
+/// Returns the active keymap, the defaults if [`init_keymap`] was not called.
+pub fn keymap() -> &'static Keymap {
+    KEYMAP.get_or_init(Keymap::default)
+}
+
+/// Terminals report uppercase letters with Shift held; the letter alone
+/// identifies the key.
+fn normalize(key: KeyEvent) -> KeyEvent {
+    let mut modifiers = key.modifiers;
+    let code = match key.code {
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::Char(c.to_ascii_uppercase())
+        }
+        KeyCode::BackTab => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::BackTab
+        }
+        code => code,
+    };
+    KeyEvent::new(code, modifiers)
+}
+
+/// Parse a key such as `j`, `G`, `Down`, `ctrl-n` or `alt-Enter`
+pub fn parse_key(text: &str) -> Result<KeyEvent> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = text;
+    // Modifiers are prefixes ending in `-`; a trailing `-` is the key itself
+    while let Some((prefix, key)) = rest.split_once('-') {
+        if key.is_empty() {
+            break;
+        }
+        modifiers |= match prefix.to_ascii_lowercase().as_str() {
+            "ctrl" | "c" => KeyModifiers::CONTROL,
+            "alt" | "meta" | "m" => KeyModifiers::ALT,
+            "shift" | "s" => KeyModifiers::SHIFT,
+            _ => bail!("unknown modifier `{prefix}` in `{text}`"),
+        };
+        rest = key;
+    }
+
+    let code = match rest.to_ascii_lowercase().as_str() {
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "space" => KeyCode::Char(' '),
+        name => {
+            let mut chars = rest.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => match name.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                    Some(n @ 1..=24) => KeyCode::F(n),
+                    _ => bail!("unknown key `{text}`"),
+                },
+            }
+        }
+    };
+    Ok(normalize(KeyEvent::new(code, modifiers)))
+}
+
+/// Show a key the way [`parse_key`] reads it
+pub fn format_key(key: &KeyEvent) -> String {
+    let name = match key.code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("F{n}"),
+        // Named keys such as `Enter` or `PageUp` read the same in crossterm
+        code => format!("{code:?}"),
+    };
+    let mut text = String::new();
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        text.push_str("Ctrl-");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        text.push_str("Alt-");
+    }
+    if key.modifiers.contains(KeyModifiers::SHIFT) {
+        text.push_str("Shift-");
+    }
+    text + &name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides(entries: &[(&str, &[&str])]) -> BTreeMap<String, KeyBinding> {
+        entries
+            .iter()
+            .map(|(name, keys)| {
+                (
+                    (*name).to_string(),
+                    KeyBinding::Many(keys.iter().map(ToString::to_string).collect()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_and_formats_keys() {
+        for (text, shown) in [
+            ("j", "j"),
+            ("G", "G"),
+            ("shift-g", "G"),
+            ("-", "-"),
+            ("ctrl--", "Ctrl--"),
+            ("Down", "Down"),
+            ("ctrl-n", "Ctrl-n"),
+            ("C-n", "Ctrl-n"),
+            ("M-x", "Alt-x"),
+            ("alt-Enter", "Alt-Enter"),
+            ("space", "Space"),
+            ("F5", "F5"),
+            ("f", "f"),
+        ] {
+            assert_eq!(format_key(&parse_key(text).unwrap()), shown, "{text}");
+        }
+        assert!(parse_key("hyper-x").is_err());
+        assert!(parse_key("jj").is_err());
+        assert!(parse_key("").is_err());
+    }
+
+    #[test]
+    fn defaults_have_no_conflicts() {
+        assert!(Keymap::default().conflicts().is_empty());
+        let (_, errors) = Keymap::from_config(&BTreeMap::new());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn overrides_replace_the_defaults() {
+        let (keymap, errors) = Keymap::from_config(&overrides(&[
+            ("next", &["j", "Down", "ctrl-n"]),
+            ("trigger", &["x"]),
+        ]));
+        assert!(errors.is_empty(), "{errors:?}");
+
+        let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action(&Panel::Dag, &ctrl_n), Some(ActionId::Next));
+        let x = KeyEvent::from(KeyCode::Char('x'));
+        assert_eq!(keymap.action(&Panel::Dag, &x), Some(ActionId::Trigger));
+        let t = KeyEvent::from(KeyCode::Char('t'));
+        assert_eq!(keymap.action(&Panel::Dag, &t), None);
+        // Shift is implied by an uppercase letter
+        let shift_g = KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT);
+        assert_eq!(keymap.action(&Panel::Dag, &shift_g), Some(ActionId::Bottom));
+
+        assert_eq!(keymap.hint(ActionId::Next), "j / Down / Ctrl-n");
+        assert_eq!(keymap.hint(ActionId::Top), "gg");
+    }
+
+    #[test]
+    fn reports_problems_and_falls_back_on_conflicts() {
+        let (keymap, errors) = Keymap::from_config(&overrides(&[
+            ("teleport", &["J"]),
+            ("open", &["hyper-o"]),
+            ("mark", &[]),
+        ]));
+        assert_eq!(errors.len(), 3, "{errors:?}");
+        // Valid overrides still apply when nothing conflicts
+        assert_eq!(keymap.hint(ActionId::Mark), "m");
+
+        // `t` triggers on the DAG panel but toggles timestamps in the logs,
+        // so only the DAG panel conflicts
+        let (keymap, errors) =
+            Keymap::from_config(&overrides(&[("toggle_pause", &["t"]), ("wrap", &["W"])]));
+        assert_eq!(
+            errors[0],
+            "`t` is bound to both `toggle_pause` and `trigger`"
+        );
+        assert_eq!(keymap.hint(ActionId::Wrap), "w");
+
+        let (_, errors) = Keymap::from_config(&overrides(&[("quit", &["ctrl-c"])]));
+        assert!(errors[0].contains("always quits"));
+    }
+}
@@ -7,11 +7,12 @@ pub mod dagruns;
 pub mod dags;
 pub mod filter;
 pub mod filterable_table;
+pub mod fuzzy;
 pub mod logs;
 pub mod popup;
 pub mod taskinstances;
 
-pub use filterable_table::FilterableTable;
+pub use filterable_table::{FilterableTable, TableMouse};
 pub use popup::Popup;
 
 /// Result of handling a key event in the chain of responsibility pattern.
@@ -6,15 +6,19 @@ use ratatui::text::Line;
 use ratatui::widgets::{Block, BorderType, Borders, Row, StatefulWidget, Table, Widget};
 
 use crate::airflow::model::common::OpenItem;
+use crate::app::actions::ActionId;
 use crate::app::events::custom::FlowrsEvent;
+use crate::app::keymap::keymap;
+use crate::app::state::Panel;
 use crate::app::worker::WorkerMessage;
 use crate::ui::theme::theme;
-use flowrs_config::AirflowConfig;
+use flowrs_airflow::client::version::cached_version;
+use flowrs_config::{AirflowConfig, AirflowVersion};
 
 mod commands;
 
-use super::{FilterableTable, KeyResult, Model, Popup};
-use crate::ui::common::create_headers;
+use super::{FilterableTable, KeyResult, Model, Popup, TableMouse};
+use crate::ui::common::{create_headers, help_title};
 use commands::CONFIG_COMMAND_POP_UP;
 
 #[derive(Debug)]
@@ -50,8 +54,8 @@ impl ConfigModel {
 
     /// Handle model-specific keys
     fn handle_keys(&mut self, key_event: &KeyEvent) -> KeyResult {
-        match key_event.code {
-            KeyCode::Char('o') => {
+        match keymap().action(&Panel::Config, key_event) {
+            Some(ActionId::Open) => {
                 if let Some(idx) = self.table.filtered.state.selected() {
                     if let Some(item) = self.table.filtered.items.get(idx) {
                         return KeyResult::PassWith(vec![WorkerMessage::OpenItem(
@@ -61,11 +65,11 @@ impl ConfigModel {
                 }
                 KeyResult::PassThrough
             }
-            KeyCode::Char('?') => {
+            Some(ActionId::Help) => {
                 self.popup.show_commands(&CONFIG_COMMAND_POP_UP);
                 KeyResult::Consumed
             }
-            KeyCode::Enter => {
+            Some(ActionId::Enter) => {
                 if let Some(idx) = self.table.filtered.state.selected() {
                     if let Some(item) = self.table.filtered.items.get(idx) {
                         debug!("Selected config: {}", item.name);
@@ -79,6 +83,19 @@ impl ConfigModel {
     }
 }
 
+/// Version column label. `auto` servers show the detected version once known.
+fn version_label(config: &AirflowConfig) -> String {
+    match config.version {
+        AirflowVersion::V2 => "v2".to_string(),
+        AirflowVersion::V3 => "v3".to_string(),
+        AirflowVersion::Auto => match cached_version(&config.endpoint) {
+            Some(AirflowVersion::V2) => "auto (v2)".to_string(),
+            Some(AirflowVersion::V3) => "auto (v3)".to_string(),
+            _ => "auto".to_string(),
+        },
+    }
+}
+
 impl Model for ConfigModel {
     fn update(
         &mut self,
@@ -94,15 +111,27 @@ impl Model for ConfigModel {
                     .or_else(|| self.popup.handle_dismiss(key_event.code))
                     .or_else(|| {
                         self.table
-                            .handle_navigation(key_event.code, &mut self.event_buffer)
+                            .handle_navigation(key_event, &mut self.event_buffer)
                     })
                     .or_else(|| self.handle_keys(key_event));
 
                 result.into_result(event)
             }
-            FlowrsEvent::Mouse | FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => {
-                (Some(event.clone()), vec![])
+            FlowrsEvent::Mouse(mouse) => {
+                if let KeyResult::Consumed = self.popup.handle_click(*mouse) {
+                    return (None, vec![]);
+                }
+                match self.table.handle_mouse(*mouse) {
+                    TableMouse::Activated => {
+                        let enter = keymap().key(ActionId::Enter);
+                        self.handle_keys(&enter)
+                            .into_result(&FlowrsEvent::Key(enter))
+                    }
+                    TableMouse::Moved => (None, vec![]),
+                    TableMouse::Ignored => (Some(event.clone()), vec![]),
+                }
             }
+            FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => (Some(event.clone()), vec![]),
         }
     }
 }
@@ -116,6 +145,20 @@ impl Widget for &mut ConfigModel {
         let header_row = create_headers(headers);
         let header = Row::new(header_row).style(t.table_header_style);
 
+        let block = {
+            let block = Block::default()
+                .border_type(BorderType::Rounded)
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+                .border_style(t.border_style)
+                .title(help_title());
+            if let Some(title) = self.table.status_title() {
+                block.title_bottom(title)
+            } else {
+                block
+            }
+        };
+        let table_area = block.inner(content_area);
+
         let rows = self
             .table
             .filtered
@@ -131,10 +174,7 @@ impl Widget for &mut ConfigModel {
                             .as_ref()
                             .map_or_else(|| "None".to_string(), ToString::to_string),
                     ),
-                    Line::from(match item.version {
-                        flowrs_config::AirflowVersion::V2 => "v2",
-                        flowrs_config::AirflowVersion::V3 => "v3",
-                    }),
+                    Line::from(version_label(item)),
                 ])
                 .style(self.table.row_style(idx))
             });
@@ -145,24 +185,14 @@ impl Widget for &mut ConfigModel {
                 Constraint::Percentage(20),
                 Constraint::Percentage(55),
                 Constraint::Percentage(15),
-                Constraint::Min(8),
+                Constraint::Min(9),
             ],
         )
         .header(header)
-        .block({
-            let block = Block::default()
-                .border_type(BorderType::Rounded)
-                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
-                .border_style(t.border_style)
-                .title(" Press <?> to see available commands ");
-            if let Some(title) = self.table.status_title() {
-                block.title_bottom(title)
-            } else {
-                block
-            }
-        })
+        .block(block)
         .row_highlight_style(t.selected_row_style);
         StatefulWidget::render(t, content_area, buf, &mut self.table.filtered.state);
+        self.table.set_rendered_area(table_area);
 
         // Render any active popup (error or commands)
         (&self.popup).render(area, buf);
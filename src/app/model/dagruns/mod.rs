@@ -3,15 +3,20 @@ mod dag_code_view;
 pub mod popup;
 mod render;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent};
 use log::debug;
 use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 
 use crate::airflow::model::common::{DagRun, DagRunId, DagRunState};
-use crate::app::events::custom::FlowrsEvent;
-
-use super::{FilterableTable, KeyResult, Model, Popup};
+use crate::app::actions::ActionId;
+use crate::app::events::custom::{scroll_key, FlowrsEvent};
+use crate::app::keymap::keymap;
+use crate::app::model::filter::dag_run_filter;
+use crate::app::state::Panel;
+use flowrs_airflow::DagRunFilter;
+
+use super::{FilterableTable, KeyResult, Model, Popup, TableMouse};
 use crate::airflow::model::common::OpenItem;
 use crate::app::worker::WorkerMessage;
 use commands::DAGRUN_COMMAND_POP_UP;
@@ -29,6 +34,8 @@ pub struct DagRunModel {
     pub table: FilterableTable<DagRun>,
     /// Unified popup state (error, commands, or custom for this model)
     pub popup: Popup<DagRunPopUp>,
+    /// The part of the table filter the API applies when listing runs
+    pub server_filter: DagRunFilter,
     ticks: u32,
     poll_tick_multiplier: u32,
     event_buffer: Vec<KeyCode>,
@@ -40,6 +47,7 @@ impl Default for DagRunModel {
             dag_code: None,
             table: FilterableTable::new(),
             popup: Popup::None,
+            server_filter: DagRunFilter::default(),
             ticks: 0,
             poll_tick_multiplier: 10,
             event_buffer: Vec::new(),
@@ -172,14 +180,48 @@ impl DagRunModel {
         Some(messages)
     }
 
+    /// Request the next page of runs once the selection nears the loaded end
+    fn next_page_request(
+        &mut self,
+        ctx: &crate::app::state::NavigationContext,
+    ) -> Option<WorkerMessage> {
+        let dag_id = ctx.dag_id()?;
+        self.table
+            .request_next_page()
+            .then(|| WorkerMessage::LoadMoreDagRuns {
+                dag_id: dag_id.clone(),
+            })
+    }
+
+    /// Recompute the server-side filter from the confirmed table filter. When
+    /// it changes, the runs are fetched again so matches beyond the loaded
+    /// pages show up.
+    fn refresh_server_filter(
+        &mut self,
+        ctx: &crate::app::state::NavigationContext,
+    ) -> Vec<WorkerMessage> {
+        let server_filter = dag_run_filter(&self.table.filter.confirmed_conditions());
+        if server_filter == self.server_filter {
+            return vec![];
+        }
+        self.server_filter = server_filter;
+        ctx.dag_id()
+            .map(|dag_id| {
+                vec![WorkerMessage::UpdateDagRuns {
+                    dag_id: dag_id.clone(),
+                }]
+            })
+            .unwrap_or_default()
+    }
+
     /// Handle model-specific keys
     fn handle_keys(
         &mut self,
-        key_code: KeyCode,
+        key: &KeyEvent,
         ctx: &crate::app::state::NavigationContext,
     ) -> KeyResult {
-        match key_code {
-            KeyCode::Char('t') => {
+        match keymap().action(&Panel::DAGRun, key) {
+            Some(ActionId::Trigger) => {
                 if let Some(dag_id) = ctx.dag_id() {
                     // The worker fetches a fresh param schema (falling back to
                     // the cached one) and opens the trigger popup once ready.
@@ -189,7 +231,7 @@ impl DagRunModel {
                 }
                 KeyResult::Consumed
             }
-            KeyCode::Char('m') => {
+            Some(ActionId::Mark) => {
                 let dag_run_ids = self.selected_dag_run_ids();
                 if let Some(dag_id) = ctx.dag_id() {
                     if !dag_run_ids.is_empty() {
@@ -202,11 +244,11 @@ impl DagRunModel {
                 }
                 KeyResult::Consumed
             }
-            KeyCode::Char('?') => {
+            Some(ActionId::Help) => {
                 self.popup.show_commands(&DAGRUN_COMMAND_POP_UP);
                 KeyResult::Consumed
             }
-            KeyCode::Char('d') => {
+            Some(ActionId::Graph) => {
                 if let (Some(dag_id), Some(dag_run)) = (ctx.dag_id(), &self.current()) {
                     KeyResult::ConsumedWith(vec![WorkerMessage::ShowDagGraph {
                         dag_id: dag_id.clone(),
@@ -216,7 +258,7 @@ impl DagRunModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('v') => {
+            Some(ActionId::ShowCode) => {
                 if let Some(dag_id) = ctx.dag_id() {
                     KeyResult::ConsumedWith(vec![WorkerMessage::GetDagCode {
                         dag_id: dag_id.clone(),
@@ -225,7 +267,7 @@ impl DagRunModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('c') => {
+            Some(ActionId::Clear) => {
                 let dag_run_ids = self.selected_dag_run_ids();
                 if let Some(dag_id) = ctx.dag_id() {
                     if !dag_run_ids.is_empty() {
@@ -238,7 +280,7 @@ impl DagRunModel {
                 }
                 KeyResult::Consumed
             }
-            KeyCode::Enter => {
+            Some(ActionId::Enter) => {
                 if let (Some(dag_id), Some(dag_run)) = (ctx.dag_id(), &self.current()) {
                     KeyResult::PassWith(vec![
                         WorkerMessage::UpdateTasks {
@@ -253,7 +295,7 @@ impl DagRunModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('o') => {
+            Some(ActionId::Open) => {
                 if let (Some(dag_id), Some(dag_run)) = (ctx.dag_id(), &self.current()) {
                     KeyResult::PassWith(vec![WorkerMessage::OpenItem(OpenItem::DagRun {
                         dag_id: dag_id.clone(),
@@ -296,7 +338,7 @@ impl Model for DagRunModel {
                     KeyResult::Consumed | KeyResult::ConsumedWith(_)
                 ) {
                     self.sort_dag_runs();
-                    return (None, vec![]);
+                    return (None, self.refresh_server_filter(ctx));
                 }
 
                 // Popup handling (has its own update method)
@@ -309,18 +351,39 @@ impl Model for DagRunModel {
                     .popup
                     .handle_dismiss(key_event.code)
                     .or_else(|| self.handle_dag_code_viewer(key_event.code))
-                    .or_else(|| self.table.handle_visual_mode_key(key_event.code))
+                    .or_else(|| self.table.handle_visual_mode_key(key_event))
                     .or_else(|| {
                         self.table
-                            .handle_navigation(key_event.code, &mut self.event_buffer)
+                            .handle_navigation(key_event, &mut self.event_buffer)
                     })
-                    .or_else(|| self.handle_keys(key_event.code, ctx));
+                    .or_else(|| self.handle_keys(key_event, ctx));
 
-                result.into_result(event)
+                let (event, mut messages) = result.into_result(event);
+                messages.extend(self.next_page_request(ctx));
+                (event, messages)
             }
-            FlowrsEvent::Mouse | FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => {
-                (Some(event.clone()), vec![])
+            FlowrsEvent::Mouse(mouse) => {
+                if let Some(messages) = self.handle_popup(event, ctx) {
+                    return (None, messages);
+                }
+
+                // The wheel scrolls the code viewer like the arrow keys
+                let result = self.popup.handle_click(*mouse).or_else(|| {
+                    self.handle_dag_code_viewer(scroll_key(*mouse).unwrap_or(KeyCode::Null))
+                });
+                if !matches!(result, KeyResult::Ignored) {
+                    return result.into_result(event);
+                }
+
+                match self.table.handle_mouse(*mouse) {
+                    TableMouse::Activated => {
+                        self.update(&FlowrsEvent::Key(keymap().key(ActionId::Enter)), ctx)
+                    }
+                    TableMouse::Moved => (None, self.next_page_request(ctx).into_iter().collect()),
+                    TableMouse::Ignored => (Some(event.clone()), vec![]),
+                }
             }
+            FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => (Some(event.clone()), vec![]),
         }
     }
 }
@@ -8,7 +8,7 @@ use ratatui::{
 use crate::app::{
     events::custom::FlowrsEvent,
     model::{
-        popup::{popup_area, render_yes_no, SelectedButton},
+        popup::{popup_area, render_yes_no, ButtonAreas, SelectedButton},
         Model,
     },
     worker::WorkerMessage,
@@ -22,6 +22,7 @@ pub struct ClearDagRunPopup {
     pub dag_run_ids: Vec<DagRunId>,
     pub dag_id: DagId,
     pub(crate) selected_button: SelectedButton,
+    buttons: ButtonAreas<SelectedButton>,
 }
 
 impl ClearDagRunPopup {
@@ -30,6 +31,7 @@ impl ClearDagRunPopup {
             dag_run_ids,
             dag_id,
             selected_button: SelectedButton::default(),
+            buttons: ButtonAreas::default(),
         }
     }
 }
@@ -40,6 +42,15 @@ impl Model for ClearDagRunPopup {
         event: &FlowrsEvent,
         _ctx: &crate::app::state::NavigationContext,
     ) -> (Option<FlowrsEvent>, Vec<WorkerMessage>) {
+        // Clicking a button selects and confirms it
+        let click;
+        let event = if let Some(button) = self.buttons.clicked(event) {
+            self.selected_button = button;
+            click = FlowrsEvent::key(KeyCode::Enter);
+            &click
+        } else {
+            event
+        };
         if let FlowrsEvent::Key(key_event) = event {
             match key_event.code {
                 KeyCode::Enter => {
@@ -112,6 +123,6 @@ impl Widget for &mut ClearDagRunPopup {
         Clear.render(area, buffer);
         popup_block.render(area, buffer);
         text.render(header, buffer);
-        render_yes_no(options, buffer, self.selected_button.is_yes(), true);
+        self.buttons = render_yes_no(options, buffer, self.selected_button.is_yes(), true);
     }
 }
@@ -9,7 +9,10 @@ use strum::Display;
 use crate::airflow::model::common::{DagId, DagRunId, DagRunState};
 use crate::app::{
     events::custom::FlowrsEvent,
-    model::{popup::popup_area, Model},
+    model::{
+        popup::{popup_area, ButtonAreas},
+        Model,
+    },
     worker::WorkerMessage,
 };
 use crate::ui::theme::theme;
@@ -19,6 +22,7 @@ pub struct MarkDagRunPopup {
     pub dag_id: DagId,
     pub status: MarkState,
     pub marked: Vec<DagRunId>,
+    buttons: ButtonAreas<MarkState>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
@@ -42,11 +46,12 @@ impl From<&MarkState> for DagRunState {
 }
 
 impl MarkDagRunPopup {
-    pub const fn new(marked: Vec<DagRunId>, dag_id: DagId) -> Self {
+    pub fn new(marked: Vec<DagRunId>, dag_id: DagId) -> Self {
         Self {
             dag_id,
             status: MarkState::Success,
             marked,
+            buttons: ButtonAreas::default(),
         }
     }
 
@@ -73,6 +78,15 @@ impl Model for MarkDagRunPopup {
         event: &FlowrsEvent,
         _ctx: &crate::app::state::NavigationContext,
     ) -> (Option<FlowrsEvent>, Vec<WorkerMessage>) {
+        // Clicking a button selects and confirms it
+        let click;
+        let event = if let Some(status) = self.buttons.clicked(event) {
+            self.status = status;
+            click = FlowrsEvent::key(KeyCode::Enter);
+            &click
+        } else {
+            event
+        };
         if let FlowrsEvent::Key(key_event) = event {
             match key_event.code {
                 KeyCode::Enter => {
@@ -198,5 +212,10 @@ impl Widget for &mut MarkDagRunPopup {
         success_btn.render(success, buffer);
         failed_btn.render(failed, buffer);
         queued_btn.render(queued, buffer);
+        self.buttons = ButtonAreas::new(vec![
+            (MarkState::Success, success),
+            (MarkState::Failed, failed),
+            (MarkState::Queued, queued),
+        ]);
     }
 }
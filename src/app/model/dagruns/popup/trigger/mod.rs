@@ -8,7 +8,10 @@ use ratatui::widgets::TableState;
 
 use crate::app::{
     events::custom::FlowrsEvent,
-    model::{popup::SelectedButton, Model},
+    model::{
+        popup::{ButtonAreas, SelectedButton},
+        Model,
+    },
     worker::WorkerMessage,
 };
 
@@ -34,6 +37,7 @@ pub struct TriggerDagRunPopUp {
     pub(crate) table_state: TableState,
     pub(crate) focus: FocusZone,
     pub(crate) selected_button: SelectedButton,
+    pub(crate) buttons: ButtonAreas<SelectedButton>,
 }
 
 impl TriggerDagRunPopUp {
@@ -47,6 +51,7 @@ impl TriggerDagRunPopUp {
             table_state: TableState::default(),
             focus: FocusZone::Params,
             selected_button: SelectedButton::default(),
+            buttons: ButtonAreas::default(),
         }
     }
 
@@ -112,6 +117,18 @@ impl Model for TriggerDagRunPopUp {
         event: &FlowrsEvent,
         _ctx: &crate::app::state::NavigationContext,
     ) -> (Option<FlowrsEvent>, Vec<WorkerMessage>) {
+        // Clicking a button selects and confirms it, also while a param is
+        // being edited
+        let click;
+        let event = if let Some(button) = self.buttons.clicked(event) {
+            self.selected_button = button;
+            self.editing = false;
+            self.focus = FocusZone::Buttons;
+            click = FlowrsEvent::key(KeyCode::Enter);
+            &click
+        } else {
+            event
+        };
         if let FlowrsEvent::Key(key_event) = event {
             if self.has_params() {
                 return self.update_with_params(key_event.code, *key_event);
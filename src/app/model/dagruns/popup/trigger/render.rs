@@ -65,7 +65,7 @@ impl TriggerDagRunPopUp {
         Clear.render(area, buffer);
         popup_block.render(area, buffer);
         text.render(header, buffer);
-        render_yes_no(options, buffer, self.selected_button.is_yes(), true);
+        self.buttons = render_yes_no(options, buffer, self.selected_button.is_yes(), true);
     }
 
     fn render_with_params(&mut self, area: Rect, buffer: &mut Buffer) {
@@ -162,7 +162,7 @@ impl TriggerDagRunPopUp {
         StatefulWidget::render(table, table_area, buffer, &mut self.table_state);
 
         // Buttons (highlight only when the buttons zone is focused).
-        render_yes_no(
+        self.buttons = render_yes_no(
             buttons_area,
             buffer,
             self.selected_button.is_yes(),
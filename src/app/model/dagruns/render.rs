@@ -7,7 +7,7 @@ use ratatui::widgets::{Block, BorderType, Borders, Row, StatefulWidget, Table, W
 use time::format_description;
 
 use crate::airflow::model::common::{calculate_duration, format_duration};
-use crate::ui::common::create_headers;
+use crate::ui::common::{create_headers, help_title};
 use crate::ui::constants::AirflowStateColor;
 use crate::ui::theme::theme;
 use crate::ui::TIME_FORMAT;
@@ -56,6 +56,23 @@ impl Widget for &mut DagRunModel {
             .saturating_sub(fixed_columns_width + dag_run_id_width)
             .max(10) as usize;
 
+        let block = {
+            let mut block = Block::default()
+                .border_type(BorderType::Rounded)
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+                .border_style(t.border_style)
+                .title(help_title());
+            if let Some(paging) = self.table.paging_title() {
+                block = block.title_top(Line::from(paging).right_aligned());
+            }
+            if let Some(title) = self.table.status_title() {
+                block.title_bottom(title)
+            } else {
+                block
+            }
+        };
+        let table_area = block.inner(content_area);
+
         let rows = self
             .table
             .filtered
@@ -109,20 +126,10 @@ impl Widget for &mut DagRunModel {
             ],
         )
         .header(header)
-        .block({
-            let block = Block::default()
-                .border_type(BorderType::Rounded)
-                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
-                .border_style(t.border_style)
-                .title(" Press <?> to see available commands ");
-            if let Some(title) = self.table.status_title() {
-                block.title_bottom(title)
-            } else {
-                block
-            }
-        })
+        .block(block)
         .row_highlight_style(t.selected_row_style);
         StatefulWidget::render(t, content_area, buf, &mut self.table.filtered.state);
+        self.table.set_rendered_area(table_area);
 
         if let Some(view) = &mut self.dag_code {
             view.render(area, buf);
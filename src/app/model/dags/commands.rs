@@ -1,28 +1,7 @@
 use std::sync::LazyLock;
 
-use crate::app::model::popup::commands_help::{Command, CommandPopUp, DefaultCommands};
+use crate::app::model::popup::commands_help::CommandPopUp;
+use crate::app::state::Panel;
 
-pub static DAG_COMMAND_POP_UP: LazyLock<CommandPopUp> = LazyLock::new(|| {
-    let mut commands = vec![
-        Command {
-            name: "Toggle pause",
-            key_binding: "p",
-            description: "Toggle pause/unpause a DAG",
-        },
-        Command {
-            name: "Show",
-            key_binding: "v",
-            description: "Show DAG code",
-        },
-        Command {
-            name: "Trigger",
-            key_binding: "t",
-            description: "Trigger a DAG run",
-        },
-    ];
-    commands.append(&mut DefaultCommands::new().0);
-    CommandPopUp {
-        title: "DAG Commands".into(),
-        commands,
-    }
-});
+pub static DAG_COMMAND_POP_UP: LazyLock<CommandPopUp> =
+    LazyLock::new(|| CommandPopUp::for_panel("DAG Commands", &Panel::Dag));
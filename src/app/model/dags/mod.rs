@@ -4,15 +4,20 @@ mod render;
 
 use std::collections::HashMap;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent};
 use log::debug;
 
 use crate::airflow::model::common::{Dag, DagId, DagStatistic};
-use crate::app::events::custom::FlowrsEvent;
+use crate::app::actions::ActionId;
+use crate::app::events::custom::{scroll_key, FlowrsEvent};
+use crate::app::keymap::keymap;
+use crate::app::model::filter::dag_filter;
+use crate::app::state::Panel;
 use commands::DAG_COMMAND_POP_UP;
+use flowrs_airflow::DagFilter;
 
 use super::dagruns::DagCodeView;
-use super::{FilterableTable, KeyResult, Model, Popup};
+use super::{FilterableTable, KeyResult, Model, Popup, TableMouse};
 use crate::airflow::model::common::OpenItem;
 use crate::app::worker::WorkerMessage;
 use popup::DagPopUp;
@@ -28,6 +33,9 @@ pub struct DagModel {
     pub popup: Popup<DagPopUp>,
     /// DAG source code viewer
     pub dag_code: Option<DagCodeView>,
+    /// The part of the table filter the API applies when listing DAGs —
+    /// keyed by environment, as each one caches the DAGs it listed
+    pub server_filters: HashMap<String, DagFilter>,
     ticks: u32,
     poll_tick_multiplier: u32,
     event_buffer: Vec<KeyCode>,
@@ -40,6 +48,7 @@ impl Default for DagModel {
             dag_stats: HashMap::new(),
             popup: Popup::None,
             dag_code: None,
+            server_filters: HashMap::new(),
             ticks: 0,
             poll_tick_multiplier: 10,
             event_buffer: Vec::new(),
@@ -88,10 +97,37 @@ impl DagModel {
         KeyResult::Consumed
     }
 
+    /// The server-side filter to list the DAGs of `environment` with
+    pub fn server_filter(&self, environment: &str) -> DagFilter {
+        self.server_filters
+            .get(environment)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Recompute the server-side filter of the active environment from the
+    /// confirmed table filter. When it changes, the DAGs are fetched again
+    /// with it.
+    fn refresh_server_filter(
+        &mut self,
+        ctx: &crate::app::state::NavigationContext,
+    ) -> Vec<WorkerMessage> {
+        let Some(environment) = ctx.environment() else {
+            return vec![];
+        };
+        let server_filter = dag_filter(&self.table.filter.confirmed_conditions(), &self.table.all);
+        if self.server_filters.get(environment) == Some(&server_filter) {
+            return vec![];
+        }
+        self.server_filters
+            .insert(environment.clone(), server_filter);
+        vec![WorkerMessage::UpdateDagsAndStats]
+    }
+
     /// Handle model-specific keys
-    fn handle_keys(&mut self, key_code: KeyCode) -> KeyResult {
-        match key_code {
-            KeyCode::Char('p') => {
+    fn handle_keys(&mut self, key: &KeyEvent) -> KeyResult {
+        match keymap().action(&Panel::Dag, key) {
+            Some(ActionId::TogglePause) => {
                 if let Some(dag) = self.table.current_mut() {
                     let current_state = dag.is_paused;
                     dag.is_paused = !current_state;
@@ -105,11 +141,11 @@ impl DagModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('?') => {
+            Some(ActionId::Help) => {
                 self.popup.show_commands(&DAG_COMMAND_POP_UP);
                 KeyResult::Consumed
             }
-            KeyCode::Enter => {
+            Some(ActionId::Enter) => {
                 if let Some(dag) = self.table.current() {
                     debug!("Selected dag: {}", dag.dag_id);
                     KeyResult::PassWith(vec![WorkerMessage::UpdateDagRuns {
@@ -121,7 +157,7 @@ impl DagModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('o') => {
+            Some(ActionId::Open) => {
                 if let Some(dag) = self.table.current() {
                     debug!("Selected dag: {}", dag.dag_id);
                     KeyResult::PassWith(vec![WorkerMessage::OpenItem(OpenItem::Dag {
@@ -133,7 +169,7 @@ impl DagModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('v') => {
+            Some(ActionId::ShowCode) => {
                 if let Some(dag) = self.table.current() {
                     KeyResult::ConsumedWith(vec![WorkerMessage::GetDagCode {
                         dag_id: dag.dag_id.clone(),
@@ -144,7 +180,7 @@ impl DagModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('t') => {
+            Some(ActionId::Trigger) => {
                 if let Some(dag) = self.table.current() {
                     // The worker fetches a fresh param schema (falling back to
                     // the cached one) and opens the trigger popup once ready.
@@ -186,22 +222,47 @@ impl Model for DagModel {
                 }
 
                 // Chain the handlers
+                if matches!(
+                    self.table.handle_filter_key(key_event),
+                    KeyResult::Consumed | KeyResult::ConsumedWith(_)
+                ) {
+                    return (None, self.refresh_server_filter(ctx));
+                }
+
                 let result = self
-                    .table
-                    .handle_filter_key(key_event)
-                    .or_else(|| self.popup.handle_dismiss(key_event.code))
+                    .popup
+                    .handle_dismiss(key_event.code)
                     .or_else(|| self.handle_dag_code_viewer(key_event.code))
                     .or_else(|| {
                         self.table
-                            .handle_navigation(key_event.code, &mut self.event_buffer)
+                            .handle_navigation(key_event, &mut self.event_buffer)
                     })
-                    .or_else(|| self.handle_keys(key_event.code));
+                    .or_else(|| self.handle_keys(key_event));
 
                 result.into_result(event)
             }
-            FlowrsEvent::Mouse | FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => {
-                (Some(event.clone()), vec![])
+            FlowrsEvent::Mouse(mouse) => {
+                if let Some(messages) = self.handle_popup(event, ctx) {
+                    return (None, messages);
+                }
+
+                // The wheel scrolls the code viewer like the arrow keys
+                let result = self.popup.handle_click(*mouse).or_else(|| {
+                    self.handle_dag_code_viewer(scroll_key(*mouse).unwrap_or(KeyCode::Null))
+                });
+                if !matches!(result, KeyResult::Ignored) {
+                    return result.into_result(event);
+                }
+
+                match self.table.handle_mouse(*mouse) {
+                    TableMouse::Activated => {
+                        self.update(&FlowrsEvent::Key(keymap().key(ActionId::Enter)), ctx)
+                    }
+                    TableMouse::Moved => (None, vec![]),
+                    TableMouse::Ignored => (Some(event.clone()), vec![]),
+                }
             }
+            FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => (Some(event.clone()), vec![]),
         }
     }
 }
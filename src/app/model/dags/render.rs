@@ -5,7 +5,7 @@ use ratatui::widgets::{Block, BorderType, Borders, Row, StatefulWidget, Table, W
 use time::OffsetDateTime;
 
 use crate::airflow::model::common::DagRunState;
-use crate::ui::common::create_headers;
+use crate::ui::common::{create_headers, help_title};
 use crate::ui::constants::AirflowStateColor;
 use crate::ui::theme::theme;
 
@@ -20,6 +20,20 @@ impl Widget for &mut DagModel {
         let headers = ["Active", "Name", "Owners", "Schedule", "Next Run", "Stats"];
         let header_row = create_headers(headers);
         let header = Row::new(header_row).style(theme.table_header_style);
+        let block = {
+            let block = Block::default()
+                .border_type(BorderType::Rounded)
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+                .border_style(theme.border_style)
+                .title(help_title());
+            if let Some(title) = self.table.status_title() {
+                block.title_bottom(title)
+            } else {
+                block
+            }
+        };
+        let table_area = block.inner(content_area);
+
         let rows = self
             .table
             .filtered
@@ -79,21 +93,11 @@ impl Widget for &mut DagModel {
             ],
         )
         .header(header)
-        .block({
-            let block = Block::default()
-                .border_type(BorderType::Rounded)
-                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
-                .border_style(theme.border_style)
-                .title(" Press <?> to see available commands ");
-            if let Some(title) = self.table.status_title() {
-                block.title_bottom(title)
-            } else {
-                block
-            }
-        })
+        .block(block)
         .row_highlight_style(theme.selected_row_style);
 
         StatefulWidget::render(table, content_area, buf, &mut self.table.filtered.state);
+        self.table.set_rendered_area(table_area);
 
         if let Some(view) = &mut self.dag_code {
             view.render(area, buf);
@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// A single filter condition (e.g., "state contains 'running'")
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FilterCondition {
     /// The field name to filter on (e.g., "state", "`dag_run_id`")
     pub field: String,
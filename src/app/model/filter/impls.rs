@@ -8,6 +8,7 @@ use crate::airflow::model::common::dagrun::DagRun;
 use crate::airflow::model::common::taskinstance::TaskInstance;
 use crate::impl_filterable;
 use flowrs_config::AirflowConfig;
+use time::macros::format_description;
 
 impl_filterable! {
     Dag,
@@ -25,6 +26,7 @@ impl_filterable! {
     fields: [
         state: enum["running", "success", "failed", "queued", "up_for_retry"] => |s: &DagRun| Some(s.state.to_string()),
         run_type: enum["scheduled", "manual", "backfill", "dataset_triggered", "asset_triggered"] => |s: &DagRun| Some(s.run_type.to_string()),
+        logical_date => |s: &DagRun| s.logical_date.and_then(|d| d.format(format_description!("[year]-[month]-[day] [hour]:[minute]:[second]")).ok()),
     ]
 }
 
@@ -69,7 +71,7 @@ mod tests {
         assert_eq!(DagRun::primary_field(), "dag_run_id");
 
         let fields = DagRun::filterable_fields();
-        assert_eq!(fields.len(), 3);
+        assert_eq!(fields.len(), 4);
         assert!(fields[0].is_primary);
     }
 
@@ -10,6 +10,7 @@ mod condition;
 mod filterable;
 mod impls;
 mod matching;
+mod pushdown;
 mod state;
 mod state_machine;
 mod widget;
@@ -18,5 +19,6 @@ pub use autocomplete::AutocompleteState;
 pub use condition::FilterCondition;
 pub use filterable::{FilterKind, Filterable, FilterableField};
 pub use matching::filter_items;
+pub use pushdown::{dag_filter, dag_run_filter};
 pub use state::FilterState;
 pub use state_machine::FilterStateMachine;
@@ -0,0 +1,254 @@
+//! Translate filter conditions into server-side API filters.
+//!
+//! Local filtering only sees the items loaded so far; pushing conditions down
+//! lets the API return matching items from beyond the first page. Only
+//! conditions the API can evaluate exactly are pushed down; all conditions are
+//! still applied locally afterwards.
+
+use std::collections::HashSet;
+
+use flowrs_airflow::{DagFilter, DagRunFilter};
+use time::{Date, Duration, Month, OffsetDateTime};
+
+use super::{FilterCondition, FilterKind, Filterable};
+use crate::airflow::model::common::{Dag, DagRun};
+
+/// Build the server-side filter for the DAG table.
+///
+/// The first `dag_id` condition becomes the id pattern and `is_paused` is
+/// pushed down when it is `true` or `false`. The API matches `tags` and
+/// `owners` exactly, so a value is pushed down only when it names one of the
+/// tags or owners of the loaded `dags`, and no other one contains it.
+pub fn dag_filter(conditions: &[FilterCondition], dags: &[Dag]) -> DagFilter {
+    let mut filter = DagFilter::default();
+    for condition in conditions {
+        let value = condition.value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        match condition.field.as_str() {
+            _ if condition.is_primary || condition.field == "dag_id" => {
+                filter
+                    .dag_id_pattern
+                    .get_or_insert_with(|| value.to_string());
+            }
+            "tags" => filter.tags.extend(known_value(
+                value,
+                dags.iter()
+                    .flat_map(|dag| dag.tags.iter().map(|tag| tag.name.as_str())),
+            )),
+            "owners" => filter.owners.extend(known_value(
+                value,
+                dags.iter()
+                    .flat_map(|dag| dag.owners.iter().map(String::as_str)),
+            )),
+            "is_paused" => {
+                filter.paused = enum_value::<Dag>("is_paused", value).and_then(|v| v.parse().ok());
+            }
+            _ => {}
+        }
+    }
+    filter
+}
+
+/// Build the server-side filter for the DAG run table.
+///
+/// `state` and `run_type` are pushed down when their value is one of the
+/// known values; `logical_date` when it is a complete date prefix such as
+/// `2024`, `2024-03` or `2024-03-05`, which covers that year, month or day.
+pub fn dag_run_filter(conditions: &[FilterCondition]) -> DagRunFilter {
+    let mut filter = DagRunFilter::default();
+    for condition in conditions.iter().filter(|c| !c.is_primary) {
+        match condition.field.as_str() {
+            "state" => filter
+                .states
+                .extend(enum_value::<DagRun>("state", &condition.value)),
+            "run_type" => filter
+                .run_types
+                .extend(enum_value::<DagRun>("run_type", &condition.value)),
+            "logical_date" => {
+                if let Some((start, end)) = date_prefix_range(&condition.value) {
+                    filter.logical_date_gte = Some(start);
+                    filter.logical_date_lte = Some(end);
+                }
+            }
+            _ => {}
+        }
+    }
+    filter
+}
+
+/// The one `known` value that contains `value` case-insensitively, if it is
+/// `value` itself. Only then does the exact match of the API return what the
+/// local substring match would.
+fn known_value<'a>(value: &str, known: impl Iterator<Item = &'a str>) -> Option<String> {
+    let value = value.to_lowercase();
+    let matching: HashSet<&str> = known
+        .filter(|known| known.to_lowercase().contains(&value))
+        .collect();
+    match matching.into_iter().collect::<Vec<_>>().as_slice() {
+        [known] if known.to_lowercase() == value => Some((*known).to_string()),
+        _ => None,
+    }
+}
+
+/// The value of an enumerated field, if `value` names one of its variants exactly.
+fn enum_value<T: Filterable>(field: &str, value: &str) -> Option<String> {
+    let value = value.trim().to_lowercase();
+    T::filterable_fields()
+        .into_iter()
+        .find(|f| f.name == field)
+        .and_then(|f| match f.kind {
+            FilterKind::Enum(values) => values.into_iter().find(|v| *v == value),
+            FilterKind::FreeText => None,
+        })
+        .map(str::to_string)
+}
+
+/// The UTC time range covered by a `YYYY`, `YYYY-MM` or `YYYY-MM-DD` prefix,
+/// with an inclusive end.
+fn date_prefix_range(value: &str) -> Option<(OffsetDateTime, OffsetDateTime)> {
+    let parts: Vec<&str> = value.trim().split('-').collect();
+    let digits =
+        |part: &str, len: usize| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+
+    let year_part = parts.first()?;
+    if !digits(year_part, 4) {
+        return None;
+    }
+    let year: i32 = year_part.parse().ok()?;
+
+    let (start, end) = match parts[1..] {
+        [] => (
+            Date::from_calendar_date(year, Month::January, 1).ok()?,
+            Date::from_calendar_date(year + 1, Month::January, 1).ok()?,
+        ),
+        [month] if digits(month, 2) => {
+            let month = Month::try_from(month.parse::<u8>().ok()?).ok()?;
+            let next_year = if month == Month::December {
+                year + 1
+            } else {
+                year
+            };
+            (
+                Date::from_calendar_date(year, month, 1).ok()?,
+                Date::from_calendar_date(next_year, month.next(), 1).ok()?,
+            )
+        }
+        [month, day] if digits(month, 2) && digits(day, 2) => {
+            let month = Month::try_from(month.parse::<u8>().ok()?).ok()?;
+            let date = Date::from_calendar_date(year, month, day.parse().ok()?).ok()?;
+            (date, date.next_day()?)
+        }
+        _ => return None,
+    };
+    Some((
+        start.midnight().assume_utc(),
+        end.midnight().assume_utc() - Duration::microseconds(1),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::airflow::model::common::Tag;
+
+    fn dags() -> Vec<Dag> {
+        ["finance", "fin", "finance-eu"]
+            .into_iter()
+            .map(|tag| Dag {
+                owners: vec!["data-eng".to_string(), "airflow".to_string()],
+                tags: vec![Tag {
+                    name: tag.to_string(),
+                }],
+                ..Dag::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pushes_down_dag_conditions() {
+        let filter = dag_filter(
+            &[
+                FilterCondition::new("dag_id", "etl", false),
+                FilterCondition::new("tags", "Finance-EU", false),
+                FilterCondition::new("owners", "data-eng", false),
+                FilterCondition::new("is_paused", "False", false),
+                FilterCondition::new("dag_id", "daily", false),
+            ],
+            &dags(),
+        );
+        assert_eq!(
+            filter,
+            DagFilter {
+                dag_id_pattern: Some("etl".to_string()),
+                tags: vec!["finance-eu".to_string()],
+                owners: vec!["data-eng".to_string()],
+                paused: Some(false),
+            }
+        );
+
+        let filter = dag_filter(&[FilterCondition::new("is_paused", "tr", false)], &dags());
+        assert_eq!(filter.paused, None);
+    }
+
+    #[test]
+    fn keeps_tags_and_owners_local_unless_they_name_one_exactly() {
+        // Partial, contained in other tags, or not loaded
+        for value in ["fina", "fin", "finance", "marketing"] {
+            let filter = dag_filter(&[FilterCondition::new("tags", value, false)], &dags());
+            assert_eq!(filter, DagFilter::default(), "{value}");
+        }
+        let filter = dag_filter(&[FilterCondition::new("owners", "air", false)], &dags());
+        assert_eq!(filter, DagFilter::default());
+    }
+
+    #[test]
+    fn pushes_down_known_states_and_run_types() {
+        let filter = dag_run_filter(&[
+            FilterCondition::primary("manual__"),
+            FilterCondition::new("state", "Failed", false),
+            FilterCondition::new("run_type", "manual", false),
+        ]);
+        assert_eq!(filter.states, vec!["failed"]);
+        assert_eq!(filter.run_types, vec!["manual"]);
+    }
+
+    #[test]
+    fn keeps_partial_values_local() {
+        let filter = dag_run_filter(&[
+            FilterCondition::new("state", "fail", false),
+            FilterCondition::new("logical_date", "2024-0", false),
+        ]);
+        assert_eq!(filter, DagRunFilter::default());
+    }
+
+    #[test]
+    fn date_prefixes_cover_their_period() {
+        assert_eq!(
+            date_prefix_range("2024"),
+            Some((
+                datetime!(2024-01-01 00:00:00 UTC),
+                datetime!(2024-12-31 23:59:59.999999 UTC)
+            ))
+        );
+        assert_eq!(
+            date_prefix_range("2024-12"),
+            Some((
+                datetime!(2024-12-01 00:00:00 UTC),
+                datetime!(2024-12-31 23:59:59.999999 UTC)
+            ))
+        );
+        assert_eq!(
+            date_prefix_range("2024-02-29"),
+            Some((
+                datetime!(2024-02-29 00:00:00 UTC),
+                datetime!(2024-02-29 23:59:59.999999 UTC)
+            ))
+        );
+        assert_eq!(date_prefix_range("2023-02-29"), None);
+        assert_eq!(date_prefix_range("24-01"), None);
+    }
+}
@@ -2,6 +2,9 @@ use std::collections::HashMap;
 
 use crossterm::event::{KeyCode, KeyEvent};
 
+use crate::app::actions::ActionId;
+use crate::app::keymap::keymap;
+
 use super::{AutocompleteState, FilterCondition, FilterKind, FilterState, FilterableField};
 
 use ratatui::layout::Position;
@@ -117,6 +120,16 @@ impl FilterStateMachine {
         self.space_just_pressed = false;
     }
 
+    /// Get the conditions the user has confirmed, leaving out a value that is
+    /// still being typed
+    pub fn confirmed_conditions(&self) -> Vec<FilterCondition> {
+        if matches!(self.state, FilterState::Inactive) {
+            self.stored_conditions.clone()
+        } else {
+            self.state.confirmed_conditions().to_vec()
+        }
+    }
+
     /// Deactivate the filter, storing current conditions for later restoration
     pub fn deactivate(&mut self) {
         // Store all active conditions (including in-progress typing) before going inactive
@@ -137,6 +150,13 @@ impl FilterStateMachine {
         &self.stored_conditions
     }
 
+    /// Replace the stored conditions, e.g. with those of a previous session
+    pub fn restore(&mut self, conditions: Vec<FilterCondition>) {
+        self.stored_conditions = conditions;
+        self.state = FilterState::Inactive;
+        self.space_just_pressed = false;
+    }
+
     /// Update the primary field name and available values for autocomplete.
     /// Call this when data is loaded/updated to enable autocomplete suggestions.
     pub fn set_primary_values(&mut self, field_name: &str, values: Vec<String>) {
@@ -211,7 +231,7 @@ impl FilterStateMachine {
 
         match &mut self.state {
             FilterState::Inactive => {
-                if key.code == KeyCode::Char('/') {
+                if keymap().matches(ActionId::Filter, key) {
                     self.activate();
                     return true;
                 }
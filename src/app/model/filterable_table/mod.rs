@@ -6,15 +6,47 @@
 //! - Filter state machine with autocomplete support
 //! - Visual mode selection
 //! - Common navigation operations
+//! - Mouse selection and scrolling
 
 mod render;
 
 use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
 
 use super::filter::{filter_items, FilterStateMachine, Filterable};
 use super::{KeyResult, StatefulTable};
+use crate::app::actions::ActionId;
+use crate::app::keymap::keymap;
+
+/// How close to the end of the loaded rows the selection may get before the
+/// next page is requested.
+const NEXT_PAGE_MARGIN: usize = 5;
+
+/// Longest time between two clicks on a row that still counts as a double click
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// What a mouse event did to a table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableMouse {
+    /// The event was not a click or scroll over the rows
+    Ignored,
+    /// A row was clicked or the wheel moved the selection
+    Moved,
+    /// A row was double-clicked, which acts like Enter on it
+    Activated,
+}
+
+/// Server-side paging state for tables that load their items page by page.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Paging {
+    /// Number of items on the server matching the server-side filter
+    pub total: usize,
+    /// Whether the next page has been requested and not yet loaded
+    pub requested: bool,
+}
 
 /// A generic filterable table that combines data storage, filtering, and visual selection.
 ///
@@ -29,6 +61,12 @@ pub struct FilterableTable<T> {
     pub filter: FilterStateMachine,
     /// Anchor index for visual selection; `Some` means visual mode is active
     pub visual_anchor: Option<usize>,
+    /// Server-side paging; `None` when all items are loaded at once
+    pub paging: Option<Paging>,
+    /// Where the rows were last rendered, for mapping clicks to rows
+    rows_area: Rect,
+    /// Row and time of the last click, to detect double clicks
+    last_click: Option<(usize, Instant)>,
 }
 
 impl<T: Filterable + Clone> FilterableTable<T> {
@@ -39,6 +77,9 @@ impl<T: Filterable + Clone> FilterableTable<T> {
             filtered: StatefulTable::new(Vec::new()),
             filter: FilterStateMachine::default(),
             visual_anchor: None,
+            paging: None,
+            rows_area: Rect::default(),
+            last_click: None,
         }
     }
 
@@ -90,6 +131,15 @@ impl<T: Filterable + Clone> FilterableTable<T> {
             .and_then(|i| self.filtered.items.get_mut(i))
     }
 
+    /// Selects the first item matching `predicate`. Returns whether one was found.
+    pub fn select_where(&mut self, predicate: impl Fn(&T) -> bool) -> bool {
+        let index = self.filtered.items.iter().position(predicate);
+        if index.is_some() {
+            self.filtered.state.select(index);
+        }
+        index.is_some()
+    }
+
     /// Returns the inclusive range of selected indices, if in visual mode
     pub fn visual_selection(&self) -> Option<RangeInclusive<usize>> {
         let anchor = self.visual_anchor?;
@@ -129,64 +179,151 @@ impl<T: Filterable + Clone> FilterableTable<T> {
         }
     }
 
+    /// Marks the next page as requested if the selection is near the end of the
+    /// loaded items and the server has more. Returns whether the caller should
+    /// fetch it.
+    pub fn request_next_page(&mut self) -> bool {
+        let Some(paging) = self.paging.as_mut() else {
+            return false;
+        };
+        let Some(selected) = self.filtered.state.selected() else {
+            return false;
+        };
+        let near_end = selected + NEXT_PAGE_MARGIN >= self.filtered.items.len();
+        if paging.requested || !near_end || self.all.len() >= paging.total {
+            return false;
+        }
+        paging.requested = true;
+        true
+    }
+
+    /// Set the number of items on the server. A pending page request is kept:
+    /// a refresh that lands while the page is loading must not request it again.
+    pub fn set_paging_total(&mut self, total: usize) {
+        let requested = self.paging.is_some_and(|paging| paging.requested);
+        self.paging = Some(Paging { total, requested });
+    }
+
+    /// Allow requesting the next page again, once the requested page has
+    /// arrived or failed to load.
+    pub fn page_loaded(&mut self) {
+        if let Some(paging) = self.paging.as_mut() {
+            paging.requested = false;
+        }
+    }
+
+    /// Title showing how many of the server's items are loaded, while some
+    /// are not.
+    pub fn paging_title(&self) -> Option<String> {
+        self.paging
+            .filter(|paging| self.all.len() < paging.total)
+            .map(|paging| format!(" {} of {} loaded ", self.all.len(), paging.total))
+    }
+
     /// Handle common navigation keys (j/k/G/gg pattern)
     pub fn handle_navigation(
         &mut self,
-        key_code: KeyCode,
+        key: &KeyEvent,
         event_buffer: &mut Vec<KeyCode>,
     ) -> KeyResult {
-        match key_code {
-            KeyCode::Down | KeyCode::Char('j') => {
-                self.filtered.next();
-                KeyResult::Consumed
-            }
-            KeyCode::Up | KeyCode::Char('k') => {
-                self.filtered.previous();
-                KeyResult::Consumed
-            }
-            KeyCode::Char('G') => {
-                if !self.filtered.items.is_empty() {
-                    self.filtered
-                        .state
-                        .select(Some(self.filtered.items.len() - 1));
-                }
-                KeyResult::Consumed
+        let keymap = keymap();
+        if keymap.matches(ActionId::Next, key) {
+            self.filtered.next();
+            KeyResult::Consumed
+        } else if keymap.matches(ActionId::Previous, key) {
+            self.filtered.previous();
+            KeyResult::Consumed
+        } else if keymap.matches(ActionId::Bottom, key) {
+            if !self.filtered.items.is_empty() {
+                self.filtered
+                    .state
+                    .select(Some(self.filtered.items.len() - 1));
             }
-            KeyCode::Char('g') => {
-                if let Some(last_key) = event_buffer.pop() {
-                    if last_key == KeyCode::Char('g') {
-                        self.filtered.state.select_first();
-                    } else {
-                        event_buffer.push(last_key);
-                        event_buffer.push(key_code);
-                    }
+            KeyResult::Consumed
+        } else if keymap.matches(ActionId::Top, key) {
+            // Top is pressed twice, like `gg` in vim
+            if let Some(last_key) = event_buffer.pop() {
+                if last_key == key.code {
+                    self.filtered.state.select_first();
                 } else {
-                    event_buffer.push(key_code);
+                    event_buffer.push(last_key);
+                    event_buffer.push(key.code);
                 }
-                KeyResult::Consumed
+            } else {
+                event_buffer.push(key.code);
             }
-            _ => KeyResult::Ignored,
+            KeyResult::Consumed
+        } else {
+            KeyResult::Ignored
         }
     }
 
-    /// Handle visual mode keys (V to enter, Esc to exit)
-    pub fn handle_visual_mode_key(&mut self, key_code: KeyCode) -> KeyResult {
-        match key_code {
-            KeyCode::Char('V') => {
-                if let Some(cursor) = self.filtered.state.selected() {
-                    self.visual_anchor = Some(cursor);
-                }
-                KeyResult::Consumed
+    /// Handle a mouse event: the wheel moves the selection, a click selects
+    /// the row under the pointer and a second click on it activates it.
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) -> TableMouse {
+        self.handle_mouse_at(mouse, Instant::now())
+    }
+
+    fn handle_mouse_at(&mut self, mouse: MouseEvent, now: Instant) -> TableMouse {
+        let len = self.filtered.items.len();
+        if len == 0
+            || !self
+                .rows_area
+                .contains(Position::new(mouse.column, mouse.row))
+        {
+            return TableMouse::Ignored;
+        }
+        let selected = self.filtered.state.selected();
+        match mouse.kind {
+            // Unlike j/k, the wheel stops at the ends instead of wrapping
+            MouseEventKind::ScrollDown => {
+                let next = selected.map_or(0, |i| (i + 1).min(len - 1));
+                self.filtered.state.select(Some(next));
+                TableMouse::Moved
             }
-            KeyCode::Esc => {
-                if self.visual_anchor.is_some() {
-                    self.visual_anchor = None;
-                    KeyResult::Consumed
+            MouseEventKind::ScrollUp => {
+                let previous = selected.map_or(0, |i| i.saturating_sub(1));
+                self.filtered.state.select(Some(previous));
+                TableMouse::Moved
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let index =
+                    self.filtered.state.offset() + usize::from(mouse.row - self.rows_area.y);
+                if index >= len {
+                    return TableMouse::Ignored;
+                }
+                self.filtered.state.select(Some(index));
+                let double_click = self.last_click.is_some_and(|(row, at)| {
+                    row == index && now.duration_since(at) <= DOUBLE_CLICK_INTERVAL
+                });
+                if double_click {
+                    self.last_click = None;
+                    TableMouse::Activated
                 } else {
-                    KeyResult::PassThrough
+                    self.last_click = Some((index, now));
+                    TableMouse::Moved
                 }
             }
-            _ => KeyResult::Ignored,
+            _ => TableMouse::Ignored,
+        }
+    }
+
+    /// Handle visual mode keys (the visual action to enter, Esc to exit)
+    pub fn handle_visual_mode_key(&mut self, key: &KeyEvent) -> KeyResult {
+        if keymap().matches(ActionId::Visual, key) {
+            if let Some(cursor) = self.filtered.state.selected() {
+                self.visual_anchor = Some(cursor);
+            }
+            KeyResult::Consumed
+        } else if key.code == KeyCode::Esc {
+            if self.visual_anchor.is_some() {
+                self.visual_anchor = None;
+                KeyResult::Consumed
+            } else {
+                KeyResult::PassThrough
+            }
+        } else {
+            KeyResult::Ignored
         }
     }
 }
@@ -352,6 +489,47 @@ mod tests {
         assert_eq!(ids, vec!["1"]);
     }
 
+    #[test]
+    fn test_request_next_page_near_end() {
+        let mut table: FilterableTable<TestItem> = FilterableTable::new();
+        table.set_items(
+            (0..20)
+                .map(|i| TestItem {
+                    id: i.to_string(),
+                    status: "running".to_string(),
+                })
+                .collect(),
+        );
+        table.filtered.state.select(Some(0));
+
+        // Without paging nothing is ever requested
+        assert!(!table.request_next_page());
+
+        table.paging = Some(Paging {
+            total: 40,
+            requested: false,
+        });
+        assert!(!table.request_next_page());
+        assert_eq!(table.paging_title().as_deref(), Some(" 20 of 40 loaded "));
+
+        table.filtered.state.select(Some(15));
+        assert!(table.request_next_page());
+        // Only requested once until the page arrives, even across refreshes
+        assert!(!table.request_next_page());
+        table.set_paging_total(40);
+        assert!(!table.request_next_page());
+        table.page_loaded();
+        assert!(table.request_next_page());
+
+        // Everything loaded
+        table.paging = Some(Paging {
+            total: 20,
+            requested: false,
+        });
+        assert!(!table.request_next_page());
+        assert_eq!(table.paging_title(), None);
+    }
+
     #[test]
     fn test_handle_navigation() {
         let mut table: FilterableTable<TestItem> = FilterableTable::new();
@@ -370,42 +548,94 @@ mod tests {
 
         // j key
         assert!(matches!(
-            table.handle_navigation(KeyCode::Char('j'), &mut buffer),
+            table.handle_navigation(&KeyEvent::from(KeyCode::Char('j')), &mut buffer),
             KeyResult::Consumed
         ));
         assert_eq!(table.current().map(|i| i.id.as_str()), Some("1"));
 
         // k key
-        table.handle_navigation(KeyCode::Char('j'), &mut buffer);
+        table.handle_navigation(&KeyEvent::from(KeyCode::Char('j')), &mut buffer);
         assert!(matches!(
-            table.handle_navigation(KeyCode::Char('k'), &mut buffer),
+            table.handle_navigation(&KeyEvent::from(KeyCode::Char('k')), &mut buffer),
             KeyResult::Consumed
         ));
         assert_eq!(table.current().map(|i| i.id.as_str()), Some("1"));
 
         // G key (go to last)
         assert!(matches!(
-            table.handle_navigation(KeyCode::Char('G'), &mut buffer),
+            table.handle_navigation(&KeyEvent::from(KeyCode::Char('G')), &mut buffer),
             KeyResult::Consumed
         ));
         assert_eq!(table.current().map(|i| i.id.as_str()), Some("2"));
 
         // gg (go to first) - first g
-        table.handle_navigation(KeyCode::Char('g'), &mut buffer);
+        table.handle_navigation(&KeyEvent::from(KeyCode::Char('g')), &mut buffer);
         assert_eq!(buffer, vec![KeyCode::Char('g')]);
 
         // gg - second g
-        table.handle_navigation(KeyCode::Char('g'), &mut buffer);
+        table.handle_navigation(&KeyEvent::from(KeyCode::Char('g')), &mut buffer);
         assert!(buffer.is_empty());
         assert_eq!(table.current().map(|i| i.id.as_str()), Some("1"));
 
         // Unknown key
         assert!(matches!(
-            table.handle_navigation(KeyCode::Char('x'), &mut buffer),
+            table.handle_navigation(&KeyEvent::from(KeyCode::Char('x')), &mut buffer),
             KeyResult::Ignored
         ));
     }
 
+    #[test]
+    fn test_handle_mouse() {
+        let mut table: FilterableTable<TestItem> = FilterableTable::new();
+        table.set_items(
+            (0..3)
+                .map(|i| TestItem {
+                    id: i.to_string(),
+                    status: "running".to_string(),
+                })
+                .collect(),
+        );
+        table.rows_area = Rect::new(1, 5, 40, 10);
+        let mouse = |kind, row| MouseEvent {
+            kind,
+            column: 10,
+            row,
+            modifiers: crossterm::event::KeyModifiers::empty(),
+        };
+        let click = |row| mouse(MouseEventKind::Down(MouseButton::Left), row);
+        let now = Instant::now();
+
+        // Clicks outside the rows or below the last row do nothing
+        assert_eq!(table.handle_mouse_at(click(4), now), TableMouse::Ignored);
+        assert_eq!(table.handle_mouse_at(click(8), now), TableMouse::Ignored);
+
+        assert_eq!(table.handle_mouse_at(click(6), now), TableMouse::Moved);
+        assert_eq!(table.current().map(|i| i.id.as_str()), Some("1"));
+        let later = now + Duration::from_millis(100);
+        assert_eq!(
+            table.handle_mouse_at(click(6), later),
+            TableMouse::Activated
+        );
+        // A slow second click is just another click
+        let much_later = later + Duration::from_secs(1);
+        assert_eq!(
+            table.handle_mouse_at(click(6), much_later),
+            TableMouse::Moved
+        );
+        assert_eq!(
+            table.handle_mouse_at(click(6), much_later + DOUBLE_CLICK_INTERVAL * 2),
+            TableMouse::Moved
+        );
+
+        // The wheel does not wrap around
+        for _ in 0..3 {
+            table.handle_mouse_at(mouse(MouseEventKind::ScrollDown, 5), now);
+        }
+        assert_eq!(table.current().map(|i| i.id.as_str()), Some("2"));
+        table.handle_mouse_at(mouse(MouseEventKind::ScrollUp, 5), now);
+        assert_eq!(table.current().map(|i| i.id.as_str()), Some("1"));
+    }
+
     #[test]
     fn test_handle_visual_mode_key() {
         let mut table: FilterableTable<TestItem> = FilterableTable::new();
@@ -426,28 +656,28 @@ mod tests {
 
         // V key enters visual mode
         assert!(matches!(
-            table.handle_visual_mode_key(KeyCode::Char('V')),
+            table.handle_visual_mode_key(&KeyEvent::from(KeyCode::Char('V'))),
             KeyResult::Consumed
         ));
         assert!(table.visual_anchor.is_some());
 
         // Esc key exits visual mode when in visual mode
         assert!(matches!(
-            table.handle_visual_mode_key(KeyCode::Esc),
+            table.handle_visual_mode_key(&KeyEvent::from(KeyCode::Esc)),
             KeyResult::Consumed
         ));
         assert!(table.visual_anchor.is_none());
 
         // Esc key returns PassThrough when not in visual mode
         assert!(matches!(
-            table.handle_visual_mode_key(KeyCode::Esc),
+            table.handle_visual_mode_key(&KeyEvent::from(KeyCode::Esc)),
             KeyResult::PassThrough
         ));
         assert!(table.visual_anchor.is_none());
 
         // Unknown key returns Ignored
         assert!(matches!(
-            table.handle_visual_mode_key(KeyCode::Char('x')),
+            table.handle_visual_mode_key(&KeyEvent::from(KeyCode::Char('x'))),
             KeyResult::Ignored
         ));
     }
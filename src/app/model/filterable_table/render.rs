@@ -24,6 +24,17 @@ impl<T: Filterable + Clone> FilterableTable<T> {
         }
     }
 
+    /// Remembers where the table was rendered, given the area inside its
+    /// block, so mouse clicks can be mapped to rows. The table has a one-row
+    /// header.
+    pub fn set_rendered_area(&mut self, inner: Rect) {
+        self.rows_area = Rect {
+            y: inner.y.saturating_add(1),
+            height: inner.height.saturating_sub(1),
+            ..inner
+        };
+    }
+
     /// Returns the style for a row based on its index and visual selection state.
     ///
     /// Uses `MARKED_STYLE` for visually selected rows, alternating `DEFAULT_STYLE`/`ALT_ROW_STYLE` otherwise.
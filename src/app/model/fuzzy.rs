@@ -0,0 +1,82 @@
+//! Fuzzy matching for the prompts that pick from a list by typing.
+//!
+//! A query matches when its characters appear in order in the candidate,
+//! ignoring case. Matches score higher the more of them are consecutive or
+//! start a word, so `cl` ranks "Clear" above "Toggle colours".
+
+const MATCH: i64 = 16;
+const CONSECUTIVE_BONUS: i64 = 24;
+const WORD_START_BONUS: i64 = 32;
+const GAP_PENALTY: i64 = 1;
+
+/// Score `candidate` against `query`, or `None` when it does not match.
+///
+/// An empty query matches everything with a score of zero.
+pub fn score(query: &str, candidate: &str) -> Option<i64> {
+    let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut total = 0;
+    let mut previous: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+
+    for (index, c) in candidate.chars().enumerate() {
+        let Some(&wanted) = query.peek() else {
+            break;
+        };
+        if c.to_lowercase().eq(std::iter::once(wanted)) {
+            total += MATCH;
+            match last_match {
+                Some(last) if last + 1 == index => total += CONSECUTIVE_BONUS,
+                Some(last) => total -= GAP_PENALTY * i64::try_from(index - last).unwrap_or(0),
+                None => total -= GAP_PENALTY * i64::try_from(index).unwrap_or(0),
+            }
+            if previous.is_none_or(|p| !p.is_alphanumeric()) {
+                total += WORD_START_BONUS;
+            }
+            last_match = Some(index);
+            query.next();
+        }
+        previous = Some(c);
+    }
+
+    query.peek().is_none().then_some(total)
+}
+
+/// Rank `items` by their score, best first.
+///
+/// Items without a score are dropped; ties keep their original order.
+pub fn rank<T>(items: impl IntoIterator<Item = T>, score: impl Fn(&T) -> Option<i64>) -> Vec<T> {
+    let mut scored: Vec<(i64, T)> = items
+        .into_iter()
+        .filter_map(|item| score(&item).map(|s| (s, item)))
+        .collect();
+    scored.sort_by_key(|(s, _)| std::cmp::Reverse(*s));
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_characters_in_order_ignoring_case() {
+        assert_eq!(score("", "anything"), Some(0));
+        assert!(score("TRG", "trigger").is_some());
+        assert!(score("rgr", "trigger").is_some());
+        assert!(score("gt", "trigger").is_none());
+        assert!(score("triggers", "trigger").is_none());
+    }
+
+    #[test]
+    fn prefers_word_starts_and_consecutive_characters() {
+        let ranked = rank(["Toggle colours", "Show DAG code", "Clear"], |c| {
+            score("cl", c)
+        });
+        assert_eq!(ranked, vec!["Clear", "Toggle colours"]);
+
+        let ranked = rank(["Mark", "Clear", "Previous match"], |c| score("mar", c));
+        assert_eq!(ranked, vec!["Mark"]);
+
+        let ranked = rank(["Next match", "Next"], |c| score("next", c));
+        assert_eq!(ranked, vec!["Next match", "Next"]);
+    }
+}
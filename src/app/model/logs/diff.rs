@@ -0,0 +1,259 @@
+//! Comparison of the logs of two task tries.
+
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use similar::{capture_diff_slices_deadline, Algorithm, DiffTag};
+
+use crate::airflow::model::common::{LogLine, TaskLog};
+
+/// Give up looking for a minimal diff after this long and show a coarser one
+const DIFF_DEADLINE: Duration = Duration::from_secs(2);
+
+/// Timestamps inside messages, such as `2025-01-01T00:00:00.123+00:00`
+static TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:?\d{2})?")
+        .expect("timestamp pattern is valid")
+});
+
+/// Text of a line for comparison: timestamps differ between every run, so
+/// the time column is dropped and timestamps in the message are masked.
+fn normalise(line: &LogLine) -> String {
+    let text = LogLine {
+        timestamp: None,
+        ..line.clone()
+    }
+    .to_string();
+    TIMESTAMP.replace_all(&text, "<time>").into_owned()
+}
+
+/// Number of lines ever loaded into `log`
+fn loaded(log: &TaskLog) -> usize {
+    log.dropped() + log.len()
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLayout {
+    #[default]
+    Unified,
+    SideBySide,
+}
+
+/// One displayed row of a diff, pointing into the old and new lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffRow {
+    pub tag: DiffTag,
+    pub old: Option<usize>,
+    pub new: Option<usize>,
+}
+
+/// The diff between a base try and the try being compared with it
+#[derive(Debug)]
+pub struct LogDiff {
+    /// Index in `LogModel::all` of the try shown as removed lines
+    pub base: usize,
+    /// Index in `LogModel::all` of the try shown as added lines
+    pub target: usize,
+    pub layout: DiffLayout,
+    /// First displayed row
+    pub scroll: usize,
+    pub old: Vec<String>,
+    pub new: Vec<String>,
+    /// Lines ever loaded into the old and new logs, including dropped ones
+    loaded: (usize, usize),
+    unified: Vec<DiffRow>,
+    side_by_side: Vec<DiffRow>,
+}
+
+impl LogDiff {
+    pub fn new(base: usize, target: usize, old: &TaskLog, new: &TaskLog) -> Self {
+        let loaded = (loaded(old), loaded(new));
+        let old: Vec<String> = old.lines().map(normalise).collect();
+        let new: Vec<String> = new.lines().map(normalise).collect();
+        let ops = capture_diff_slices_deadline(
+            Algorithm::Myers,
+            &old,
+            &new,
+            Some(Instant::now() + DIFF_DEADLINE),
+        );
+
+        let mut unified = Vec::new();
+        let mut side_by_side = Vec::new();
+        for op in ops {
+            let (tag, old_range, new_range) = op.as_tag_tuple();
+            if tag == DiffTag::Equal {
+                let rows = old_range.zip(new_range).map(|(o, n)| DiffRow {
+                    tag,
+                    old: Some(o),
+                    new: Some(n),
+                });
+                unified.extend(rows.clone());
+                side_by_side.extend(rows);
+                continue;
+            }
+            let removed = old_range.clone().map(|o| DiffRow {
+                tag: DiffTag::Delete,
+                old: Some(o),
+                new: None,
+            });
+            let added = new_range.clone().map(|n| DiffRow {
+                tag: DiffTag::Insert,
+                old: None,
+                new: Some(n),
+            });
+            unified.extend(removed.chain(added));
+            // Side by side, changed lines are paired up row by row
+            let rows = old_range.len().max(new_range.len());
+            side_by_side.extend((0..rows).map(|i| {
+                let old = Some(old_range.start + i).filter(|o| old_range.contains(o));
+                let new = Some(new_range.start + i).filter(|n| new_range.contains(n));
+                let tag = match (old, new) {
+                    (Some(_), Some(_)) => DiffTag::Replace,
+                    (Some(_), None) => DiffTag::Delete,
+                    _ => DiffTag::Insert,
+                };
+                DiffRow { tag, old, new }
+            }));
+        }
+
+        Self {
+            base,
+            target,
+            layout: DiffLayout::default(),
+            scroll: 0,
+            old,
+            new,
+            loaded,
+            unified,
+            side_by_side,
+        }
+    }
+
+    /// Whether the diff was computed from these logs as loaded now. A log
+    /// that is full keeps its length while lines are appended and dropped.
+    pub fn is_current(&self, old: &TaskLog, new: &TaskLog) -> bool {
+        self.loaded == (loaded(old), loaded(new))
+    }
+
+    /// The rows of the current layout
+    pub fn rows(&self) -> &[DiffRow] {
+        match self.layout {
+            DiffLayout::Unified => &self.unified,
+            DiffLayout::SideBySide => &self.side_by_side,
+        }
+    }
+
+    /// Number of changed lines: removed and added
+    pub fn change_counts(&self) -> (usize, usize) {
+        self.unified
+            .iter()
+            .fold((0, 0), |(removed, added), row| match row.tag {
+                DiffTag::Delete => (removed + 1, added),
+                DiffTag::Insert => (removed, added + 1),
+                _ => (removed, added),
+            })
+    }
+
+    pub fn toggle_layout(&mut self) {
+        self.layout = match self.layout {
+            DiffLayout::Unified => DiffLayout::SideBySide,
+            DiffLayout::SideBySide => DiffLayout::Unified,
+        };
+        self.scroll = self.scroll.min(self.rows().len().saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::airflow::model::common::Log;
+
+    fn log(content: &str) -> TaskLog {
+        let mut log = TaskLog::new(100);
+        log.apply(
+            None,
+            Log {
+                continuation_token: None,
+                lines: LogLine::parse_text(content),
+                unterminated: false,
+            },
+        );
+        log
+    }
+
+    #[test]
+    fn ignores_timestamps_and_pairs_changed_lines() {
+        let old = log(
+            "[2025-01-01T00:00:01+0000] {a.py:1} INFO - start at 2025-01-01 00:00:01\n\
+             [2025-01-01T00:00:02+0000] {a.py:2} INFO - loaded 10 rows\n\
+             [2025-01-01T00:00:03+0000] {a.py:3} INFO - done",
+        );
+        let new = log(
+            "[2025-01-02T09:00:01+0000] {a.py:1} INFO - start at 2025-01-02 09:00:01\n\
+             [2025-01-02T09:00:02+0000] {a.py:2} ERROR - failed\n\
+             Traceback (most recent call last):\n\
+             [2025-01-02T09:00:03+0000] {a.py:3} INFO - done",
+        );
+        let mut diff = LogDiff::new(0, 1, &old, &new);
+        assert_eq!(diff.old[0], "{a.py:1} INFO - start at <time>");
+        assert_eq!(diff.change_counts(), (1, 2));
+
+        let tags: Vec<DiffTag> = diff.rows().iter().map(|row| row.tag).collect();
+        assert_eq!(
+            tags,
+            [
+                DiffTag::Equal,
+                DiffTag::Delete,
+                DiffTag::Insert,
+                DiffTag::Insert,
+                DiffTag::Equal
+            ]
+        );
+
+        diff.toggle_layout();
+        assert_eq!(
+            diff.rows()[1..3],
+            [
+                DiffRow {
+                    tag: DiffTag::Replace,
+                    old: Some(1),
+                    new: Some(1)
+                },
+                DiffRow {
+                    tag: DiffTag::Insert,
+                    old: None,
+                    new: Some(2)
+                }
+            ]
+        );
+        assert!(diff.is_current(&old, &new));
+    }
+
+    #[test]
+    fn a_full_log_with_new_lines_is_not_current() {
+        let old = log("a\nb");
+        let mut new = TaskLog::new(2);
+        new.apply(
+            None,
+            Log {
+                continuation_token: Some("1".into()),
+                lines: LogLine::parse_text("a\nb"),
+                unterminated: false,
+            },
+        );
+        let diff = LogDiff::new(0, 1, &old, &new);
+        assert!(diff.is_current(&old, &new));
+
+        new.apply(
+            Some("1"),
+            Log {
+                continuation_token: None,
+                lines: LogLine::parse_text("c"),
+                unterminated: false,
+            },
+        );
+        assert_eq!(new.len(), 2);
+        assert!(!diff.is_current(&old, &new));
+    }
+}
@@ -1,34 +1,70 @@
+mod diff;
 mod render;
+mod search;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::widgets::ScrollbarState;
 
-use crate::airflow::model::common::{Log, OpenItem};
-use crate::app::events::custom::FlowrsEvent;
+use crate::airflow::model::common::{
+    DagId, DagRunId, LogLevel, LogLine, OpenItem, TaskId, TaskLog,
+};
+use crate::app::actions::ActionId;
+use crate::app::events::custom::{is_click, scroll_key, FlowrsEvent};
+use crate::app::keymap::keymap;
+use crate::app::state::{NavigationContext, Panel};
+use crate::app::terminal::{ExternalProgram, TerminalRequest};
 use crate::app::worker::WorkerMessage;
 
 use super::popup::error::ErrorPopup;
-use super::Model;
+use super::{KeyResult, Model};
+pub(crate) use diff::LogDiff;
+use search::LogSearch;
+
+/// Columns moved per horizontal scroll step
+const HORIZONTAL_STEP: u16 = 8;
+
+/// File name for the log of a task try, safe to use on any filesystem
+pub(crate) fn log_file_name(
+    dag_id: &DagId,
+    dag_run_id: &DagRunId,
+    task_id: &TaskId,
+    task_try: u32,
+) -> String {
+    let name = format!("{dag_id}__{dag_run_id}__{task_id}__try{task_try}");
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{name}.log")
+}
 
 /// Represents the log viewer's scroll behavior.
 ///
 /// Eliminates the invalid state where `follow_mode = true` but `vertical_scroll`
-/// points somewhere other than the bottom.
-#[derive(Debug, Default)]
+/// points somewhere other than the bottom. Positions are indices of displayed
+/// log lines, not screen rows, so they stay valid when wrapping changes.
+#[derive(Debug, Default, Clone, Copy)]
 pub enum ScrollMode {
     /// Automatically scroll to bottom when new content arrives (tail mode).
-    /// The scroll position is computed from the content length at render time.
+    /// The scroll position is computed from the content at render time.
     #[default]
     Following,
-    /// User is manually scrolling at a fixed position.
+    /// User is manually scrolling with this line at the top.
     Manual { position: usize },
 }
 
 impl ScrollMode {
-    /// Returns the concrete scroll position for the given content length.
-    fn position(&self, line_count: usize) -> usize {
+    /// Returns the first line to show, given the first line that puts the
+    /// last line at the bottom of the viewport.
+    fn position(&self, follow_top: usize) -> usize {
         match self {
-            ScrollMode::Following => line_count.saturating_sub(1),
+            ScrollMode::Following => follow_top,
             ScrollMode::Manual { position } => *position,
         }
     }
@@ -38,9 +74,16 @@ impl ScrollMode {
     }
 }
 
+/// Size of the area log lines are rendered in, known after the first render
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub width: u16,
+    pub height: u16,
+}
+
 #[derive(Debug)]
 pub struct LogModel {
-    pub all: Vec<Log>,
+    pub all: Vec<TaskLog>,
     pub current: usize,
     pub error_popup: Option<ErrorPopup>,
     ticks: u32,
@@ -48,6 +91,26 @@ pub struct LogModel {
     pub(crate) scroll_mode: ScrollMode,
     pub(crate) vertical_scroll_state: ScrollbarState,
     pending_g: bool,
+    format: render::LineFormat,
+    /// Only lines at or above this level are shown; `None` shows all lines
+    pub(crate) min_level: Option<LogLevel>,
+    /// Wrap long lines; otherwise they are cut off and scroll horizontally
+    pub(crate) wrap: bool,
+    /// Columns scrolled to the right while not wrapping
+    pub(crate) horizontal_scroll: u16,
+    pub(crate) line_numbers: bool,
+    viewport: Option<Viewport>,
+    pub(crate) search: LogSearch,
+    /// Scroll mode when the query being typed was started, restored on cancel
+    scroll_before_search: Option<ScrollMode>,
+    /// Line where the selection for copying starts; it ends at the current line
+    pub(crate) selection_anchor: Option<usize>,
+    /// Outcome of the last action, shown until the next key press
+    pub(crate) notice: Option<String>,
+    /// Request for the main loop, which owns the terminal
+    pub(crate) terminal_request: Option<TerminalRequest>,
+    /// Diff between two tries, shown instead of the log while set
+    pub(crate) diff: Option<LogDiff>,
 }
 
 impl Default for LogModel {
@@ -61,6 +124,18 @@ impl Default for LogModel {
             scroll_mode: ScrollMode::default(),
             vertical_scroll_state: ScrollbarState::default(),
             pending_g: false,
+            format: render::LineFormat::default(),
+            min_level: None,
+            wrap: true,
+            horizontal_scroll: 0,
+            line_numbers: false,
+            viewport: None,
+            search: LogSearch::default(),
+            scroll_before_search: None,
+            selection_anchor: None,
+            notice: None,
+            terminal_request: None,
+            diff: None,
         }
     }
 }
@@ -80,8 +155,20 @@ impl LogModel {
 
     /// Update the logs content. When in follow mode, the scroll position
     /// will automatically track the bottom at render time.
-    pub fn update_logs(&mut self, logs: Vec<Log>) {
-        self.all = logs;
+    ///
+    /// An open diff is left as it is: diffing large logs is slow, so the
+    /// worker recomputes it without holding the app lock, see
+    /// [`outdated_diff`](Self::outdated_diff).
+    pub fn update_logs(&mut self, logs: Vec<TaskLog>) {
+        let previous = std::mem::replace(&mut self.all, logs);
+        self.search_appended(&previous);
+        if self
+            .diff
+            .as_ref()
+            .is_some_and(|diff| diff.base.max(diff.target) >= self.all.len())
+        {
+            self.diff = None;
+        }
     }
 
     /// Index of the currently selected log (task try), wrapped into range.
@@ -96,14 +183,345 @@ impl LogModel {
 
     /// Returns the currently selected log (the task try being viewed), if any.
     /// `None` when no logs are loaded.
-    fn current_log(&self) -> Option<&Log> {
+    fn current_log(&self) -> Option<&TaskLog> {
         self.all.get(self.current_index())
     }
 
-    /// Returns the total number of lines in the current log content
-    pub(crate) fn current_line_count(&self) -> usize {
+    /// The lines of the current log that pass the level filter, with their
+    /// index in the log
+    fn numbered_lines(&self) -> impl DoubleEndedIterator<Item = (usize, &LogLine)> {
+        let min_level = self.min_level;
         self.current_log()
-            .map_or(0, |log| log.content.lines().count())
+            .into_iter()
+            .flat_map(|log| log.lines().enumerate())
+            .filter(move |(_, line)| min_level.is_none_or(|min| line.level >= Some(min)))
+    }
+
+    /// The lines of the current log that pass the level filter
+    pub(crate) fn visible_lines(&self) -> impl DoubleEndedIterator<Item = &LogLine> {
+        self.numbered_lines().map(|(_, line)| line)
+    }
+
+    /// Returns the number of lines shown for the current log
+    pub(crate) fn current_line_count(&self) -> usize {
+        match self.min_level {
+            None => self.current_log().map_or(0, TaskLog::len),
+            Some(_) => self.visible_lines().count(),
+        }
+    }
+
+    /// The first line shown in follow mode. Before the first render the
+    /// viewport is unknown, and the last line is taken.
+    fn follow_top(&self) -> usize {
+        match self.viewport {
+            Some(viewport) => render::follow_top(self, viewport),
+            None => self.current_line_count().saturating_sub(1),
+        }
+    }
+
+    fn scroll_position(&self) -> usize {
+        self.scroll_mode.position(self.follow_top())
+    }
+
+    /// The selected range of displayed lines: from the anchor to the current
+    /// line, or just the current line without an anchor
+    pub(crate) fn selection(&self) -> Option<(usize, usize)> {
+        let last = self.current_line_count().checked_sub(1)?;
+        let current = self.scroll_position().min(last);
+        let anchor = self
+            .selection_anchor
+            .map_or(current, |anchor| anchor.min(last));
+        Some((anchor.min(current), anchor.max(current)))
+    }
+
+    /// Copy the selected lines as displayed
+    fn copy_selection(&mut self) {
+        let Some((start, end)) = self.selection() else {
+            return;
+        };
+        let format = self.format;
+        let text = self
+            .visible_lines()
+            .skip(start)
+            .take(end - start + 1)
+            .map(|line| render::line_text(line, format))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let count = end - start + 1;
+        self.terminal_request = Some(TerminalRequest::Copy(text));
+        self.selection_anchor = None;
+        self.notice = Some(format!(
+            "Copied {count} line{} to the clipboard",
+            if count == 1 { "" } else { "s" }
+        ));
+    }
+
+    /// Open the current try in a pager or editor
+    fn open_in(&mut self, program: ExternalProgram, ctx: &NavigationContext) {
+        let (Some(dag_id), Some(dag_run_id), Some(task_id)) =
+            (ctx.dag_id(), ctx.dag_run_id(), ctx.task_id())
+        else {
+            return;
+        };
+        let Some(log) = self.current_log() else {
+            return;
+        };
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "the number of task tries is small"
+        )]
+        let task_try = (self.current_index() + 1) as u32;
+        self.terminal_request = Some(TerminalRequest::Open {
+            program,
+            file_name: log_file_name(dag_id, dag_run_id, task_id, task_try),
+            content: log.text(),
+        });
+    }
+
+    /// Compare the current try with the one before it, or with the next one
+    /// when the first try is selected
+    fn open_diff(&mut self) {
+        if self.all.len() < 2 {
+            self.notice = Some("There is only one try to compare".to_string());
+            return;
+        }
+        let target = self.current_index();
+        let base = if target == 0 { 1 } else { target - 1 };
+        self.diff = Some(LogDiff::new(
+            base,
+            target,
+            &self.all[base],
+            &self.all[target],
+        ));
+    }
+
+    /// Compare against the next or previous other try
+    fn change_diff_base(&mut self, forward: bool) {
+        let Some(diff) = &self.diff else {
+            return;
+        };
+        let count = self.all.len();
+        let step = if forward { 1 } else { count - 1 };
+        let mut base = (diff.base + step) % count;
+        if base == diff.target {
+            base = (base + step) % count;
+        }
+        let (target, layout) = (diff.target, diff.layout);
+        let mut diff = LogDiff::new(base, target, &self.all[base], &self.all[target]);
+        diff.layout = layout;
+        self.diff = Some(diff);
+    }
+
+    /// The tries to diff again when either got new lines since the open diff
+    /// was computed: the base and target index, and their logs
+    pub(crate) fn outdated_diff(&self) -> Option<(usize, usize, TaskLog, TaskLog)> {
+        let diff = self.diff.as_ref()?;
+        let old = self.all.get(diff.base)?;
+        let new = self.all.get(diff.target)?;
+        if diff.is_current(old, new) {
+            return None;
+        }
+        Some((diff.base, diff.target, old.clone(), new.clone()))
+    }
+
+    /// Show a recomputed diff, unless the user has closed the diff or changed
+    /// the tries compared in the meantime
+    pub(crate) fn refresh_diff(&mut self, mut refreshed: LogDiff) {
+        let Some(diff) = &self.diff else {
+            return;
+        };
+        if (diff.base, diff.target) != (refreshed.base, refreshed.target) {
+            return;
+        }
+        refreshed.layout = diff.layout;
+        refreshed.scroll = diff.scroll.min(refreshed.rows().len().saturating_sub(1));
+        self.diff = Some(refreshed);
+    }
+
+    /// Handle a key in diff mode. Keys other than the diff keys pass through
+    /// to the global handlers; outside diff mode all keys are ignored.
+    fn update_diff(&mut self, key: &KeyEvent) -> KeyResult {
+        let Some(diff) = self.diff.as_mut() else {
+            return KeyResult::Ignored;
+        };
+        let last_row = diff.rows().len().saturating_sub(1);
+        let action = keymap().action(&Panel::Logs, key);
+        if action != Some(ActionId::Top) {
+            self.pending_g = false;
+        }
+        if key.code == KeyCode::Esc {
+            self.diff = None;
+            return KeyResult::Consumed;
+        }
+        match action {
+            Some(ActionId::Diff) => self.diff = None,
+            Some(ActionId::Next) => diff.scroll = (diff.scroll + 1).min(last_row),
+            Some(ActionId::Previous) => diff.scroll = diff.scroll.saturating_sub(1),
+            Some(ActionId::Bottom) => diff.scroll = last_row,
+            Some(ActionId::Top) => {
+                if self.pending_g {
+                    diff.scroll = 0;
+                    self.pending_g = false;
+                } else {
+                    self.pending_g = true;
+                }
+            }
+            Some(ActionId::DiffLayout) => diff.toggle_layout(),
+            Some(ActionId::PreviousTry) => self.change_diff_base(false),
+            Some(ActionId::NextTry) => self.change_diff_base(true),
+            _ => return KeyResult::PassThrough,
+        }
+        KeyResult::Consumed
+    }
+
+    /// Ask the worker to save the current try, or all tries
+    fn save(&self, all_tries: bool, ctx: &NavigationContext) -> Vec<WorkerMessage> {
+        let (Some(dag_id), Some(dag_run_id), Some(task_id)) =
+            (ctx.dag_id(), ctx.dag_run_id(), ctx.task_id())
+        else {
+            return vec![];
+        };
+        if self.current_log().is_none() {
+            return vec![];
+        }
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "the number of task tries is small"
+        )]
+        let task_try = (!all_tries).then_some((self.current_index() + 1) as u32);
+        vec![WorkerMessage::SaveTaskLogs {
+            dag_id: dag_id.clone(),
+            dag_run_id: dag_run_id.clone(),
+            task_id: task_id.clone(),
+            task_try,
+        }]
+    }
+
+    /// Find the search matches again after the displayed lines changed
+    fn refresh_search(&mut self) {
+        if !self.search.is_active() {
+            return;
+        }
+        let texts: Vec<String> = self
+            .visible_lines()
+            .map(|line| render::line_text(line, self.format))
+            .collect();
+        self.search.update_matches(&texts);
+    }
+
+    /// Find the search matches in the lines appended to the current log
+    /// since `previous` was loaded, instead of searching the whole log again
+    /// on every poll. Searches everything when the log was replaced.
+    fn search_appended(&mut self, previous: &[TaskLog]) {
+        if !self.search.is_active() {
+            return;
+        }
+        let (Some(old), Some(new)) = (previous.get(self.current_index()), self.current_log())
+        else {
+            return self.refresh_search();
+        };
+        // The log only grew if its last loaded line is still in place
+        let old_loaded = old.dropped() + old.len();
+        let grown = previous.len() == self.all.len()
+            && new.dropped() >= old.dropped()
+            && old.lines().last().is_none_or(|last| {
+                (old_loaded - 1)
+                    .checked_sub(new.dropped())
+                    .and_then(|index| new.lines().nth(index))
+                    == Some(last)
+            });
+        if !grown {
+            return self.refresh_search();
+        }
+
+        let min_level = self.min_level;
+        let shown = |line: &&LogLine| min_level.is_none_or(|min| line.level >= Some(min));
+        let newly_dropped = new.dropped() - old.dropped();
+        let dropped = old.lines().take(newly_dropped).filter(shown).count();
+        let kept = old.lines().skip(newly_dropped).filter(shown).count();
+        let appended: Vec<String> = new
+            .lines()
+            .skip(old_loaded.saturating_sub(new.dropped()))
+            .filter(shown)
+            .map(|line| render::line_text(line, self.format))
+            .collect();
+        self.search.extend_matches(dropped, kept, &appended);
+    }
+
+    /// Scroll to the line of a match
+    fn scroll_to_match(&mut self, line: Option<usize>) {
+        if let Some(position) = line {
+            self.scroll_mode = ScrollMode::Manual { position };
+        }
+    }
+
+    /// Search for `query` as literal text, starting at the current position
+    fn search_for(&mut self, query: &str) {
+        let position = self.scroll_position();
+        self.search.clear();
+        self.search.query = query.to_string();
+        self.search.regex = false;
+        self.refresh_search();
+        let line = self.search.select_from(position);
+        self.scroll_to_match(line);
+    }
+
+    /// Re-run the query being typed and move to its first match from where
+    /// the search started, or back there if nothing matches
+    fn search_incrementally(&mut self, origin: usize) {
+        self.refresh_search();
+        match self.search.select_from(origin) {
+            Some(line) => self.scroll_to_match(Some(line)),
+            None => {
+                if let Some(mode) = self.scroll_before_search {
+                    self.scroll_mode = mode;
+                }
+            }
+        }
+    }
+
+    /// Handle a key while the search query is being typed. All keys are
+    /// consumed except Ctrl-C/Ctrl-D, so typing never triggers other actions;
+    /// only a regex toggle bound with Ctrl or Alt works while typing.
+    fn update_search_input(&mut self, key: &KeyEvent) -> Option<FlowrsEvent> {
+        let origin = self
+            .scroll_before_search
+            .map_or(0, |mode| mode.position(self.follow_top()));
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('c' | 'd') if ctrl => return Some(FlowrsEvent::Key(*key)),
+            _ if key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                && keymap().matches(ActionId::ToggleRegex, key) =>
+            {
+                self.search.regex = !self.search.regex;
+                self.search_incrementally(origin);
+            }
+            KeyCode::Enter => {
+                self.search.editing = false;
+                self.scroll_before_search = None;
+                if self.search.query.is_empty() {
+                    self.search.clear();
+                }
+            }
+            KeyCode::Esc => {
+                self.search.clear();
+                if let Some(mode) = self.scroll_before_search.take() {
+                    self.scroll_mode = mode;
+                }
+            }
+            KeyCode::Backspace => {
+                self.search.query.pop();
+                self.search_incrementally(origin);
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.search.query.push(c);
+                self.search_incrementally(origin);
+            }
+            _ => {}
+        }
+        None
     }
 }
 
@@ -111,7 +529,7 @@ impl Model for LogModel {
     fn update(
         &mut self,
         event: &FlowrsEvent,
-        ctx: &crate::app::state::NavigationContext,
+        ctx: &NavigationContext,
     ) -> (Option<FlowrsEvent>, Vec<WorkerMessage>) {
         match event {
             FlowrsEvent::Tick => {
@@ -148,41 +566,83 @@ impl Model for LogModel {
                     }
                     return (None, vec![]);
                 }
-                // Clear pending 'g' on any key that is not 'g' to ensure gg requires consecutive presses
-                if key.code != KeyCode::Char('g') {
+                self.notice = None;
+                if self.search.editing {
+                    return (self.update_search_input(key), vec![]);
+                }
+                match self.update_diff(key) {
+                    KeyResult::Ignored => {}
+                    result => return result.into_result(event),
+                }
+                let action = keymap().action(&Panel::Logs, key);
+                // Clear pending 'g' on any other key to ensure gg requires consecutive presses
+                if action != Some(ActionId::Top) {
                     self.pending_g = false;
                 }
-                match key.code {
-                    KeyCode::Char('l') | KeyCode::Right => {
+                // Esc cancels the selection or search before going back
+                if key.code == KeyCode::Esc {
+                    if self.selection_anchor.is_some() {
+                        self.selection_anchor = None;
+                        return (None, vec![]);
+                    }
+                    if self.search.is_active() {
+                        self.search.clear();
+                        return (None, vec![]);
+                    }
+                }
+                match action {
+                    Some(ActionId::NextTry) => {
                         if !self.all.is_empty() && self.current < self.all.len() - 1 {
                             self.current += 1;
+                            self.selection_anchor = None;
+                            self.refresh_search();
                         }
                     }
-                    KeyCode::Char('h') | KeyCode::Left => {
+                    Some(ActionId::PreviousTry) => {
                         if self.all.is_empty() || self.current == 0 {
                             // Navigate back to previous panel
-                            return (Some(FlowrsEvent::Key(*key)), vec![]);
+                            let back = keymap().key(ActionId::PreviousTab);
+                            return (Some(FlowrsEvent::Key(back)), vec![]);
                         }
                         self.current -= 1;
+                        self.selection_anchor = None;
+                        self.refresh_search();
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        let line_count = self.current_line_count();
-                        let current_pos = self.scroll_mode.position(line_count);
-                        let new_pos = current_pos.saturating_add(1);
-                        if new_pos >= line_count.saturating_sub(1) {
+                    Some(ActionId::Next) => {
+                        // Reaching the position where the last line is at the
+                        // bottom resumes following
+                        let follow_top = self.follow_top();
+                        let new_pos = self.scroll_mode.position(follow_top).saturating_add(1);
+                        if new_pos >= follow_top {
                             self.scroll_mode = ScrollMode::Following;
                         } else {
                             self.scroll_mode = ScrollMode::Manual { position: new_pos };
                         }
                     }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        let line_count = self.current_line_count();
-                        let current_pos = self.scroll_mode.position(line_count);
+                    Some(ActionId::Previous) => {
                         self.scroll_mode = ScrollMode::Manual {
-                            position: current_pos.saturating_sub(1),
+                            position: self.scroll_position().saturating_sub(1),
                         };
                     }
-                    KeyCode::Char('o') => {
+                    Some(ActionId::ScrollRight) if !self.wrap => {
+                        self.horizontal_scroll =
+                            self.horizontal_scroll.saturating_add(HORIZONTAL_STEP);
+                    }
+                    Some(ActionId::ScrollLeft) if !self.wrap => {
+                        self.horizontal_scroll =
+                            self.horizontal_scroll.saturating_sub(HORIZONTAL_STEP);
+                    }
+                    Some(ActionId::LineStart) => self.horizontal_scroll = 0,
+                    Some(ActionId::Wrap) => {
+                        self.wrap = !self.wrap;
+                        self.horizontal_scroll = 0;
+                    }
+                    Some(ActionId::LineNumbers) => self.line_numbers = !self.line_numbers,
+                    Some(ActionId::Timestamps) => {
+                        self.format.show_timestamps = !self.format.show_timestamps;
+                        self.refresh_search();
+                    }
+                    Some(ActionId::Open) => {
                         if self.current_log().is_some() {
                             if let (Some(dag_id), Some(dag_run_id), Some(task_id)) =
                                 (ctx.dag_id(), ctx.dag_run_id(), ctx.task_id())
@@ -203,21 +663,66 @@ impl Model for LogModel {
                             }
                         }
                     }
-                    KeyCode::Char('G') => {
+                    Some(ActionId::Bottom) => {
+                        self.scroll_mode = ScrollMode::Following;
+                    }
+                    Some(ActionId::ExtraFields) => {
+                        self.format.show_extra = !self.format.show_extra;
+                        self.refresh_search();
+                    }
+                    Some(ActionId::Colours) => {
+                        self.format.colors = !self.format.colors;
+                    }
+                    Some(ActionId::MinLevel) => {
+                        self.min_level = LogLevel::next(self.min_level);
+                        self.selection_anchor = None;
+                        // Line positions change with the filter
                         self.scroll_mode = ScrollMode::Following;
+                        self.refresh_search();
+                    }
+                    Some(ActionId::Search) => {
+                        self.scroll_before_search = Some(self.scroll_mode);
+                        self.search.start();
+                    }
+                    Some(ActionId::NextMatch) => {
+                        let line = self.search.next(self.scroll_position());
+                        self.scroll_to_match(line);
+                    }
+                    Some(ActionId::PreviousMatch) => {
+                        let line = self.search.previous(self.scroll_position());
+                        self.scroll_to_match(line);
+                    }
+                    Some(ActionId::ToggleRegex) if self.search.is_active() => {
+                        self.search.regex = !self.search.regex;
+                        self.refresh_search();
+                    }
+                    // Shortcut searches for the usual markers of a failure
+                    Some(ActionId::FindTraceback) => self.search_for("Traceback"),
+                    Some(ActionId::FindError) => self.search_for("ERROR"),
+                    Some(ActionId::FindException) => self.search_for("Exception"),
+                    Some(ActionId::Save) => return (None, self.save(false, ctx)),
+                    Some(ActionId::SaveAll) => return (None, self.save(true, ctx)),
+                    Some(ActionId::Pager) => self.open_in(ExternalProgram::Pager, ctx),
+                    Some(ActionId::Editor) => self.open_in(ExternalProgram::Editor, ctx),
+                    Some(ActionId::Select) => {
+                        self.selection_anchor = match self.selection_anchor {
+                            Some(_) => None,
+                            None => self.selection().map(|(start, _)| start),
+                        };
                     }
-                    KeyCode::Char('F') => {
+                    Some(ActionId::Copy) => self.copy_selection(),
+                    Some(ActionId::Diff) => self.open_diff(),
+                    Some(ActionId::Follow) => {
                         // Toggle follow mode
                         if self.scroll_mode.is_following() {
-                            let line_count = self.current_line_count();
                             self.scroll_mode = ScrollMode::Manual {
-                                position: line_count.saturating_sub(1),
+                                position: self.follow_top(),
                             };
                         } else {
                             self.scroll_mode = ScrollMode::Following;
                         }
                     }
-                    KeyCode::Char('g') => {
+                    Some(ActionId::Top) => {
                         // gg: go to top of log
                         if self.pending_g {
                             self.scroll_mode = ScrollMode::Manual { position: 0 };
@@ -230,7 +735,28 @@ impl Model for LogModel {
                     _ => return (Some(FlowrsEvent::Key(*key)), vec![]), // if no match, return the event
                 }
             }
-            FlowrsEvent::Mouse | FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => (),
+            FlowrsEvent::Mouse(mouse) => {
+                if self.error_popup.is_some() {
+                    if is_click(*mouse) {
+                        self.error_popup = None;
+                    }
+                    return (None, vec![]);
+                }
+                if self.search.editing {
+                    return (None, vec![]);
+                }
+                // The wheel scrolls like j/k, and sideways like </> when
+                // lines are not wrapped
+                let action = match scroll_key(*mouse) {
+                    Some(KeyCode::Down) => ActionId::Next,
+                    Some(KeyCode::Up) => ActionId::Previous,
+                    Some(KeyCode::Left) => ActionId::ScrollLeft,
+                    Some(KeyCode::Right) => ActionId::ScrollRight,
+                    _ => return (None, vec![]),
+                };
+                return self.update(&FlowrsEvent::Key(keymap().key(action)), ctx);
+            }
+            FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => (),
         }
 
         (None, vec![])
@@ -242,6 +768,7 @@ mod tests {
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
     use super::*;
+    use crate::airflow::model::common::Log;
     use crate::app::state::NavigationContext;
 
     /// Regression test: pressing `o` to open the Airflow UI while the log list
@@ -267,11 +794,17 @@ mod tests {
         assert!(messages.is_empty());
     }
 
-    fn log(content: &str) -> Log {
-        Log {
-            continuation_token: None,
-            content: content.to_string(),
-        }
+    fn log(content: &str) -> TaskLog {
+        let mut log = TaskLog::new(100);
+        log.apply(
+            None,
+            Log {
+                continuation_token: None,
+                lines: LogLine::parse_text(content),
+                unterminated: false,
+            },
+        );
+        log
     }
 
     #[test]
@@ -287,4 +820,328 @@ mod tests {
         assert_eq!(model.current_index(), 1); // 3 % 2
         assert!(model.current_log().is_some());
     }
+
+    #[test]
+    fn level_filter_keeps_records_and_their_continuations() {
+        let mut model = LogModel::default();
+        model.update_logs(vec![log("*** header\n\
+             [t] {a.py:1} INFO - starting\n\
+             [t] {a.py:2} ERROR - failed\n\
+             Traceback (most recent call last):\n\
+             [t] {a.py:3} WARNING - retrying")]);
+        assert_eq!(model.current_line_count(), 5);
+
+        let ctx = NavigationContext::None;
+        let press = |model: &mut LogModel, c| {
+            model.update(
+                &FlowrsEvent::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty())),
+                &ctx,
+            );
+        };
+        // all -> DEBUG+ -> INFO+ -> WARNING+
+        for _ in 0..3 {
+            press(&mut model, 'L');
+        }
+        assert_eq!(model.min_level, Some(LogLevel::Warning));
+        let events: Vec<&str> = model.visible_lines().map(|l| l.event.as_str()).collect();
+        assert_eq!(
+            events,
+            ["failed", "Traceback (most recent call last):", "retrying"]
+        );
+        assert_eq!(model.current_line_count(), 3);
+
+        press(&mut model, 'e');
+        assert!(model.format.show_extra);
+    }
+
+    fn key(code: KeyCode) -> FlowrsEvent {
+        FlowrsEvent::Key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    fn failed_log_model() -> LogModel {
+        let mut model = LogModel::default();
+        model.update_logs(vec![log("[t] {a.py:1} INFO - starting\n\
+             [t] {a.py:2} ERROR - failed\n\
+             Traceback (most recent call last):\n\
+             ValueError: bad\n\
+             [t] {a.py:3} INFO - marking task as FAILED")]);
+        model
+    }
+
+    #[test]
+    fn typing_a_query_consumes_keys_and_jumps_to_matches() {
+        let mut model = failed_log_model();
+        let ctx = NavigationContext::None;
+        model.update(&key(KeyCode::Char('g')), &ctx);
+        model.update(&key(KeyCode::Char('g')), &ctx);
+        model.update(&key(KeyCode::Char('/')), &ctx);
+        for c in "failed".chars() {
+            let (fall_through, _) = model.update(&key(KeyCode::Char(c)), &ctx);
+            assert!(fall_through.is_none());
+        }
+        assert_eq!(model.search.match_count(), 2);
+        // Smart case: "failed" also matches "FAILED"
+        assert!(matches!(
+            model.scroll_mode,
+            ScrollMode::Manual { position: 1 }
+        ));
+
+        model.update(&key(KeyCode::Enter), &ctx);
+        assert!(!model.search.editing);
+        model.update(&key(KeyCode::Char('n')), &ctx);
+        assert!(matches!(
+            model.scroll_mode,
+            ScrollMode::Manual { position: 4 }
+        ));
+        model.update(&key(KeyCode::Char('N')), &ctx);
+        assert!(matches!(
+            model.scroll_mode,
+            ScrollMode::Manual { position: 1 }
+        ));
+
+        // Esc clears the search instead of leaving the panel
+        let (fall_through, _) = model.update(&key(KeyCode::Esc), &ctx);
+        assert!(fall_through.is_none());
+        assert!(!model.search.is_active());
+        let (fall_through, _) = model.update(&key(KeyCode::Esc), &ctx);
+        assert!(fall_through.is_some());
+    }
+
+    #[test]
+    fn cancelling_a_search_restores_the_scroll_position() {
+        let mut model = failed_log_model();
+        let ctx = NavigationContext::None;
+        model.update(&key(KeyCode::Char('/')), &ctx);
+        model.update(&key(KeyCode::Char('s')), &ctx);
+        assert!(!model.scroll_mode.is_following());
+        model.update(&key(KeyCode::Esc), &ctx);
+        assert!(model.scroll_mode.is_following());
+        assert!(!model.search.is_active());
+    }
+
+    fn task_context() -> NavigationContext {
+        NavigationContext::Task {
+            environment: "env".to_string(),
+            dag_id: "etl".into(),
+            dag_run_id: "manual__2025-01-01T00:00:00+00:00".into(),
+            task_id: "load".into(),
+            task_try: 1,
+        }
+    }
+
+    #[test]
+    fn log_file_names_are_filesystem_safe() {
+        assert_eq!(
+            log_file_name(
+                &"etl".into(),
+                &"manual__2025-01-01T00:00:00+00:00".into(),
+                &"group.load".into(),
+                2
+            ),
+            "etl__manual__2025-01-01T00_00_00_00_00__group.load__try2.log"
+        );
+    }
+
+    #[test]
+    fn copies_the_selected_line_range() {
+        let mut model = failed_log_model();
+        let ctx = NavigationContext::None;
+        model.update(&key(KeyCode::Char('g')), &ctx);
+        model.update(&key(KeyCode::Char('g')), &ctx);
+        model.update(&key(KeyCode::Char('j')), &ctx);
+        model.update(&key(KeyCode::Char('v')), &ctx);
+        model.update(&key(KeyCode::Char('j')), &ctx);
+        assert_eq!(model.selection(), Some((1, 2)));
+
+        model.update(&key(KeyCode::Char('y')), &ctx);
+        assert_eq!(
+            model.terminal_request,
+            Some(TerminalRequest::Copy(
+                "t ERROR    a.py:2 failed\nTraceback (most recent call last):".to_string()
+            ))
+        );
+        assert_eq!(model.selection_anchor, None);
+        assert!(model.notice.is_some());
+
+        // Without a selection only the current line is copied
+        model.update(&key(KeyCode::Char('y')), &ctx);
+        assert_eq!(
+            model.terminal_request,
+            Some(TerminalRequest::Copy(
+                "Traceback (most recent call last):".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn saves_and_opens_the_current_try() {
+        let mut model = failed_log_model();
+        let ctx = task_context();
+
+        let (_, messages) = model.update(&key(KeyCode::Char('s')), &ctx);
+        assert!(matches!(
+            messages.as_slice(),
+            [WorkerMessage::SaveTaskLogs {
+                task_try: Some(1),
+                ..
+            }]
+        ));
+        let (_, messages) = model.update(&key(KeyCode::Char('S')), &ctx);
+        assert!(matches!(
+            messages.as_slice(),
+            [WorkerMessage::SaveTaskLogs { task_try: None, .. }]
+        ));
+
+        model.update(&key(KeyCode::Char('p')), &ctx);
+        match &model.terminal_request {
+            Some(TerminalRequest::Open {
+                program,
+                file_name,
+                content,
+            }) => {
+                assert_eq!(*program, ExternalProgram::Pager);
+                assert!(file_name.starts_with("etl__manual__"));
+                assert!(content.starts_with("[t] {a.py:1} INFO - starting\n"));
+            }
+            other => panic!("unexpected request: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn diff_mode_compares_tries_and_takes_the_vim_keys() {
+        let ctx = NavigationContext::None;
+        let mut model = LogModel::default();
+        model.update_logs(vec![log("a")]);
+        model.update(&key(KeyCode::Char('d')), &ctx);
+        assert!(model.diff.is_none());
+        assert!(model.notice.is_some());
+
+        model.update_logs(vec![log("a\nb"), log("a\nc"), log("a\nb\nc")]);
+        model.current = 2;
+        model.update(&key(KeyCode::Char('d')), &ctx);
+        let diff = model.diff.as_ref().unwrap();
+        assert_eq!((diff.base, diff.target), (1, 2));
+
+        model.update(&key(KeyCode::Char('j')), &ctx);
+        model.update(&key(KeyCode::Char('l')), &ctx);
+        let diff = model.diff.as_ref().unwrap();
+        assert_eq!((diff.base, diff.target), (0, 2));
+        // h/l change the base try instead of switching tries or panels
+        assert_eq!(model.current, 2);
+        let (fall_through, _) = model.update(&key(KeyCode::Char('h')), &ctx);
+        assert!(fall_through.is_none());
+        assert_eq!(model.diff.as_ref().unwrap().base, 1);
+
+        // New lines for a compared try outdate the diff, which is refreshed
+        // with a diff computed elsewhere
+        model.update_logs(vec![log("a\nb"), log("a\nc\nd"), log("a\nb\nc")]);
+        assert_eq!(model.diff.as_ref().unwrap().old.len(), 2);
+        let (base, target, old, new) = model.outdated_diff().unwrap();
+        model.refresh_diff(LogDiff::new(base, target, &old, &new));
+        assert_eq!(model.diff.as_ref().unwrap().old.len(), 3);
+        assert!(model.outdated_diff().is_none());
+
+        model.update(&key(KeyCode::Esc), &ctx);
+        assert!(model.diff.is_none());
+    }
+
+    #[test]
+    fn shortcut_and_regex_searches() {
+        let mut model = failed_log_model();
+        let ctx = NavigationContext::None;
+        model.update(&key(KeyCode::Char('T')), &ctx);
+        assert_eq!(model.search.query, "Traceback");
+        assert_eq!(model.search.match_count(), 1);
+        assert!(matches!(
+            model.scroll_mode,
+            ScrollMode::Manual { position: 2 }
+        ));
+
+        model.update(&key(KeyCode::Char('/')), &ctx);
+        for c in r"\w+Error".chars() {
+            model.update(&key(KeyCode::Char(c)), &ctx);
+        }
+        assert_eq!(model.search.match_count(), 0);
+        model.update(
+            &FlowrsEvent::Key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL)),
+            &ctx,
+        );
+        assert!(model.search.regex);
+        assert_eq!(model.search.match_count(), 1);
+    }
+
+    #[test]
+    fn searches_only_the_appended_lines() {
+        let chunk = |content: &str, token: &str| Log::from_text(Some(token.into()), content);
+        let mut capped = TaskLog::new(3);
+        capped.apply(None, chunk("error 1\nok\n", "t1"));
+        let mut model = LogModel::default();
+        model.update_logs(vec![capped.clone()]);
+        model.search_for("error");
+        assert_eq!(model.search.match_count(), 1);
+
+        // Two more lines push the first one out of the capped log
+        capped.apply(Some("t1"), chunk("error 2\nerror 3\n", "t2"));
+        model.update_logs(vec![capped.clone()]);
+        assert_eq!(model.search.match_count(), 2);
+        assert_eq!(model.search.matches_on(1).count(), 1);
+        assert_eq!(model.search.matches_on(2).count(), 1);
+
+        // Another task's log is searched from scratch
+        model.update_logs(vec![log("error\nerror\nerror")]);
+        assert_eq!(model.search.match_count(), 3);
+    }
+
+    #[test]
+    fn follow_position_counts_wrapped_rows() {
+        let mut model = LogModel::default();
+        model.update_logs(vec![log(&format!(
+            "one\ntwo\nthree\nfour\n{}",
+            "x".repeat(25)
+        ))]);
+        assert_eq!(model.follow_top(), 4);
+
+        // The last line takes three rows of a ten column viewport
+        model.viewport = Some(Viewport {
+            width: 10,
+            height: 4,
+        });
+        assert_eq!(model.follow_top(), 3);
+        model.line_numbers = true;
+        assert_eq!(model.follow_top(), 4);
+
+        // Without wrapping each line is one row
+        let ctx = NavigationContext::None;
+        model.update(&key(KeyCode::Char('#')), &ctx);
+        model.update(&key(KeyCode::Char('w')), &ctx);
+        assert_eq!(model.follow_top(), 1);
+        model.scroll_mode = ScrollMode::Manual { position: 0 };
+        model.update(&key(KeyCode::Char('j')), &ctx);
+        assert!(model.scroll_mode.is_following());
+    }
+
+    #[test]
+    fn horizontal_scrolling_and_hidden_timestamps() {
+        let mut model = LogModel::default();
+        model.update_logs(vec![log(
+            "[2025-01-01T00:00:00.000+0000] {a.py:1} INFO - started 2025 run",
+        )]);
+        let ctx = NavigationContext::None;
+
+        // Lines wrap by default, so there is nothing to scroll sideways
+        model.update(&key(KeyCode::Char('>')), &ctx);
+        assert_eq!(model.horizontal_scroll, 0);
+        model.update(&key(KeyCode::Char('w')), &ctx);
+        model.update(&key(KeyCode::Char('>')), &ctx);
+        model.update(&key(KeyCode::Char('>')), &ctx);
+        model.update(&key(KeyCode::Char('<')), &ctx);
+        assert_eq!(model.horizontal_scroll, HORIZONTAL_STEP);
+        model.update(&key(KeyCode::Char('0')), &ctx);
+        assert_eq!(model.horizontal_scroll, 0);
+
+        model.search_for("2025");
+        assert_eq!(model.search.match_count(), 2);
+        model.update(&key(KeyCode::Char('t')), &ctx);
+        assert_eq!(model.search.match_count(), 1);
+    }
 }
@@ -1,17 +1,472 @@
+use std::ops::Range;
+
+use ansi_to_tui::IntoText;
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Layout, Rect},
     style::{Modifier, Style},
-    text::{Line, Text},
+    text::{Line, Span},
     widgets::{
-        Block, BorderType, Borders, Paragraph, Scrollbar, ScrollbarOrientation, StatefulWidget,
-        Tabs, Widget, Wrap,
+        Block, BorderType, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        StatefulWidget, Tabs, Widget, Wrap,
     },
 };
 
+use crate::airflow::model::common::{LogLevel, LogLine};
+use crate::app::actions::ActionId;
+use crate::app::keymap::{format_key, keymap};
 use crate::ui::theme::theme;
 
-use super::LogModel;
+use similar::DiffTag;
+
+use super::diff::{DiffLayout, LogDiff};
+use super::search::LogSearch;
+use super::{LogModel, Viewport};
+
+/// Width of the level column, fitting the longest level name
+const LEVEL_WIDTH: usize = 8;
+
+fn level_style(level: LogLevel) -> Style {
+    let t = theme();
+    match level {
+        LogLevel::Debug => t.log_debug_style,
+        LogLevel::Info => t.log_info_style,
+        LogLevel::Warning => t.log_warning_style,
+        LogLevel::Error => t.log_error_style,
+        LogLevel::Critical => t.log_critical_style,
+    }
+}
+
+/// How log lines are turned into styled text
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LineFormat {
+    /// Show the extra key-value fields of structured events
+    pub show_extra: bool,
+    /// Apply ANSI colours, level colours and traceback highlighting
+    pub colors: bool,
+    /// Show the time column of log records
+    pub show_timestamps: bool,
+}
+
+impl Default for LineFormat {
+    fn default() -> Self {
+        Self {
+            show_extra: false,
+            colors: true,
+            show_timestamps: true,
+        }
+    }
+}
+
+/// A message as spans. ANSI escape sequences become styles, or are dropped
+/// when colours are off, so the displayed text is the same either way.
+fn message_spans(text: &str, colors: bool) -> Vec<Span<'_>> {
+    if !text.contains('\x1b') {
+        return vec![Span::raw(text)];
+    }
+    let Ok(parsed) = text.into_text() else {
+        return vec![Span::raw(text)];
+    };
+    parsed
+        .lines
+        .into_iter()
+        .flat_map(|line| line.spans)
+        .map(|span| {
+            if colors {
+                span
+            } else {
+                Span::raw(span.content)
+            }
+        })
+        .collect()
+}
+
+/// Style of a line that is part of a Python traceback, if it is one
+fn traceback_style(text: &str) -> Option<Style> {
+    let t = theme();
+    let trimmed = text.trim_start();
+    if trimmed.starts_with("Traceback (most recent call last)") {
+        return Some(t.log_error_style);
+    }
+    if trimmed.starts_with("File \"")
+        || trimmed.starts_with("During handling of the above exception")
+        || trimmed.starts_with("The above exception was the direct cause")
+    {
+        return Some(Style::default().fg(t.text_muted));
+    }
+    // The final `module.SomeError: message` line
+    let name = trimmed.split_once(':').map_or(trimmed, |(name, _)| name);
+    let is_exception = ["Error", "Exception", "Exit", "Interrupt"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.');
+    is_exception.then_some(t.log_error_style)
+}
+
+/// Render a line that is not an Airflow record: tracebacks are highlighted
+/// and a leading level name, as in Python's default `INFO:name:message`
+/// format, gets its level colour.
+fn continuation_line(text: &str, colors: bool) -> Line<'_> {
+    if !colors || text.contains('\x1b') {
+        return Line::from(message_spans(text, colors));
+    }
+    if let Some(style) = traceback_style(text) {
+        return Line::from(Span::styled(text, style));
+    }
+    let word_end = text
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(text.len());
+    match LogLevel::parse(&text[..word_end]) {
+        Some(level) if word_end > 0 => Line::from(vec![
+            Span::styled(&text[..word_end], level_style(level)),
+            Span::raw(&text[word_end..]),
+        ]),
+        _ => Line::raw(text),
+    }
+}
+
+/// Render a log line as columns: time, level, logger, message and, when
+/// enabled, the extra fields of structured events. Continuation lines are
+/// rendered without columns.
+fn log_line(line: &LogLine, format: LineFormat) -> Line<'_> {
+    let t = theme();
+    if line.continuation {
+        return continuation_line(&line.event, format.colors);
+    }
+    let color = |style: Style| {
+        if format.colors {
+            style
+        } else {
+            Style::default()
+        }
+    };
+    let muted = Style::default().fg(t.text_muted);
+    let mut spans = Vec::new();
+    if let Some(timestamp) = line.timestamp.as_ref().filter(|_| format.show_timestamps) {
+        spans.push(Span::styled(format!("{timestamp} "), muted));
+    }
+    if let Some(level) = line.level {
+        spans.push(Span::styled(
+            format!("{level:<LEVEL_WIDTH$}"),
+            color(level_style(level)),
+        ));
+        spans.push(Span::raw(" "));
+    }
+    if let Some(logger) = &line.logger {
+        spans.push(Span::styled(
+            format!("{logger} "),
+            color(Style::default().fg(t.purple)),
+        ));
+    }
+    spans.extend(message_spans(&line.event, format.colors));
+    if format.show_extra {
+        for (key, value) in &line.extra {
+            spans.push(Span::styled(
+                format!(" {key}={value}"),
+                Style::default().fg(t.text_ghost),
+            ));
+        }
+    }
+    Line::from(spans)
+}
+
+/// Number of screen rows a line takes in the given width
+fn line_height(line: &Line<'_>, width: u16, wrap: bool) -> usize {
+    if !wrap || width == 0 {
+        return 1;
+    }
+    Paragraph::new(line.clone())
+        .wrap(Wrap { trim: false })
+        .line_count(width)
+        .max(1)
+}
+
+/// Width of the line number gutter, including a separating space
+fn gutter_width(model: &LogModel) -> u16 {
+    if !model.line_numbers {
+        return 0;
+    }
+    let last_number = model
+        .current_log()
+        .map_or(0, |log| log.dropped() + log.len());
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the number of digits of a line count is tiny"
+    )]
+    let digits = last_number.max(1).ilog10() as u16 + 1;
+    digits + 1
+}
+
+/// The first line to show so the last line ends at the bottom of the
+/// viewport, taking the rows of wrapped lines into account
+pub(super) fn follow_top(model: &LogModel, viewport: Viewport) -> usize {
+    let width = viewport.width.saturating_sub(gutter_width(model));
+    let height = usize::from(viewport.height);
+    let mut rows = 0;
+    let mut shown = 0;
+    for line in model.visible_lines().rev() {
+        if rows >= height {
+            break;
+        }
+        rows += line_height(&log_line(line, model.format), width, model.wrap);
+        shown += 1;
+    }
+    // A partly visible first line would push the last line off the bottom
+    if rows > height && shown > 1 {
+        shown -= 1;
+    }
+    model.current_line_count() - shown
+}
+
+/// The displayed text of a log line, which search matches are found in
+pub(super) fn line_text(line: &LogLine, format: LineFormat) -> String {
+    log_line(line, format)
+        .spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+/// Restyle the matched byte ranges of a line, splitting spans at the match
+/// boundaries. Each match comes with whether it is the current match.
+fn highlight<'a>(line: Line<'a>, matches: &[(Range<usize>, bool)]) -> Line<'a> {
+    if matches.is_empty() {
+        return line;
+    }
+    let t = theme();
+    let mut spans = Vec::new();
+    let mut offset = 0;
+    for span in line.spans {
+        let end = offset + span.content.len();
+        let mut pos = offset;
+        for (range, current) in matches {
+            let start = range.start.max(pos);
+            let stop = range.end.min(end);
+            if start >= stop {
+                continue;
+            }
+            if start > pos {
+                spans.push(Span::styled(
+                    span.content[pos - offset..start - offset].to_string(),
+                    span.style,
+                ));
+            }
+            let style = if *current {
+                t.search_current_style
+            } else {
+                t.search_match_style
+            };
+            spans.push(Span::styled(
+                span.content[start - offset..stop - offset].to_string(),
+                span.style.patch(style),
+            ));
+            pos = stop;
+        }
+        if pos < end {
+            spans.push(Span::styled(
+                span.content[pos - offset..].to_string(),
+                span.style,
+            ));
+        }
+        offset = end;
+    }
+    Line::from(spans)
+}
+
+/// The query and match counter, shown in the title while a search is active
+fn search_title(search: &LogSearch) -> Option<String> {
+    if search.query.is_empty() {
+        return None;
+    }
+    let mode = if search.regex { " (regex)" } else { "" };
+    let status = if search.error.is_some() {
+        "invalid regex".to_string()
+    } else if search.match_count() == 0 {
+        "no matches".to_string()
+    } else {
+        format!(
+            "[{}/{}]",
+            search
+                .current_position()
+                .map_or_else(|| "-".to_string(), |p| p.to_string()),
+            search.match_count()
+        )
+    };
+    Some(format!(" /{}{mode} {status} ", search.query))
+}
+
+/// The key that runs `id`, as shown in the titles
+fn key(id: ActionId) -> String {
+    format_key(&keymap().key(id))
+}
+
+/// The bottom title: the search prompt while typing a query, otherwise the
+/// follow state and search navigation hints
+fn bottom_title(model: &LogModel) -> String {
+    let search = &model.search;
+    if let Some(notice) = &model.notice {
+        return format!(" {notice} ");
+    }
+    if model.selection_anchor.is_some() {
+        return format!(
+            " Selecting: {}/{} to extend | {}: copy | Esc: cancel ",
+            key(ActionId::Next),
+            key(ActionId::Previous),
+            key(ActionId::Copy),
+        );
+    }
+    if search.editing {
+        return format!(
+            " /{}\u{2588} | Enter: confirm | Esc: cancel | {}: regex {} ",
+            search.query,
+            key(ActionId::ToggleRegex),
+            if search.regex { "on" } else { "off" },
+        );
+    }
+    let follow = if model.scroll_mode.is_following() {
+        format!(" [{}] follow: ON - auto-scrolling ", key(ActionId::Follow))
+    } else {
+        format!(
+            " [{}] follow: OFF - press {} to resume ",
+            key(ActionId::Follow),
+            key(ActionId::Bottom)
+        )
+    };
+    if search.is_active() {
+        format!(
+            "{follow}| {}/{}: next/previous match | Esc: clear search ",
+            key(ActionId::NextMatch),
+            key(ActionId::PreviousMatch)
+        )
+    } else if !model.wrap {
+        format!(
+            "{follow}| {}/{}: scroll sideways | {}: line start | {}: wrap ",
+            key(ActionId::ScrollLeft),
+            key(ActionId::ScrollRight),
+            key(ActionId::LineStart),
+            key(ActionId::Wrap)
+        )
+    } else {
+        format!(
+            "{follow}| {}: search | {}/{}/{}: Traceback/ERROR/Exception | {}/{}: save | {}/{}: pager/editor | {}/{}: select/copy | {}: diff tries ",
+            key(ActionId::Search),
+            key(ActionId::FindTraceback),
+            key(ActionId::FindError),
+            key(ActionId::FindException),
+            key(ActionId::Save),
+            key(ActionId::SaveAll),
+            key(ActionId::Pager),
+            key(ActionId::Editor),
+            key(ActionId::Select),
+            key(ActionId::Copy),
+            key(ActionId::Diff)
+        )
+    }
+}
+
+/// A side of a diff row: the line, styled as removed or added when changed
+fn diff_side(lines: &[String], index: Option<usize>, changed: Style) -> Line<'_> {
+    index.map_or_else(Line::default, |i| Line::styled(lines[i].as_str(), changed))
+}
+
+/// Render the diff between two tries in its current layout
+fn render_diff(diff: &LogDiff, area: Rect, buffer: &mut Buffer) {
+    let t = theme();
+    let (removed, added) = diff.change_counts();
+    let rows = diff.rows();
+    let visible = usize::from(area.height.saturating_sub(2));
+    let shown = rows.iter().skip(diff.scroll).take(visible);
+    let style_of = |tag: DiffTag, side_changed: Style| match tag {
+        DiffTag::Equal => Style::default(),
+        _ => side_changed,
+    };
+
+    let block = Block::default()
+        .border_type(BorderType::Rounded)
+        .borders(Borders::ALL)
+        .title(format!(
+            " Diff: try {} → try {} (-{removed} +{added}) ",
+            diff.base + 1,
+            diff.target + 1
+        ))
+        .title_top(
+            Line::from(format!(
+                " [{}] layout: {} ",
+                key(ActionId::DiffLayout),
+                match diff.layout {
+                    DiffLayout::Unified => "unified",
+                    DiffLayout::SideBySide => "side by side",
+                }
+            ))
+            .right_aligned(),
+        )
+        .title_bottom(format!(
+            " {}/{}: base try | {}/{}, {}/{}: scroll | {}/Esc: close diff ",
+            key(ActionId::PreviousTry),
+            key(ActionId::NextTry),
+            key(ActionId::Next),
+            key(ActionId::Previous),
+            keymap().hint(ActionId::Top),
+            key(ActionId::Bottom),
+            key(ActionId::Diff)
+        ))
+        .border_style(t.border_style)
+        .title_style(t.title_style);
+    let inner = block.inner(area);
+    block.render(area, buffer);
+
+    match diff.layout {
+        DiffLayout::Unified => {
+            let lines: Vec<Line> = shown
+                .map(|row| match (row.old, row.new) {
+                    (_, Some(new)) if row.tag == DiffTag::Insert => {
+                        Line::styled(format!("+ {}", diff.new[new]), t.diff_added_style)
+                    }
+                    (Some(old), _) if row.tag == DiffTag::Delete => {
+                        Line::styled(format!("- {}", diff.old[old]), t.diff_removed_style)
+                    }
+                    (_, new) => Line::raw(format!(
+                        "  {}",
+                        new.map_or("", |new| diff.new[new].as_str())
+                    )),
+                })
+                .collect();
+            Paragraph::new(lines)
+                .style(t.default_style)
+                .render(inner, buffer);
+        }
+        DiffLayout::SideBySide => {
+            let columns = Layout::horizontal([Constraint::Percentage(50); 2]).split(inner);
+            let (old, new): (Vec<Line>, Vec<Line>) = shown
+                .map(|row| {
+                    (
+                        diff_side(&diff.old, row.old, style_of(row.tag, t.diff_removed_style)),
+                        diff_side(&diff.new, row.new, style_of(row.tag, t.diff_added_style)),
+                    )
+                })
+                .unzip();
+            Paragraph::new(old)
+                .style(t.default_style)
+                .block(
+                    Block::default()
+                        .borders(Borders::RIGHT)
+                        .border_style(t.border_style),
+                )
+                .render(columns[0], buffer);
+            Paragraph::new(new)
+                .style(t.default_style)
+                .render(columns[1], buffer);
+        }
+    }
+
+    let mut scroll_state = ScrollbarState::new(rows.len()).position(diff.scroll);
+    Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(Some("↑"))
+        .end_symbol(Some("↓"))
+        .render(area, buffer, &mut scroll_state);
+}
 
 impl Widget for &mut LogModel {
     fn render(self, area: Rect, buffer: &mut Buffer) {
@@ -53,43 +508,108 @@ impl Widget for &mut LogModel {
             .constraints([Constraint::Length(3), Constraint::Min(0)])
             .split(area);
 
-        if let Some(log) = self.current_log() {
-            let mut content = Text::default();
-            for line in log.content.lines() {
-                content.push_line(Line::raw(line));
-            }
+        if let Some(diff) = &self.diff {
+            render_diff(diff, chunks[1], buffer);
+        } else if let Some(log) = self.current_log() {
+            let title = match log.dropped() {
+                0 => " Content ".to_string(),
+                dropped => format!(" Content ({dropped} earlier lines dropped) "),
+            };
+            let first_number = log.dropped() + 1;
+            let on_off = |on: bool| if on { "on" } else { "off" };
+            let block = Block::default()
+                .border_type(BorderType::Rounded)
+                .borders(Borders::ALL)
+                .title(title)
+                .title(search_title(&self.search).unwrap_or_default())
+                .title_top(
+                    Line::from(format!(
+                        " [{}] level: {} | [{}] extra: {} [{}] colours: {} [{}] wrap: {} [{}] times: {} [{}] numbers: {} ",
+                        key(ActionId::MinLevel),
+                        self.min_level
+                            .map_or_else(|| "all".to_string(), |level| format!("{level}+")),
+                        key(ActionId::ExtraFields),
+                        on_off(self.format.show_extra),
+                        key(ActionId::Colours),
+                        on_off(self.format.colors),
+                        key(ActionId::Wrap),
+                        on_off(self.wrap),
+                        key(ActionId::Timestamps),
+                        on_off(self.format.show_timestamps),
+                        key(ActionId::LineNumbers),
+                        on_off(self.line_numbers),
+                    ))
+                    .right_aligned(),
+                )
+                .title_bottom(bottom_title(self))
+                .border_style(t.border_style)
+                .title_style(t.title_style);
+            let inner = block.inner(chunks[1]);
+            block.render(chunks[1], buffer);
 
-            let line_count = self.current_line_count();
-            let scroll_pos = self.scroll_mode.position(line_count);
+            self.viewport = Some(Viewport {
+                width: inner.width,
+                height: inner.height,
+            });
+            let scroll_pos = self.scroll_position();
+            let gutter = gutter_width(self);
+            let [gutter_area, content_area] =
+                Layout::horizontal([Constraint::Length(gutter), Constraint::Min(0)]).areas(inner);
 
-            #[expect(
-                clippy::cast_possible_truncation,
-                reason = "value is bounded by terminal/layout dimensions and stays well within the target integer range"
-            )]
-            let paragraph = Paragraph::new(content)
-                .block(
-                    Block::default()
-                        .border_type(BorderType::Rounded)
-                        .borders(Borders::ALL)
-                        .title(" Content ")
-                        .title_bottom(if self.scroll_mode.is_following() {
-                            " [F]ollow: ON - auto-scrolling "
-                        } else {
-                            " [F]ollow: OFF - press G to resume "
-                        })
-                        .border_style(t.border_style)
-                        .title_style(t.title_style),
-                )
-                .wrap(Wrap { trim: true })
-                .style(t.default_style)
-                .scroll((scroll_pos as u16, 0));
+            // Only the lines in view are rendered
+            let selection = self
+                .selection_anchor
+                .and_then(|_| self.selection())
+                .map(|(start, end)| start..=end);
+            let height = usize::from(inner.height);
+            let mut rows = 0;
+            let mut content = Vec::new();
+            let mut numbers = Vec::new();
+            for (index, (number, line)) in self.numbered_lines().enumerate().skip(scroll_pos) {
+                if rows >= height {
+                    break;
+                }
+                let matches: Vec<_> = self.search.matches_on(index).collect();
+                let mut line = highlight(log_line(line, self.format), &matches);
+                if selection
+                    .as_ref()
+                    .is_some_and(|range| range.contains(&index))
+                {
+                    line = line.style(t.marked_style);
+                }
+                let line_rows = line_height(&line, content_area.width, self.wrap);
+                rows += line_rows;
+                if gutter > 0 {
+                    numbers.push(Line::styled(
+                        format!(
+                            "{:>width$}",
+                            first_number + number,
+                            width = usize::from(gutter - 1)
+                        ),
+                        Style::default().fg(t.text_muted),
+                    ));
+                    numbers.extend(std::iter::repeat_n(Line::default(), line_rows - 1));
+                }
+                content.push(line);
+            }
 
-            // Render the selected log's content
-            paragraph.render(chunks[1], buffer);
+            let mut paragraph = Paragraph::new(content).style(t.default_style);
+            paragraph = if self.wrap {
+                paragraph.wrap(Wrap { trim: false })
+            } else {
+                paragraph.scroll((0, self.horizontal_scroll))
+            };
+            paragraph.render(content_area, buffer);
+            if gutter > 0 {
+                Paragraph::new(numbers)
+                    .style(t.default_style)
+                    .render(gutter_area, buffer);
+            }
 
-            // The log borrow (held by `content`) has ended, so the scroll state
-            // can now be updated before rendering the scrollbar.
-            self.vertical_scroll_state = self.vertical_scroll_state.position(scroll_pos);
+            self.vertical_scroll_state = self
+                .vertical_scroll_state
+                .content_length(self.current_line_count())
+                .position(scroll_pos);
 
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some("↑"))
@@ -103,3 +623,118 @@ impl Widget for &mut LogModel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    use super::*;
+    use crate::airflow::model::common::{Log, TaskLog};
+
+    const COLORS: LineFormat = LineFormat {
+        show_extra: false,
+        colors: true,
+        show_timestamps: true,
+    };
+    const PLAIN: LineFormat = LineFormat {
+        show_extra: false,
+        colors: false,
+        show_timestamps: true,
+    };
+
+    fn text(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    fn rendered(model: &mut LogModel) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(&mut *model, frame.area()))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    fn task_log(content: &str) -> TaskLog {
+        let mut log = TaskLog::new(100);
+        log.apply(
+            None,
+            Log {
+                continuation_token: None,
+                lines: LogLine::parse_text(content),
+                unterminated: false,
+            },
+        );
+        log
+    }
+
+    #[test]
+    fn renders_with_and_without_line_numbers() {
+        let mut model = LogModel::default();
+        model.update_logs(vec![task_log("first line\nsecond line")]);
+        assert!(!model.line_numbers);
+        let screen = rendered(&mut model);
+        assert!(screen.contains("first line"));
+        assert!(!screen.contains("1 first line"));
+
+        model.line_numbers = true;
+        let screen = rendered(&mut model);
+        assert!(screen.contains("1 first line"));
+        assert!(screen.contains("2 second line"));
+    }
+
+    #[test]
+    fn ansi_sequences_become_styles_or_are_stripped() {
+        let line = LogLine {
+            event: "\x1b[31mred\x1b[0m done".to_string(),
+            continuation: true,
+            ..LogLine::default()
+        };
+        let colored = log_line(&line, COLORS);
+        assert_eq!(text(&colored), "red done");
+        assert_eq!(colored.spans[0].style.fg, Some(ratatui::style::Color::Red));
+
+        let plain = log_line(&line, PLAIN);
+        assert_eq!(text(&plain), "red done");
+        assert!(plain
+            .spans
+            .iter()
+            .all(|span| span.style == Style::default()));
+    }
+
+    #[test]
+    fn highlights_tracebacks_and_level_prefixes() {
+        let t = theme();
+        assert_eq!(
+            traceback_style("Traceback (most recent call last):"),
+            Some(t.log_error_style)
+        );
+        assert_eq!(
+            traceback_style("  File \"/dags/etl.py\", line 12, in run"),
+            Some(Style::default().fg(t.text_muted))
+        );
+        assert_eq!(
+            traceback_style("airflow.exceptions.AirflowException: boom"),
+            Some(t.log_error_style)
+        );
+        assert_eq!(traceback_style("    raise ValueError(\"bad\")"), None);
+        assert_eq!(traceback_style("Error handling is fine: no"), None);
+
+        let line = continuation_line("WARNING:root:careful", true);
+        assert_eq!(line.spans[0].content, "WARNING");
+        assert_eq!(line.spans[0].style, t.log_warning_style);
+        assert_eq!(
+            continuation_line("WARNING:root:careful", false).spans.len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Search within the displayed log lines.
+
+use std::ops::Range;
+
+use regex::{Regex, RegexBuilder};
+
+/// One occurrence of the search pattern: a byte range within a displayed line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Index of the line among the displayed (level-filtered) lines
+    pub line: usize,
+    pub range: Range<usize>,
+}
+
+/// Search state of the log viewer.
+///
+/// Literal queries and regexes are both smart-case: case-insensitive unless
+/// the query contains an uppercase letter.
+#[derive(Debug, Default)]
+pub struct LogSearch {
+    pub query: String,
+    /// Interpret the query as a regular expression instead of literal text
+    pub regex: bool,
+    /// Whether the query is being typed
+    pub editing: bool,
+    /// All occurrences, ordered by line and position
+    matches: Vec<SearchMatch>,
+    /// Index into `matches` of the match the view is on
+    current: Option<usize>,
+    /// Why the query is not a valid regex
+    pub error: Option<String>,
+}
+
+impl LogSearch {
+    /// Whether a query is set, either being typed or confirmed
+    pub fn is_active(&self) -> bool {
+        self.editing || !self.query.is_empty()
+    }
+
+    /// Start typing a new query
+    pub fn start(&mut self) {
+        self.query.clear();
+        self.editing = true;
+        self.matches.clear();
+        self.current = None;
+        self.error = None;
+    }
+
+    /// Drop the query and its matches
+    pub fn clear(&mut self) {
+        *self = Self {
+            regex: self.regex,
+            ..Self::default()
+        };
+    }
+
+    fn pattern(&self) -> Result<Option<Regex>, regex::Error> {
+        if self.query.is_empty() {
+            return Ok(None);
+        }
+        let pattern = if self.regex {
+            self.query.clone()
+        } else {
+            regex::escape(&self.query)
+        };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.query.chars().any(char::is_uppercase))
+            .build()
+            .map(Some)
+    }
+
+    /// Find all occurrences of the query in `lines`. The current match is
+    /// kept on the same line when possible.
+    pub fn update_matches<S: AsRef<str>>(&mut self, lines: &[S]) {
+        let current_line = self.current_match().map(|m| m.line);
+        self.matches.clear();
+        self.current = None;
+        self.error = None;
+        let pattern = match self.pattern() {
+            Ok(Some(pattern)) => pattern,
+            Ok(None) => return,
+            Err(e) => {
+                self.error = Some(e.to_string());
+                return;
+            }
+        };
+        for (line, text) in lines.iter().enumerate() {
+            self.matches.extend(
+                pattern
+                    .find_iter(text.as_ref())
+                    .filter(|m| !m.is_empty())
+                    .map(|m| SearchMatch {
+                        line,
+                        range: m.range(),
+                    }),
+            );
+        }
+        if let Some(line) = current_line {
+            self.select_from(line);
+        }
+    }
+
+    /// Update the matches after `dropped` lines were removed from the start
+    /// of the searched lines and `appended` added after the `kept` remaining
+    /// ones. Only the appended lines are searched.
+    pub fn extend_matches<S: AsRef<str>>(&mut self, dropped: usize, kept: usize, appended: &[S]) {
+        let Ok(Some(pattern)) = self.pattern() else {
+            return;
+        };
+        let removed = self.matches.partition_point(|m| m.line < dropped);
+        self.matches.drain(..removed);
+        for m in &mut self.matches {
+            m.line -= dropped;
+        }
+        self.current = self.current.and_then(|i| i.checked_sub(removed));
+        for (offset, text) in appended.iter().enumerate() {
+            self.matches.extend(
+                pattern
+                    .find_iter(text.as_ref())
+                    .filter(|m| !m.is_empty())
+                    .map(|m| SearchMatch {
+                        line: kept + offset,
+                        range: m.range(),
+                    }),
+            );
+        }
+    }
+
+    /// Number of occurrences
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// One-based position of the current match, for display
+    pub fn current_position(&self) -> Option<usize> {
+        self.current.map(|i| i + 1)
+    }
+
+    pub fn current_match(&self) -> Option<&SearchMatch> {
+        self.current.and_then(|i| self.matches.get(i))
+    }
+
+    /// Occurrences on `line`, each with whether it is the current match
+    pub fn matches_on(&self, line: usize) -> impl Iterator<Item = (Range<usize>, bool)> + '_ {
+        let start = self.matches.partition_point(|m| m.line < line);
+        self.matches[start..]
+            .iter()
+            .take_while(move |m| m.line == line)
+            .enumerate()
+            .map(move |(offset, m)| (m.range.clone(), self.current == Some(start + offset)))
+    }
+
+    /// Select the first match on or after `line`, wrapping to the first match.
+    /// Returns the line of the selected match.
+    pub fn select_from(&mut self, line: usize) -> Option<usize> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        let index = self.matches.partition_point(|m| m.line < line);
+        self.current = Some(if index < self.matches.len() { index } else { 0 });
+        self.current_match().map(|m| m.line)
+    }
+
+    /// Move to the next match, wrapping around. Without a current match,
+    /// starts from `line`. Returns the line of the selected match.
+    pub fn next(&mut self, line: usize) -> Option<usize> {
+        match self.current {
+            Some(i) if !self.matches.is_empty() => {
+                self.current = Some((i + 1) % self.matches.len());
+                self.current_match().map(|m| m.line)
+            }
+            _ => self.select_from(line),
+        }
+    }
+
+    /// Move to the previous match, wrapping around. Without a current match,
+    /// starts from the last match before `line`.
+    pub fn previous(&mut self, line: usize) -> Option<usize> {
+        if self.matches.is_empty() {
+            return None;
+        }
+        let count = self.matches.len();
+        let index = match self.current {
+            Some(i) => (i + count - 1) % count,
+            None => self
+                .matches
+                .partition_point(|m| m.line < line)
+                .checked_sub(1)
+                .unwrap_or(count - 1),
+        };
+        self.current = Some(index);
+        self.current_match().map(|m| m.line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 4] = [
+        "INFO - starting",
+        "ERROR - Task failed with error",
+        "Traceback (most recent call last):",
+        "ValueError: bad value",
+    ];
+
+    fn search(query: &str, regex: bool) -> LogSearch {
+        let mut search = LogSearch {
+            query: query.to_string(),
+            regex,
+            ..LogSearch::default()
+        };
+        search.update_matches(&LINES);
+        search
+    }
+
+    #[test]
+    fn literal_search_is_smart_case() {
+        assert_eq!(search("error", false).match_count(), 3);
+        assert_eq!(search("ERROR", false).match_count(), 1);
+        // Regex metacharacters are literal unless regex mode is on
+        assert_eq!(search("(most", false).match_count(), 1);
+    }
+
+    #[test]
+    fn regex_search_and_invalid_patterns() {
+        let found = search(r"\w+Error", true);
+        assert_eq!(found.match_count(), 1);
+        assert_eq!(
+            found.matches[0],
+            SearchMatch {
+                line: 3,
+                range: 0..10
+            }
+        );
+
+        let invalid = search("(most", true);
+        assert_eq!(invalid.match_count(), 0);
+        assert!(invalid.error.is_some());
+    }
+
+    #[test]
+    fn next_and_previous_wrap_around() {
+        let mut found = search("error", false);
+        assert_eq!(found.next(2), Some(3));
+        assert_eq!(found.current_position(), Some(3));
+        assert_eq!(found.next(0), Some(1));
+        assert_eq!(found.current_position(), Some(1));
+        assert_eq!(found.previous(0), Some(3));
+
+        let on_line_1: Vec<_> = found.matches_on(1).collect();
+        assert_eq!(on_line_1, [(0..5, false), (25..30, false)]);
+    }
+
+    #[test]
+    fn keeps_the_current_line_when_lines_change() {
+        let mut found = search("error", false);
+        found.select_from(3);
+        let mut grown = LINES.to_vec();
+        grown.push("another error");
+        found.update_matches(&grown);
+        assert_eq!(found.match_count(), 4);
+        assert_eq!(found.current_match().map(|m| m.line), Some(3));
+    }
+
+    #[test]
+    fn extends_matches_with_appended_lines() {
+        let mut found = search("error", false);
+        found.select_from(3);
+        assert_eq!(found.current_match().map(|m| m.line), Some(3));
+
+        // The first two lines scroll out and one new line comes in
+        found.extend_matches(2, 2, &["error again"]);
+        let lines: Vec<usize> = (0..4)
+            .flat_map(|l| found.matches_on(l).map(move |_| l))
+            .collect();
+        assert_eq!(lines, [1, 2]);
+        assert_eq!(found.current_match().map(|m| m.line), Some(1));
+    }
+}
@@ -1,9 +1,13 @@
 mod render;
 
+use crate::app::actions;
+use crate::app::keymap::keymap;
+use crate::app::state::Panel;
+
 #[derive(Debug)]
 pub struct Command<'a> {
     pub name: &'a str,
-    pub key_binding: &'a str,
+    pub key_binding: String,
     pub description: &'a str,
 }
 
@@ -13,63 +17,20 @@ pub struct CommandPopUp<'a> {
     pub commands: Vec<Command<'a>>,
 }
 
-#[derive(Debug)]
-pub struct DefaultCommands(pub Vec<Command<'static>>);
-
-impl Default for DefaultCommands {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl DefaultCommands {
-    pub fn new() -> Self {
-        Self(vec![
-            Command {
-                name: "Enter",
-                key_binding: "Enter",
-                description: "Open the selected item",
-            },
-            Command {
-                name: "Filter",
-                key_binding: "/",
-                description: "Filter items",
-            },
-            Command {
-                name: "Open",
-                key_binding: "o",
-                description: "Open the selected item in the browser",
-            },
-            Command {
-                name: "Previous",
-                key_binding: "k / Up",
-                description: "Move to the previous item",
-            },
-            Command {
-                name: "Next",
-                key_binding: "j / Down",
-                description: "Move to the next item",
-            },
-            Command {
-                name: "Previous tab",
-                key_binding: "h / Left / Esc",
-                description: "Move to the previous tab",
-            },
-            Command {
-                name: "Next tab",
-                key_binding: "l / Right",
-                description: "Move to the next tab",
-            },
-            Command {
-                name: "Help",
-                key_binding: "?",
-                description: "Show help",
-            },
-            Command {
-                name: "Quit",
-                key_binding: "q / Ctrl-c",
-                description: "Quit",
-            },
-        ])
+impl CommandPopUp<'static> {
+    /// Help for `panel`, listing the actions registered for it with the keys
+    /// currently bound to them
+    pub fn for_panel(title: &str, panel: &Panel) -> Self {
+        let commands = actions::for_panel(panel)
+            .map(|action| Command {
+                name: action.name,
+                key_binding: keymap().hint(action.id),
+                description: action.description,
+            })
+            .collect();
+        Self {
+            title: title.into(),
+            commands,
+        }
     }
 }
@@ -1,4 +1,5 @@
 use anyhow::Error;
+use flowrs_airflow::AirflowApiError;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -19,10 +20,7 @@ pub struct ErrorPopup {
 impl ErrorPopup {
     pub fn new(errors: &[Error]) -> Self {
         Self {
-            errors: errors
-                .iter()
-                .map(std::string::ToString::to_string)
-                .collect(),
+            errors: errors.iter().map(error_message).collect(),
         }
     }
 
@@ -31,6 +29,74 @@ impl ErrorPopup {
     }
 }
 
+/// The message to show for `error`: Airflow API failures are explained in
+/// plain words, with the server's detail on the following lines.
+pub fn error_message(error: &Error) -> String {
+    let Some(api_error) = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<AirflowApiError>())
+    else {
+        return error.to_string();
+    };
+    let with_detail = |message: &str, detail: &str| {
+        if detail.is_empty() {
+            message.to_string()
+        } else {
+            format!("{message}\n{detail}")
+        }
+    };
+    match api_error {
+        AirflowApiError::Unauthorized { detail, .. } => with_detail(
+            "Authentication failed: the server rejected the configured credentials. Check them with `flowrs config test`.",
+            detail,
+        ),
+        AirflowApiError::Forbidden { detail, .. } => with_detail(
+            "Permission denied: your Airflow user is not allowed to do this.",
+            detail,
+        ),
+        AirflowApiError::NotFound { url, detail } => with_detail(
+            &format!(
+                "Not found: {} does not exist (anymore). Refresh to reload the list.",
+                url.path()
+            ),
+            detail,
+        ),
+        AirflowApiError::Conflict { detail, .. } => with_detail(
+            "Conflict: the request clashes with the current state in Airflow.",
+            detail,
+        ),
+        AirflowApiError::RateLimited { retry_after, .. } => match retry_after {
+            Some(delay) => format!(
+                "Airflow is rate limiting requests; try again in {}s.",
+                delay.as_secs().max(1)
+            ),
+            None => "Airflow is rate limiting requests; try again shortly.".to_string(),
+        },
+        AirflowApiError::Server { status, detail, .. } => with_detail(
+            &format!(
+                "Airflow server error ({status}): the webserver or a proxy in front of it is unavailable. Try again later."
+            ),
+            detail,
+        ),
+        AirflowApiError::Status { status, detail, .. } => {
+            with_detail(&format!("Airflow rejected the request ({status})."), detail)
+        }
+        AirflowApiError::Decode {
+            message, snippet, ..
+        } => format!(
+            "Airflow returned a response flowrs does not understand: {message}\n{snippet}"
+        ),
+        AirflowApiError::Request(e) if e.is_connect() => format!(
+            "Could not connect to {}. Is the server running and the endpoint correct?",
+            e.url().map_or("the server", |url| url.as_str())
+        ),
+        AirflowApiError::Request(e) if e.is_timeout() => {
+            "The request timed out; the server may be overloaded. Try again later.".to_string()
+        }
+        AirflowApiError::Request(_) => error.to_string(),
+    }
+}
+
 impl Widget for &ErrorPopup {
     fn render(self, area: Rect, buf: &mut Buffer) {
         if self.errors.is_empty() {
@@ -74,3 +140,53 @@ impl Widget for &ErrorPopup {
         error_paragraph.render(popup_area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::{StatusCode, Url};
+
+    use super::*;
+
+    fn url() -> Url {
+        Url::parse("http://localhost/api/v2/dags/example").unwrap()
+    }
+
+    #[test]
+    fn explains_api_errors() {
+        let not_found = Error::from(AirflowApiError::NotFound {
+            url: url(),
+            detail: "DAG with dag_id: 'example' not found".to_string(),
+        });
+        assert_eq!(
+            error_message(&not_found),
+            "Not found: /api/v2/dags/example does not exist (anymore). Refresh to reload the list.\nDAG with dag_id: 'example' not found"
+        );
+
+        let bad_gateway = Error::from(AirflowApiError::Server {
+            url: url(),
+            status: StatusCode::BAD_GATEWAY,
+            detail: String::new(),
+        })
+        .context("Failed to fetch DAGs");
+        let message = error_message(&bad_gateway);
+        assert!(
+            message.starts_with("Airflow server error (502 Bad Gateway)"),
+            "{message}"
+        );
+        assert!(!message.contains('\n'));
+
+        let rate_limited = Error::from(AirflowApiError::RateLimited {
+            url: url(),
+            retry_after: Some(Duration::from_secs(20)),
+        });
+        assert!(error_message(&rate_limited).contains("try again in 20s"));
+    }
+
+    #[test]
+    fn keeps_other_errors_unchanged() {
+        let error = anyhow::anyhow!("No active server configured");
+        assert_eq!(error_message(&error), "No active server configured");
+    }
+}
@@ -0,0 +1,429 @@
+use std::collections::HashSet;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+
+use super::popup_area;
+use crate::airflow::model::common::{DagId, DagRunId, OpenItem, TaskId};
+use crate::app::model::fuzzy;
+use crate::app::state::environment_state::EnvironmentData;
+use crate::app::state::Panel;
+use crate::app::worker::WorkerMessage;
+use crate::ui::common::titled_popup_block;
+use crate::ui::theme::theme;
+
+/// Most matches listed at once; typing narrows them down further
+const MAX_MATCHES: usize = 200;
+
+/// A DAG, DAG run or task instance to jump to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JumpTarget {
+    Dag {
+        dag_id: DagId,
+    },
+    DagRun {
+        dag_id: DagId,
+        dag_run_id: DagRunId,
+    },
+    Task {
+        dag_id: DagId,
+        dag_run_id: DagRunId,
+        task_id: TaskId,
+        /// `None` opens the latest try, once the task instances are loaded
+        task_try: Option<u32>,
+    },
+}
+
+impl JumpTarget {
+    /// The target for a web UI item. A task instance opens its latest try.
+    pub fn from_open_item(item: OpenItem) -> Option<Self> {
+        match item {
+            OpenItem::Config(_) => None,
+            OpenItem::Dag { dag_id } => Some(JumpTarget::Dag { dag_id }),
+            OpenItem::DagRun { dag_id, dag_run_id } => {
+                Some(JumpTarget::DagRun { dag_id, dag_run_id })
+            }
+            OpenItem::TaskInstance {
+                dag_id,
+                dag_run_id,
+                task_id,
+            } => Some(JumpTarget::Task {
+                dag_id,
+                dag_run_id,
+                task_id,
+                task_try: None,
+            }),
+            OpenItem::Log {
+                dag_id,
+                dag_run_id,
+                task_id,
+                task_try,
+            } => Some(JumpTarget::Task {
+                dag_id,
+                dag_run_id,
+                task_id,
+                task_try: Some(task_try),
+            }),
+        }
+    }
+
+    /// The id the query is matched against
+    fn id(&self) -> &str {
+        match self {
+            JumpTarget::Dag { dag_id } => dag_id,
+            JumpTarget::DagRun { dag_run_id, .. } => dag_run_id,
+            JumpTarget::Task { task_id, .. } => task_id,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            JumpTarget::Dag { .. } => "DAG",
+            JumpTarget::DagRun { .. } => "run",
+            JumpTarget::Task { .. } => "task",
+        }
+    }
+
+    /// Where the target lives, shown next to its id
+    fn parent(&self) -> String {
+        match self {
+            JumpTarget::Dag { .. } => String::new(),
+            JumpTarget::DagRun { dag_id, .. } => dag_id.to_string(),
+            JumpTarget::Task {
+                dag_id, dag_run_id, ..
+            } => format!("{dag_id} › {dag_run_id}"),
+        }
+    }
+
+    /// The panel showing what is below the target, like pressing Enter on it
+    pub fn panel(&self) -> Panel {
+        match self {
+            JumpTarget::Dag { .. } => Panel::DAGRun,
+            // A task without its try waits for the task instances to tell it
+            JumpTarget::DagRun { .. } | JumpTarget::Task { task_try: None, .. } => {
+                Panel::TaskInstance
+            }
+            JumpTarget::Task { .. } => Panel::Logs,
+        }
+    }
+
+    /// The fetches for the target and the panels above it. Their order moves
+    /// the navigation context down to the target, one level per message, after
+    /// the task graph of another DAG is dropped.
+    pub fn messages(&self) -> Vec<WorkerMessage> {
+        match self.clone() {
+            JumpTarget::Dag { dag_id } => vec![WorkerMessage::UpdateDagRuns { dag_id }],
+            JumpTarget::DagRun { dag_id, dag_run_id } => vec![
+                WorkerMessage::UpdateTasks {
+                    dag_id: dag_id.clone(),
+                },
+                WorkerMessage::UpdateDagRuns {
+                    dag_id: dag_id.clone(),
+                },
+                WorkerMessage::UpdateTaskInstances { dag_id, dag_run_id },
+            ],
+            JumpTarget::Task {
+                dag_id,
+                dag_run_id,
+                task_id,
+                task_try,
+            } => {
+                let mut messages = vec![
+                    WorkerMessage::UpdateTasks {
+                        dag_id: dag_id.clone(),
+                    },
+                    WorkerMessage::UpdateDagRuns {
+                        dag_id: dag_id.clone(),
+                    },
+                    WorkerMessage::UpdateTaskInstances {
+                        dag_id: dag_id.clone(),
+                        dag_run_id: dag_run_id.clone(),
+                    },
+                ];
+                // Without its try, the logs are fetched once the task
+                // instances tell the latest one
+                if let Some(task_try) = task_try {
+                    messages.push(WorkerMessage::UpdateTaskLogs {
+                        dag_id,
+                        dag_run_id,
+                        task_id,
+                        task_try,
+                    });
+                }
+                messages
+            }
+        }
+    }
+}
+
+/// All targets cached for an environment: its DAGs, then the loaded runs,
+/// then the loaded task instances
+pub fn jump_targets(env: &EnvironmentData) -> Vec<JumpTarget> {
+    let mut targets: Vec<JumpTarget> = env
+        .dags
+        .iter()
+        .map(|dag| JumpTarget::Dag {
+            dag_id: dag.dag_id.clone(),
+        })
+        .collect();
+
+    let mut dag_ids: Vec<&DagId> = env.dag_runs.keys().collect();
+    dag_ids.sort();
+    for dag_id in dag_ids {
+        targets.extend(env.dag_runs[dag_id].iter().map(|run| JumpTarget::DagRun {
+            dag_id: dag_id.clone(),
+            dag_run_id: run.dag_run_id.clone(),
+        }));
+    }
+
+    let mut runs: Vec<&(DagId, DagRunId)> = env.task_instances.keys().collect();
+    runs.sort();
+    for key @ (dag_id, dag_run_id) in runs {
+        // Mapped tasks have an instance per map index but share their logs
+        let mut seen = HashSet::new();
+        for instance in &env.task_instances[key] {
+            if seen.insert(&instance.task_id) {
+                targets.push(JumpTarget::Task {
+                    dag_id: dag_id.clone(),
+                    dag_run_id: dag_run_id.clone(),
+                    task_id: instance.task_id.clone(),
+                    task_try: Some(instance.try_number),
+                });
+            }
+        }
+    }
+    targets
+}
+
+/// What the jump prompt wants done after a key press
+#[derive(Debug, PartialEq, Eq)]
+pub enum JumpResult {
+    Open,
+    Closed,
+    Jump(JumpTarget),
+}
+
+/// Fuzzy search over the DAG, run and task ids cached for the active environment
+#[derive(Debug)]
+pub struct JumpPrompt {
+    targets: Vec<JumpTarget>,
+    query: String,
+    matches: Vec<usize>,
+    state: ListState,
+    /// The DAG list is server-side filtered, so other DAGs are not offered
+    partial: bool,
+    /// Where the query cursor was last drawn
+    pub cursor_position: Position,
+}
+
+impl JumpPrompt {
+    pub fn new(targets: Vec<JumpTarget>, partial: bool) -> Self {
+        let mut prompt = Self {
+            targets,
+            query: String::new(),
+            matches: Vec::new(),
+            state: ListState::default(),
+            partial,
+            cursor_position: Position::default(),
+        };
+        prompt.refresh();
+        prompt
+    }
+
+    fn refresh(&mut self) {
+        let query = &self.query;
+        let targets = &self.targets;
+        self.matches = fuzzy::rank(0..targets.len(), |&i| fuzzy::score(query, targets[i].id()));
+        self.matches.truncate(MAX_MATCHES);
+        self.state.select((!self.matches.is_empty()).then_some(0));
+    }
+
+    fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            let next = self
+                .state
+                .selected()
+                .map_or(0, |i| (i + 1) % self.matches.len());
+            self.state.select(Some(next));
+        }
+    }
+
+    fn select_previous(&mut self) {
+        if !self.matches.is_empty() {
+            let previous = self
+                .state
+                .selected()
+                .map_or(0, |i| i.checked_sub(1).unwrap_or(self.matches.len() - 1));
+            self.state.select(Some(previous));
+        }
+    }
+
+    pub fn update(&mut self, key: &KeyEvent) -> JumpResult {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return JumpResult::Closed,
+            KeyCode::Enter => {
+                return self
+                    .state
+                    .selected()
+                    .and_then(|i| self.matches.get(i))
+                    .map_or(JumpResult::Closed, |&i| {
+                        JumpResult::Jump(self.targets[i].clone())
+                    });
+            }
+            KeyCode::Down | KeyCode::Tab => self.select_next(),
+            KeyCode::Up | KeyCode::BackTab => self.select_previous(),
+            KeyCode::Char('n') if ctrl => self.select_next(),
+            KeyCode::Char('p') if ctrl => self.select_previous(),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.refresh();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.query.push(c);
+                self.refresh();
+            }
+            _ => {}
+        }
+        JumpResult::Open
+    }
+}
+
+impl Widget for &mut JumpPrompt {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let t = theme();
+        let popup_area = popup_area(area, 70, 60);
+        let title = if self.partial {
+            "Jump to (filtered DAGs)"
+        } else {
+            "Jump to"
+        };
+        let block = titled_popup_block(title, t.purple)
+            .title_bottom(" <Enter> jump · <Esc> close · <Up/Down> select ");
+        let inner = block.inner(popup_area);
+
+        Clear.render(popup_area, buf);
+        block.render(popup_area, buf);
+
+        let [input_area, list_area] =
+            Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).areas(inner);
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                "> ",
+                Style::default().fg(t.purple).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(self.query.as_str(), Style::default().fg(t.text_primary)),
+        ]))
+        .render(input_area, buf);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "the query is typed by hand and stays far shorter than u16::MAX"
+        )]
+        let query_width = self.query.chars().count() as u16;
+        self.cursor_position = Position::new(
+            (input_area.x + 2 + query_width).min(input_area.right().saturating_sub(1)),
+            input_area.y,
+        );
+
+        if self.matches.is_empty() {
+            let message = if self.targets.is_empty() {
+                "Nothing loaded yet for this environment"
+            } else {
+                "No matching DAGs, runs or tasks"
+            };
+            Paragraph::new(Span::styled(message, Style::default().fg(t.text_muted)))
+                .render(list_area, buf);
+            return;
+        }
+
+        let items = self.matches.iter().map(|&i| {
+            let target = &self.targets[i];
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(" {:<4}  ", target.kind()),
+                    Style::default().fg(t.accent),
+                ),
+                Span::styled(
+                    format!("{}  ", target.id()),
+                    Style::default()
+                        .fg(t.text_primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(target.parent(), Style::default().fg(t.text_muted)),
+            ]))
+        });
+        let list = List::new(items).highlight_style(t.selected_row_style);
+        StatefulWidget::render(list, list_area, buf, &mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets() -> Vec<JumpTarget> {
+        vec![
+            JumpTarget::Dag {
+                dag_id: "etl_daily".into(),
+            },
+            JumpTarget::Dag {
+                dag_id: "reporting".into(),
+            },
+            JumpTarget::DagRun {
+                dag_id: "etl_daily".into(),
+                dag_run_id: "scheduled__2024-01-01".into(),
+            },
+            JumpTarget::Task {
+                dag_id: "etl_daily".into(),
+                dag_run_id: "scheduled__2024-01-01".into(),
+                task_id: "extract_orders".into(),
+                task_try: Some(2),
+            },
+        ]
+    }
+
+    #[test]
+    fn jumps_to_the_best_match() {
+        let mut prompt = JumpPrompt::new(targets(), false);
+        assert_eq!(prompt.matches.len(), 4);
+
+        for c in "extr".chars() {
+            prompt.update(&KeyEvent::from(KeyCode::Char(c)));
+        }
+        let JumpResult::Jump(target) = prompt.update(&KeyEvent::from(KeyCode::Enter)) else {
+            panic!("expected a jump");
+        };
+        assert_eq!(target.panel(), Panel::Logs);
+        assert!(matches!(
+            target.messages().last(),
+            Some(WorkerMessage::UpdateTaskLogs { task_try: 2, .. })
+        ));
+
+        prompt.update(&KeyEvent::from(KeyCode::Char('z')));
+        assert_eq!(
+            prompt.update(&KeyEvent::from(KeyCode::Enter)),
+            JumpResult::Closed
+        );
+    }
+
+    #[test]
+    fn a_task_without_its_try_waits_for_the_task_instances() {
+        let target = JumpTarget::from_open_item(OpenItem::TaskInstance {
+            dag_id: "etl_daily".into(),
+            dag_run_id: "scheduled__2024-01-01".into(),
+            task_id: "extract_orders".into(),
+        })
+        .unwrap();
+        assert_eq!(target.panel(), Panel::TaskInstance);
+        assert!(matches!(
+            target.messages().last(),
+            Some(WorkerMessage::UpdateTaskInstances { .. })
+        ));
+    }
+}
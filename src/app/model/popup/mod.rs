@@ -1,8 +1,10 @@
 pub mod commands_help;
 pub mod error;
+pub mod jump;
+pub mod palette;
 pub mod warning;
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, MouseEvent};
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Flex, Layout, Rect},
@@ -10,6 +12,7 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph, Widget},
 };
 
+use crate::app::events::custom::{is_click, is_click_in, FlowrsEvent};
 use crate::ui::theme::theme;
 
 use self::{commands_help::CommandPopUp, error::ErrorPopup};
@@ -61,6 +64,19 @@ impl<T> Popup<T> {
         }
     }
 
+    /// A click anywhere dismisses error and command popups
+    pub fn handle_click(&mut self, mouse: MouseEvent) -> KeyResult {
+        match self {
+            Popup::Error(_) | Popup::Commands(_) => {
+                if is_click(mouse) {
+                    *self = Popup::None;
+                }
+                KeyResult::Consumed
+            }
+            Popup::None | Popup::Custom(_) => KeyResult::Ignored,
+        }
+    }
+
     /// Show error popup
     pub fn show_error(&mut self, errors: Vec<String>) {
         *self = Popup::Error(ErrorPopup::from_strings(errors));
@@ -138,6 +154,34 @@ impl SelectedButton {
     }
 }
 
+/// Where the buttons of a popup were last rendered, so mouse clicks can be
+/// mapped to the option each button stands for.
+#[derive(Debug, Clone)]
+pub struct ButtonAreas<T>(Vec<(T, Rect)>);
+
+impl<T> Default for ButtonAreas<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: Clone> ButtonAreas<T> {
+    pub fn new(buttons: Vec<(T, Rect)>) -> Self {
+        Self(buttons)
+    }
+
+    /// The option of the button a mouse event clicked, if any
+    pub fn clicked(&self, event: &FlowrsEvent) -> Option<T> {
+        let FlowrsEvent::Mouse(mouse) = event else {
+            return None;
+        };
+        self.0
+            .iter()
+            .find(|(_, area)| is_click_in(*mouse, *area))
+            .map(|(option, _)| option.clone())
+    }
+}
+
 /// Create a themed button with consistent styling across popups.
 pub fn themed_button(text: &str, selected: bool) -> Paragraph<'_> {
     let t = theme();
@@ -158,9 +202,15 @@ pub fn themed_button(text: &str, selected: bool) -> Paragraph<'_> {
 
 /// Render the standard centered Yes / No button pair into `area`.
 /// `active` dims the highlight when the buttons aren't the focused zone.
-pub fn render_yes_no(area: Rect, buffer: &mut Buffer, yes_selected: bool, active: bool) {
+/// Returns where the buttons were drawn.
+pub fn render_yes_no(
+    area: Rect,
+    buffer: &mut Buffer,
+    yes_selected: bool,
+    active: bool,
+) -> ButtonAreas<SelectedButton> {
     if area.height == 0 {
-        return;
+        return ButtonAreas::default();
     }
     let [_, yes, _, no, _] = Layout::horizontal([
         Constraint::Fill(1),
@@ -173,6 +223,7 @@ pub fn render_yes_no(area: Rect, buffer: &mut Buffer, yes_selected: bool, active
     if area.height >= 3 {
         themed_button("Yes", active && yes_selected).render(yes, buffer);
         themed_button("No", active && !yes_selected).render(no, buffer);
+        ButtonAreas::new(vec![(SelectedButton::Yes, yes), (SelectedButton::No, no)])
     } else {
         // Too short for bordered buttons — the border would swallow the label
         // and leave two blank boxes. Fall back to flat highlighted labels.
@@ -184,13 +235,15 @@ pub fn render_yes_no(area: Rect, buffer: &mut Buffer, yes_selected: bool, active
                 t.button_default
             }
         };
+        let (yes, no) = (Rect { height: 1, ..yes }, Rect { height: 1, ..no });
         Paragraph::new("Yes")
             .style(style(active && yes_selected))
             .centered()
-            .render(Rect { height: 1, ..yes }, buffer);
+            .render(yes, buffer);
         Paragraph::new("No")
             .style(style(active && !yes_selected))
             .centered()
-            .render(Rect { height: 1, ..no }, buffer);
+            .render(no, buffer);
+        ButtonAreas::new(vec![(SelectedButton::Yes, yes), (SelectedButton::No, no)])
     }
 }
@@ -0,0 +1,268 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Clear, List, ListItem, ListState, Paragraph, StatefulWidget, Widget},
+};
+
+use super::popup_area;
+use crate::app::actions::{self, Action, ActionId};
+use crate::app::keymap::keymap;
+use crate::app::model::fuzzy;
+use crate::app::state::{NavigationContext, Panel};
+use crate::ui::common::titled_popup_block;
+use crate::ui::theme::theme;
+
+/// Matches on the name rank above matches on the description only
+const NAME_BONUS: i64 = 64;
+
+/// What the palette wants done after a key press
+#[derive(Debug, PartialEq, Eq)]
+pub enum PaletteResult {
+    Open,
+    Closed,
+    Run(&'static Action),
+}
+
+/// Fuzzy search over the actions available on the active panel
+#[derive(Debug)]
+pub struct CommandPalette {
+    actions: Vec<&'static Action>,
+    query: String,
+    matches: Vec<&'static Action>,
+    state: ListState,
+    /// Where the query cursor was last drawn
+    pub cursor_position: Position,
+}
+
+impl CommandPalette {
+    pub fn new(panel: &Panel, ctx: &NavigationContext) -> Self {
+        let actions = actions::available(panel, ctx)
+            .filter(|action| action.id != ActionId::Palette)
+            .collect();
+        let mut palette = Self {
+            actions,
+            query: String::new(),
+            matches: Vec::new(),
+            state: ListState::default(),
+            cursor_position: Position::default(),
+        };
+        palette.refresh();
+        palette
+    }
+
+    fn refresh(&mut self) {
+        let query = &self.query;
+        self.matches = fuzzy::rank(self.actions.iter().copied(), |action| {
+            fuzzy::score(query, action.name)
+                .map(|score| score + NAME_BONUS)
+                .or_else(|| fuzzy::score(query, action.description))
+        });
+        self.state.select((!self.matches.is_empty()).then_some(0));
+    }
+
+    fn select_next(&mut self) {
+        if !self.matches.is_empty() {
+            let next = self
+                .state
+                .selected()
+                .map_or(0, |i| (i + 1) % self.matches.len());
+            self.state.select(Some(next));
+        }
+    }
+
+    fn select_previous(&mut self) {
+        if !self.matches.is_empty() {
+            let previous = self
+                .state
+                .selected()
+                .map_or(0, |i| i.checked_sub(1).unwrap_or(self.matches.len() - 1));
+            self.state.select(Some(previous));
+        }
+    }
+
+    pub fn update(&mut self, key: &KeyEvent) -> PaletteResult {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return PaletteResult::Closed,
+            KeyCode::Enter => {
+                return self
+                    .state
+                    .selected()
+                    .and_then(|i| self.matches.get(i))
+                    .map_or(PaletteResult::Closed, |action| PaletteResult::Run(action));
+            }
+            KeyCode::Down | KeyCode::Tab => self.select_next(),
+            KeyCode::Up | KeyCode::BackTab => self.select_previous(),
+            KeyCode::Char('n') if ctrl => self.select_next(),
+            KeyCode::Char('p') if ctrl => self.select_previous(),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.refresh();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.query.push(c);
+                self.refresh();
+            }
+            _ => {}
+        }
+        PaletteResult::Open
+    }
+}
+
+impl Widget for &mut CommandPalette {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let t = theme();
+        let popup_area = popup_area(area, 60, 60);
+        let block = titled_popup_block("Commands", t.purple)
+            .title_bottom(" <Enter> run · <Esc> close · <Up/Down> select ");
+        let inner = block.inner(popup_area);
+
+        Clear.render(popup_area, buf);
+        block.render(popup_area, buf);
+
+        let [input_area, list_area] =
+            Layout::vertical([Constraint::Length(2), Constraint::Min(0)]).areas(inner);
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                "> ",
+                Style::default().fg(t.purple).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(self.query.as_str(), Style::default().fg(t.text_primary)),
+        ]))
+        .render(input_area, buf);
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "the query is typed by hand and stays far shorter than u16::MAX"
+        )]
+        let query_width = self.query.chars().count() as u16;
+        self.cursor_position = Position::new(
+            (input_area.x + 2 + query_width).min(input_area.right().saturating_sub(1)),
+            input_area.y,
+        );
+
+        if self.matches.is_empty() {
+            Paragraph::new(Span::styled(
+                "No matching commands",
+                Style::default().fg(t.text_muted),
+            ))
+            .render(list_area, buf);
+            return;
+        }
+
+        let name_width = self.matches.iter().map(|a| a.name.len()).max().unwrap_or(0);
+        let hints: Vec<String> = self
+            .matches
+            .iter()
+            .map(|action| keymap().hint(action.id))
+            .collect();
+        let hint_width = hints.iter().map(|h| h.chars().count()).max().unwrap_or(0);
+        let items = self.matches.iter().zip(&hints).map(|(action, hint)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(" {:<name_width$}  ", action.name),
+                    Style::default()
+                        .fg(t.text_primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{hint:<hint_width$}  "),
+                    Style::default().fg(t.accent),
+                ),
+                Span::styled(action.description, Style::default().fg(t.text_muted)),
+            ]))
+        });
+        let list = List::new(items).highlight_style(t.selected_row_style);
+        StatefulWidget::render(list, list_area, buf, &mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flowrs_config::FlowrsConfig;
+
+    use super::*;
+    use crate::airflow::model::common::Dag;
+    use crate::app::events::custom::FlowrsEvent;
+    use crate::app::state::App;
+
+    fn type_query(palette: &mut CommandPalette, query: &str) {
+        for c in query.chars() {
+            palette.update(&KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn filters_and_runs_the_selected_action() {
+        let ctx = NavigationContext::Dag {
+            environment: "prod".into(),
+            dag_id: "etl".into(),
+        };
+        let mut palette = CommandPalette::new(&Panel::DAGRun, &ctx);
+        assert!(palette
+            .matches
+            .iter()
+            .all(|action| action.id != ActionId::Palette));
+
+        type_query(&mut palette, "trig");
+        assert_eq!(palette.matches[0].id, ActionId::Trigger);
+        match palette.update(&KeyEvent::from(KeyCode::Enter)) {
+            PaletteResult::Run(action) => assert_eq!(action.id, ActionId::Trigger),
+            other => panic!("expected an action to run, got {other:?}"),
+        }
+
+        type_query(&mut palette, "zzz");
+        assert!(palette.matches.is_empty());
+        assert_eq!(
+            palette.update(&KeyEvent::from(KeyCode::Enter)),
+            PaletteResult::Closed
+        );
+    }
+
+    #[test]
+    fn selection_wraps_around() {
+        let mut palette = CommandPalette::new(&Panel::Config, &NavigationContext::None);
+        let last = palette.matches.len() - 1;
+        palette.update(&KeyEvent::from(KeyCode::Up));
+        assert_eq!(palette.state.selected(), Some(last));
+        palette.update(&KeyEvent::from(KeyCode::Down));
+        assert_eq!(palette.state.selected(), Some(0));
+        assert_eq!(
+            palette.update(&KeyEvent::from(KeyCode::Esc)),
+            PaletteResult::Closed
+        );
+    }
+
+    #[test]
+    fn running_top_moves_to_the_first_row() {
+        let mut app = App::new(FlowrsConfig::parse_toml("").unwrap());
+        app.active_panel = Panel::Dag;
+        app.dags.table.set_items(
+            ["a", "b", "c"]
+                .into_iter()
+                .map(|id| Dag {
+                    dag_id: id.into(),
+                    ..Dag::default()
+                })
+                .collect(),
+        );
+        app.dags.table.filtered.state.select(Some(2));
+
+        let mut palette = CommandPalette::new(&Panel::Dag, &NavigationContext::None);
+        type_query(&mut palette, "first");
+        let PaletteResult::Run(action) = palette.update(&KeyEvent::from(KeyCode::Enter)) else {
+            panic!("expected an action to run");
+        };
+        assert_eq!(action.id, ActionId::Top);
+        let event = app.palette_event(action.id);
+        app.update_active_panel(&event);
+        assert_eq!(app.dags.table.filtered.state.selected(), Some(0));
+
+        // Nothing is left half-typed: a single `g` does not jump again
+        app.dags.table.filtered.state.select(Some(2));
+        app.update_active_panel(&FlowrsEvent::Key(keymap().key(ActionId::Top)));
+        assert_eq!(app.dags.table.filtered.state.selected(), Some(2));
+    }
+}
@@ -3,16 +3,19 @@ pub mod popup;
 mod render;
 
 use commands::TASK_COMMAND_POP_UP;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent};
 use log::debug;
 
 use crate::airflow::graph::{sort_task_instances, TaskGraph};
 use crate::airflow::model::common::{
     DagId, DagRunId, GanttData, TaskId, TaskInstance, TaskInstanceState,
 };
+use crate::app::actions::ActionId;
 use crate::app::events::custom::FlowrsEvent;
+use crate::app::keymap::keymap;
+use crate::app::state::Panel;
 
-use super::{FilterableTable, KeyResult, Model, Popup};
+use super::{FilterableTable, KeyResult, Model, Popup, TableMouse};
 use crate::airflow::model::common::OpenItem;
 use crate::app::worker::WorkerMessage;
 use popup::clear::ClearTaskInstancePopup;
@@ -126,11 +129,11 @@ impl TaskInstanceModel {
     /// Handle model-specific keys
     fn handle_keys(
         &mut self,
-        key_code: KeyCode,
+        key: &KeyEvent,
         ctx: &crate::app::state::NavigationContext,
     ) -> KeyResult {
-        match key_code {
-            KeyCode::Char('m') => {
+        match keymap().action(&Panel::TaskInstance, key) {
+            Some(ActionId::Mark) => {
                 let task_ids = self.selected_task_ids();
                 if !task_ids.is_empty() {
                     if let (Some(dag_id), Some(dag_run_id)) = (ctx.dag_id(), ctx.dag_run_id()) {
@@ -141,7 +144,7 @@ impl TaskInstanceModel {
                 }
                 KeyResult::Consumed
             }
-            KeyCode::Char('c') => {
+            Some(ActionId::Clear) => {
                 let task_ids = self.selected_task_ids();
                 if let (Some(dag_id), Some(dag_run_id)) = (ctx.dag_id(), ctx.dag_run_id()) {
                     if !task_ids.is_empty() {
@@ -152,11 +155,11 @@ impl TaskInstanceModel {
                 }
                 KeyResult::Consumed
             }
-            KeyCode::Char('?') => {
+            Some(ActionId::Help) => {
                 self.popup.show_commands(&TASK_COMMAND_POP_UP);
                 KeyResult::Consumed
             }
-            KeyCode::Enter => {
+            Some(ActionId::Enter) => {
                 if let Some(task_instance) = self.table.current() {
                     KeyResult::PassWith(vec![WorkerMessage::UpdateTaskLogs {
                         dag_id: task_instance.dag_id.clone(),
@@ -168,7 +171,7 @@ impl TaskInstanceModel {
                     KeyResult::Consumed
                 }
             }
-            KeyCode::Char('d') => {
+            Some(ActionId::Graph) => {
                 if let Some(graph) = &self.task_graph {
                     if !graph.is_empty() {
                         let popup = DagGraphPopup::new(graph, &self.table.all);
@@ -177,7 +180,7 @@ impl TaskInstanceModel {
                 }
                 KeyResult::Consumed
             }
-            KeyCode::Char('o') => {
+            Some(ActionId::Open) => {
                 if let Some(task_instance) = self.table.current() {
                     KeyResult::PassWith(vec![WorkerMessage::OpenItem(OpenItem::TaskInstance {
                         dag_id: task_instance.dag_id.clone(),
@@ -226,18 +229,32 @@ impl Model for TaskInstanceModel {
                     .table
                     .handle_filter_key(key_event)
                     .or_else(|| self.popup.handle_dismiss(key_event.code))
-                    .or_else(|| self.table.handle_visual_mode_key(key_event.code))
+                    .or_else(|| self.table.handle_visual_mode_key(key_event))
                     .or_else(|| {
                         self.table
-                            .handle_navigation(key_event.code, &mut self.event_buffer)
+                            .handle_navigation(key_event, &mut self.event_buffer)
                     })
-                    .or_else(|| self.handle_keys(key_event.code, ctx));
+                    .or_else(|| self.handle_keys(key_event, ctx));
 
                 result.into_result(event)
             }
-            FlowrsEvent::Mouse | FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => {
-                (Some(event.clone()), vec![])
+            FlowrsEvent::Mouse(mouse) => {
+                if let Some(messages) = self.handle_popup(event, ctx) {
+                    return (None, messages);
+                }
+                if let KeyResult::Consumed = self.popup.handle_click(*mouse) {
+                    return (None, vec![]);
+                }
+
+                match self.table.handle_mouse(*mouse) {
+                    TableMouse::Activated => {
+                        self.update(&FlowrsEvent::Key(keymap().key(ActionId::Enter)), ctx)
+                    }
+                    TableMouse::Moved => (None, vec![]),
+                    TableMouse::Ignored => (Some(event.clone()), vec![]),
+                }
             }
+            FlowrsEvent::FocusGained | FlowrsEvent::FocusLost => (Some(event.clone()), vec![]),
         }
     }
 }
@@ -2,7 +2,10 @@ use crossterm::event::KeyCode;
 
 use crate::app::{
     events::custom::FlowrsEvent,
-    model::{popup::SelectedButton, Model},
+    model::{
+        popup::{ButtonAreas, SelectedButton},
+        Model,
+    },
     worker::WorkerMessage,
 };
 
@@ -14,6 +17,7 @@ pub struct ClearTaskInstancePopup {
     pub dag_id: DagId,
     pub task_ids: Vec<TaskId>,
     pub(crate) selected_button: SelectedButton,
+    pub(crate) buttons: ButtonAreas<SelectedButton>,
 }
 
 impl ClearTaskInstancePopup {
@@ -23,6 +27,7 @@ impl ClearTaskInstancePopup {
             dag_id: dag_id.clone(),
             task_ids,
             selected_button: SelectedButton::default(),
+            buttons: ButtonAreas::default(),
         }
     }
 }
@@ -33,6 +38,15 @@ impl Model for ClearTaskInstancePopup {
         event: &FlowrsEvent,
         _ctx: &crate::app::state::NavigationContext,
     ) -> (Option<FlowrsEvent>, Vec<WorkerMessage>) {
+        // Clicking a button selects and confirms it
+        let click;
+        let event = if let Some(button) = self.buttons.clicked(event) {
+            self.selected_button = button;
+            click = FlowrsEvent::key(KeyCode::Enter);
+            &click
+        } else {
+            event
+        };
         if let FlowrsEvent::Key(key_event) = event {
             match key_event.code {
                 KeyCode::Enter => {
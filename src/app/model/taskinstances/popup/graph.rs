@@ -6,7 +6,7 @@ use ratatui::style::{Color, Style};
 use crate::airflow::graph::TaskGraph;
 use crate::airflow::model::common::taskinstance::TaskInstanceState;
 use crate::airflow::model::common::TaskInstance;
-use crate::app::events::custom::FlowrsEvent;
+use crate::app::events::custom::{scroll_key, FlowrsEvent};
 use crate::app::worker::WorkerMessage;
 use crate::ui::constants::AirflowStateColor;
 
@@ -164,17 +164,22 @@ impl DagGraphPopup {
         self.content_height.saturating_sub(self.viewport.1)
     }
 
-    /// Handle keyboard events (scrolling and dismiss).
+    /// Handle keyboard and scroll wheel events (scrolling and dismiss).
     /// Returns a key event on Esc/q to signal the parent to close the popup.
     pub fn update(
         &mut self,
         event: &FlowrsEvent,
         _ctx: &crate::app::state::NavigationContext,
     ) -> (Option<FlowrsEvent>, Vec<WorkerMessage>) {
-        if let FlowrsEvent::Key(key) = event {
-            match key.code {
+        let code = match event {
+            FlowrsEvent::Key(key) => Some(key.code),
+            FlowrsEvent::Mouse(mouse) => scroll_key(*mouse),
+            _ => None,
+        };
+        if let Some(code) = code {
+            match code {
                 KeyCode::Esc | KeyCode::Char('q') => {
-                    return (Some(FlowrsEvent::Key(*key)), vec![]);
+                    return (Some(event.clone()), vec![]);
                 }
                 KeyCode::Left | KeyCode::Char('h') => {
                     self.scroll_x = self.scroll_x.saturating_sub(SCROLL_STEP);
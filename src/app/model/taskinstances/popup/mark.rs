@@ -2,7 +2,11 @@ use crossterm::event::KeyCode;
 use strum::Display;
 
 use crate::airflow::model::common::{DagId, DagRunId, TaskId, TaskInstanceState};
-use crate::app::{events::custom::FlowrsEvent, model::Model, worker::WorkerMessage};
+use crate::app::{
+    events::custom::FlowrsEvent,
+    model::{popup::ButtonAreas, Model},
+    worker::WorkerMessage,
+};
 
 #[derive(Debug)]
 pub struct MarkTaskInstancePopup {
@@ -10,6 +14,7 @@ pub struct MarkTaskInstancePopup {
     pub dag_run_id: DagRunId,
     pub status: MarkState,
     pub marked: Vec<TaskId>,
+    pub(crate) buttons: ButtonAreas<MarkState>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Display)]
@@ -39,6 +44,7 @@ impl MarkTaskInstancePopup {
             status: MarkState::Success,
             marked,
             dag_run_id: dag_run_id.clone(),
+            buttons: ButtonAreas::default(),
         }
     }
 
@@ -65,6 +71,15 @@ impl Model for MarkTaskInstancePopup {
         event: &FlowrsEvent,
         _ctx: &crate::app::state::NavigationContext,
     ) -> (Option<FlowrsEvent>, Vec<WorkerMessage>) {
+        // Clicking a button selects and confirms it
+        let click;
+        let event = if let Some(status) = self.buttons.clicked(event) {
+            self.status = status;
+            click = FlowrsEvent::key(KeyCode::Enter);
+            &click
+        } else {
+            event
+        };
         if let FlowrsEvent::Key(key_event) = event {
             match key_event.code {
                 KeyCode::Enter => {
@@ -7,7 +7,7 @@ use ratatui::{
 use std::cmp::min;
 
 use crate::{
-    app::model::popup::{popup_area, themed_button},
+    app::model::popup::{popup_area, render_yes_no, ButtonAreas},
     ui::theme::theme,
 };
 
@@ -46,23 +46,10 @@ impl Widget for &mut ClearTaskInstancePopup {
         };
         let text = Paragraph::new(message).style(t.default_style).centered();
 
-        let [_, yes, _, no, _] = Layout::horizontal([
-            Constraint::Fill(1),
-            Constraint::Length(8),
-            Constraint::Length(2),
-            Constraint::Length(8),
-            Constraint::Fill(1),
-        ])
-        .areas(options);
-
-        let yes_btn = themed_button("Yes", self.selected_button.is_yes());
-        let no_btn = themed_button("No", !self.selected_button.is_yes());
-
         Clear.render(area, buffer);
         popup_block.render(area, buffer);
         text.render(header, buffer);
-        yes_btn.render(yes, buffer);
-        no_btn.render(no, buffer);
+        self.buttons = render_yes_no(options, buffer, self.selected_button.is_yes(), true);
     }
 }
 
@@ -156,6 +143,11 @@ impl Widget for &mut MarkTaskInstancePopup {
         success_btn.render(success, buffer);
         failed_btn.render(failed, buffer);
         skipped_btn.render(skipped, buffer);
+        self.buttons = ButtonAreas::new(vec![
+            (MarkState::Success, success),
+            (MarkState::Failed, failed),
+            (MarkState::Skipped, skipped),
+        ]);
     }
 }
 
@@ -6,7 +6,7 @@ use ratatui::widgets::{
 };
 
 use crate::airflow::model::common::{calculate_duration, format_duration};
-use crate::ui::common::{create_headers, state_to_colored_square};
+use crate::ui::common::{create_headers, help_title, state_to_colored_square};
 use crate::ui::constants::AirflowStateColor;
 use crate::ui::gantt::{create_gantt_bar, gantt_legend_line};
 use crate::ui::theme::theme;
@@ -42,6 +42,20 @@ impl Widget for &mut TaskInstanceModel {
         let table_inner_width = content_area.width.saturating_sub(2); // Subtract borders
         let gantt_width = (table_inner_width / 2).max(10);
 
+        let block = {
+            let block = Block::default()
+                .border_type(BorderType::Rounded)
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
+                .border_style(t.border_style)
+                .title(help_title());
+            if let Some(title) = self.table.status_title() {
+                block.title_bottom(title)
+            } else {
+                block
+            }
+        };
+        let table_area = block.inner(content_area);
+
         let rows = self
             .table
             .filtered
@@ -75,21 +89,11 @@ impl Widget for &mut TaskInstanceModel {
             ],
         )
         .header(header)
-        .block({
-            let block = Block::default()
-                .border_type(BorderType::Rounded)
-                .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
-                .border_style(t.border_style)
-                .title(" Press <?> to see available commands ");
-            if let Some(title) = self.table.status_title() {
-                block.title_bottom(title)
-            } else {
-                block
-            }
-        })
+        .block(block)
         .row_highlight_style(t.selected_row_style);
 
         StatefulWidget::render(t, content_area, buffer, &mut self.table.filtered.state);
+        self.table.set_rendered_area(table_area);
 
         legend.render(legend_area, buffer);
 
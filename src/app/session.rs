@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::app::model::filter::FilterCondition;
+use crate::app::state::{NavigationContext, Panel};
+
+/// Where flowrs keeps its state: the saved session and the debug logs. macOS
+/// and Windows have no state directory, so the local data directory is used.
+pub fn state_dir() -> Option<PathBuf> {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .map(|p| p.join("flowrs"))
+}
+
+const SESSION_FILE: &str = "session.json";
+
+/// What flowrs saves on exit to pick up where the user left off
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    /// Where each environment was last left
+    #[serde(default)]
+    pub environments: BTreeMap<String, EnvironmentSession>,
+    /// Stored filter conditions of each table
+    #[serde(default)]
+    pub filters: BTreeMap<Panel, Vec<FilterCondition>>,
+}
+
+/// Where the user was in one environment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentSession {
+    pub context: NavigationContext,
+    pub panel: Panel,
+    /// Id of the selected row of each table
+    #[serde(default)]
+    pub selected: BTreeMap<Panel, String>,
+}
+
+impl Session {
+    /// Read the session saved by the previous run. A missing or unreadable
+    /// file gives an empty session; losing it only costs the user their place.
+    pub fn load() -> Self {
+        let Some(path) = state_dir().map(|dir| dir.join(SESSION_FILE)) else {
+            return Self::default();
+        };
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        serde_json::from_str(&contents).unwrap_or_else(|e| {
+            log::warn!("Ignoring unreadable session file {}: {e}", path.display());
+            Self::default()
+        })
+    }
+
+    /// Write the session atomically, via a temp file and a rename, so that
+    /// instances quitting at the same time never leave a truncated file.
+    pub fn save(&self) -> Result<()> {
+        let dir = state_dir().context("no directory to save the session in")?;
+        std::fs::create_dir_all(&dir)?;
+        let mut file = tempfile::NamedTempFile::new_in(&dir)?;
+        file.write_all(serde_json::to_string_pretty(self)?.as_bytes())?;
+        file.as_file().sync_all()?;
+        file.persist(dir.join(SESSION_FILE))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_json() {
+        let session = Session {
+            environments: BTreeMap::from([(
+                "prod".to_string(),
+                EnvironmentSession {
+                    context: NavigationContext::Task {
+                        environment: "prod".into(),
+                        dag_id: "etl".into(),
+                        dag_run_id: "manual__2024-01-01".into(),
+                        task_id: "load".into(),
+                        task_try: 2,
+                    },
+                    panel: Panel::TaskInstance,
+                    selected: BTreeMap::from([(Panel::Dag, "etl".to_string())]),
+                },
+            )]),
+            filters: BTreeMap::from([(
+                Panel::DAGRun,
+                vec![FilterCondition::new("state", "failed", false)],
+            )]),
+        };
+        let json = serde_json::to_string(&session).unwrap();
+        assert_eq!(serde_json::from_str::<Session>(&json).unwrap(), session);
+        assert_eq!(
+            serde_json::from_str::<Session>("{}").unwrap(),
+            Session::default()
+        );
+    }
+}
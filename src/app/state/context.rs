@@ -1,6 +1,7 @@
+use crate::app::model::popup::jump::JumpTarget;
 use crate::app::worker::WorkerMessage;
 
-use super::{App, NavigationContext};
+use super::{App, NavigationContext, Panel};
 
 impl App {
     /// Update the centralized navigation context from a `WorkerMessage`.
@@ -52,4 +53,60 @@ impl App {
             _ => {}
         }
     }
+
+    /// Move the navigation context straight to `target` and show the panel
+    /// below it. Returns the fetches for the new context, which the caller
+    /// sends to the worker.
+    pub fn jump_to(&mut self, target: &JumpTarget) -> Vec<WorkerMessage> {
+        if self.nav_context.environment().is_none() {
+            return vec![];
+        }
+        let messages = target.messages();
+        for message in &messages {
+            self.set_context_from_message(message);
+        }
+        self.latest_try_of = match target {
+            JumpTarget::Task {
+                dag_id,
+                dag_run_id,
+                task_id,
+                task_try: None,
+            } => Some((dag_id.clone(), dag_run_id.clone(), task_id.clone())),
+            _ => None,
+        };
+        let panel = target.panel();
+        self.active_panel = panel.clone();
+        self.sync_panel(&panel);
+        messages
+    }
+
+    /// Open the latest try of the task jumped to without its try, once the
+    /// task instances of its run are loaded. Returns the fetch of its logs,
+    /// unless the user has moved on or the task is not in the run.
+    pub fn open_latest_try(&mut self) -> Option<WorkerMessage> {
+        let (dag_id, dag_run_id, task_id) = self.latest_try_of.take()?;
+        if self.nav_context.dag_id() != Some(&dag_id)
+            || self.nav_context.dag_run_id() != Some(&dag_run_id)
+            || self.active_panel != Panel::TaskInstance
+        {
+            return None;
+        }
+        let task_try = self
+            .environment_state
+            .get_active_task_instances(&dag_id, &dag_run_id)
+            .iter()
+            .filter(|instance| instance.task_id == task_id)
+            .map(|instance| instance.try_number)
+            .max()?;
+        let message = WorkerMessage::UpdateTaskLogs {
+            dag_id,
+            dag_run_id,
+            task_id,
+            task_try,
+        };
+        self.set_context_from_message(&message);
+        self.active_panel = Panel::Logs;
+        self.sync_panel(&Panel::Logs);
+        Some(message)
+    }
 }
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use flowrs_airflow::{DagFilter, DagRunFilter};
+
 use crate::airflow::model::common::{
-    Dag, DagId, DagRun, DagRunId, DagStatistic, EnvironmentKey, Log, TaskId, TaskInstance,
+    Dag, DagId, DagRun, DagRunId, DagStatistic, EnvironmentKey, Log, TaskId, TaskInstance, TaskLog,
 };
 use crate::airflow::traits::AirflowClient as AirflowClientTrait;
 
@@ -19,17 +21,30 @@ pub struct EnvironmentData {
     /// Result of `list_dags()` — sorted alphabetically by `dag_id` on write.
     pub dags: Vec<Dag>,
 
+    /// The server-side filter `dags` was listed with. Unless it is the
+    /// default, the list holds only the matching DAGs.
+    pub dags_filter: DagFilter,
+
     /// Result of `get_dag_stats(dag_ids)` — keyed per DAG.
     pub dag_stats: HashMap<DagId, Vec<DagStatistic>>,
 
-    /// Result of `list_dagruns(dag_id)` — keyed by `dag_id`.
+    /// Pages of `list_dagruns(dag_id, ..)` loaded so far — keyed by `dag_id`.
     pub dag_runs: HashMap<DagId, Vec<DagRun>>,
 
+    /// Number of runs on the server matching the current filter — keyed by
+    /// `dag_id`, so the DAG run table knows whether more pages exist.
+    pub dag_run_totals: HashMap<DagId, usize>,
+
+    /// The server-side filter the cached runs were listed with — keyed by
+    /// `dag_id`. Pages listed with another filter replace the cached runs.
+    pub dag_run_filters: HashMap<DagId, DagRunFilter>,
+
     /// Result of `list_task_instances(dag_id, dag_run_id)` — flat composite key.
     pub task_instances: HashMap<(DagId, DagRunId), Vec<TaskInstance>>,
 
-    /// Result of `get_task_logs(dag_id, dag_run_id, task_id, try)` — flat composite key.
-    pub task_logs: HashMap<(DagId, DagRunId, TaskId), Vec<Log>>,
+    /// Logs of `get_task_logs(dag_id, dag_run_id, task_id, try, token)`, one
+    /// per try and grown chunk by chunk — flat composite key.
+    pub task_logs: HashMap<(DagId, DagRunId, TaskId), Vec<TaskLog>>,
 
     /// Result of `get_dag_params(dag_id)` — cached param schemas for trigger popup.
     /// `Arc` so the per-sync copies into the panel models are pointer bumps,
@@ -42,8 +57,11 @@ impl EnvironmentData {
         Self {
             client,
             dags: Vec::new(),
+            dags_filter: DagFilter::default(),
             dag_stats: HashMap::new(),
             dag_runs: HashMap::new(),
+            dag_run_totals: HashMap::new(),
+            dag_run_filters: HashMap::new(),
             task_instances: HashMap::new(),
             task_logs: HashMap::new(),
             dag_params: HashMap::new(),
@@ -52,10 +70,16 @@ impl EnvironmentData {
 
     // ── Write methods (called by workers after API responses) ────────
 
-    /// Replace the full DAG list (evicts deleted DAGs).
-    pub fn replace_dags(&mut self, mut dags: Vec<Dag>) {
+    /// Replace the full DAG list (evicts deleted DAGs), listed with `filter`.
+    pub fn replace_dags(&mut self, mut dags: Vec<Dag>, filter: &DagFilter) {
         dags.sort_by(|a, b| a.dag_id.cmp(&b.dag_id));
         self.dags = dags;
+        self.dags_filter = filter.clone();
+    }
+
+    /// Whether `dags` misses the DAGs the server-side filter left out.
+    pub fn dags_partial(&self) -> bool {
+        self.dags_filter != DagFilter::default()
     }
 
     /// Replace stats for a single DAG.
@@ -63,9 +87,62 @@ impl EnvironmentData {
         self.dag_stats.insert(dag_id.clone(), stats);
     }
 
-    /// Replace all DAG runs for a DAG (evicts deleted runs).
-    pub fn replace_dag_runs(&mut self, dag_id: &DagId, dag_runs: Vec<DagRun>) {
+    /// Update the runs of a DAG with a fresh first page, newest first.
+    ///
+    /// The older pages loaded with the same filter are kept after the page,
+    /// from the last run it shares with them on, so a refresh costs one
+    /// request however far the user has scrolled. Runs listed with another
+    /// filter, or no longer adjacent to the page, are replaced by it.
+    pub fn refresh_dag_runs(
+        &mut self,
+        dag_id: &DagId,
+        first_page: Vec<DagRun>,
+        page_size: usize,
+        total: usize,
+        filter: &DagRunFilter,
+    ) {
+        let same_filter = self.dag_run_filters.get(dag_id) == Some(filter);
+        let cached = self.dag_runs.remove(dag_id).unwrap_or_default();
+        let older = match first_page.last() {
+            Some(last) if same_filter && first_page.len() == page_size => cached
+                .iter()
+                .position(|run| run.dag_run_id == last.dag_run_id)
+                .map_or_else(Vec::new, |i| {
+                    cached[i + 1..]
+                        .iter()
+                        .filter(|run| !first_page.iter().any(|r| r.dag_run_id == run.dag_run_id))
+                        .cloned()
+                        .collect()
+                }),
+            _ => Vec::new(),
+        };
+        let mut dag_runs = first_page;
+        dag_runs.extend(older);
         self.dag_runs.insert(dag_id.clone(), dag_runs);
+        self.dag_run_totals.insert(dag_id.clone(), total);
+        self.dag_run_filters.insert(dag_id.clone(), filter.clone());
+    }
+
+    /// Append the next page of DAG runs. Runs already loaded (a refresh may
+    /// have shifted the pages) are skipped, and so is a page listed with
+    /// another filter than the loaded runs.
+    pub fn append_dag_runs(
+        &mut self,
+        dag_id: &DagId,
+        dag_runs: Vec<DagRun>,
+        total: usize,
+        filter: &DagRunFilter,
+    ) {
+        if self.dag_run_filters.get(dag_id) != Some(filter) {
+            return;
+        }
+        let loaded = self.dag_runs.entry(dag_id.clone()).or_default();
+        for dag_run in dag_runs {
+            if !loaded.iter().any(|r| r.dag_run_id == dag_run.dag_run_id) {
+                loaded.push(dag_run);
+            }
+        }
+        self.dag_run_totals.insert(dag_id.clone(), total);
     }
 
     /// Replace all task instances for a DAG run (evicts deleted instances).
@@ -84,16 +161,48 @@ impl EnvironmentData {
         self.dag_params.insert(dag_id.clone(), Arc::new(params));
     }
 
-    /// Replace logs for a specific task instance.
-    pub fn replace_task_logs(
+    /// Continuation tokens of the cached logs of a task instance, one per try
+    /// up to `task_try`. `None` for tries that must be fetched in full.
+    pub fn task_log_tokens(
+        &self,
+        dag_id: &DagId,
+        dag_run_id: &DagRunId,
+        task_id: &TaskId,
+        task_try: u32,
+    ) -> Vec<Option<String>> {
+        let logs = self
+            .task_logs
+            .get(&(dag_id.clone(), dag_run_id.clone(), task_id.clone()));
+        (0..task_try as usize)
+            .map(|i| {
+                logs.and_then(|logs| logs.get(i))
+                    .and_then(|log| log.continuation_token.clone())
+            })
+            .collect()
+    }
+
+    /// Merge fetched log chunks into the cached logs of a task instance.
+    /// Each chunk is the try number, the token it was fetched with, and the
+    /// response; see [`TaskLog::apply`].
+    pub fn merge_task_logs(
         &mut self,
         dag_id: &DagId,
         dag_run_id: &DagRunId,
         task_id: &TaskId,
-        logs: Vec<Log>,
+        chunks: Vec<(u32, Option<String>, Log)>,
+        max_lines: usize,
     ) {
-        self.task_logs
-            .insert((dag_id.clone(), dag_run_id.clone(), task_id.clone()), logs);
+        let logs = self
+            .task_logs
+            .entry((dag_id.clone(), dag_run_id.clone(), task_id.clone()))
+            .or_default();
+        for (task_try, since, chunk) in chunks {
+            let index = task_try.saturating_sub(1) as usize;
+            if logs.len() <= index {
+                logs.resize_with(index + 1, || TaskLog::new(max_lines));
+            }
+            logs[index].apply(since.as_deref(), chunk);
+        }
     }
 }
 
@@ -148,6 +257,14 @@ impl EnvironmentStateContainer {
             .unwrap_or_default()
     }
 
+    /// Get the number of DAG runs on the server for a DAG in the active environment.
+    pub fn get_active_dag_run_total(&self, dag_id: &DagId) -> usize {
+        self.get_active_environment()
+            .and_then(|env| env.dag_run_totals.get(dag_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Get all task instances for a specific DAG run in the active environment.
     pub fn get_active_task_instances(
         &self,
@@ -169,7 +286,7 @@ impl EnvironmentStateContainer {
         dag_id: &DagId,
         dag_run_id: &DagRunId,
         task_id: &TaskId,
-    ) -> Vec<Log> {
+    ) -> Vec<TaskLog> {
         self.get_active_environment()
             .and_then(|env| {
                 env.task_logs
@@ -185,3 +302,85 @@ impl Default for EnvironmentStateContainer {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use flowrs_config::FlowrsConfig;
+
+    use super::*;
+    use crate::airflow::client::FlowrsClient;
+
+    fn environment() -> EnvironmentData {
+        let config = FlowrsConfig::parse_toml(
+            r#"
+[[servers]]
+name = "prod"
+endpoint = "http://localhost:8080"
+version = "V3"
+
+[servers.auth.Token]
+token = "tok"
+"#,
+        )
+        .unwrap();
+        EnvironmentData::new(Arc::new(FlowrsClient::new(&config.servers[0]).unwrap()))
+    }
+
+    fn runs(ids: &[&str]) -> Vec<DagRun> {
+        ids.iter()
+            .map(|id| DagRun {
+                dag_run_id: (*id).into(),
+                ..DagRun::default()
+            })
+            .collect()
+    }
+
+    fn loaded(env: &EnvironmentData) -> Vec<String> {
+        env.dag_runs[&DagId::from("etl")]
+            .iter()
+            .map(|run| run.dag_run_id.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn refreshing_keeps_older_pages_loaded_with_the_same_filter() {
+        let dag_id = DagId::from("etl");
+        let filter = DagRunFilter::default();
+        let mut env = environment();
+        env.refresh_dag_runs(&dag_id, runs(&["4", "3"]), 2, 6, &filter);
+        env.append_dag_runs(&dag_id, runs(&["2", "1"]), 6, &filter);
+        assert_eq!(loaded(&env), ["4", "3", "2", "1"]);
+
+        // A new run shifts the first page; the older runs stay loaded
+        env.refresh_dag_runs(&dag_id, runs(&["5", "4"]), 2, 7, &filter);
+        assert_eq!(loaded(&env), ["5", "4", "3", "2", "1"]);
+
+        // A first page that does not reach the loaded runs replaces them
+        env.refresh_dag_runs(&dag_id, runs(&["8", "7"]), 2, 9, &filter);
+        assert_eq!(loaded(&env), ["8", "7"]);
+
+        // So does one listed with another filter, and a stale page for the
+        // old filter is not appended
+        let failed = DagRunFilter {
+            states: vec!["failed".to_string()],
+            ..DagRunFilter::default()
+        };
+        env.refresh_dag_runs(&dag_id, runs(&["7"]), 2, 1, &failed);
+        env.append_dag_runs(&dag_id, runs(&["6"]), 9, &filter);
+        assert_eq!(loaded(&env), ["7"]);
+    }
+
+    #[test]
+    fn records_whether_the_dags_were_filtered_on_the_server() {
+        let mut env = environment();
+        let filter = DagFilter {
+            dag_id_pattern: Some("etl".to_string()),
+            ..DagFilter::default()
+        };
+        env.replace_dags(vec![Dag::default()], &filter);
+        assert!(env.dags_partial());
+
+        env.replace_dags(vec![Dag::default()], &DagFilter::default());
+        assert!(!env.dags_partial());
+    }
+}
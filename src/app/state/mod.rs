@@ -1,21 +1,31 @@
 mod breadcrumb;
 mod context;
 mod navigation;
+mod session;
 mod sync;
 
 pub mod environment_state;
 
 pub use navigation::NavigationContext;
 
+use crate::airflow::model::common::{DagId, DagRunId, TaskId};
+use crate::app::actions::ActionId;
 use crate::app::events::custom::FlowrsEvent;
+use crate::app::keymap::keymap;
 use crate::app::model::dagruns::DagRunModel;
 use crate::app::model::dags::DagModel;
 use crate::app::model::popup::error::ErrorPopup;
+use crate::app::model::popup::jump::{jump_targets, JumpPrompt};
+use crate::app::model::popup::palette::CommandPalette;
 use crate::app::model::popup::warning::WarningPopup;
 use crate::app::model::Model;
+use crate::app::session::Session;
 use crate::app::worker::WorkerMessage;
+use crate::CONFIG_PATHS;
 use environment_state::EnvironmentStateContainer;
 use flowrs_config::FlowrsConfig;
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
 use throbber_widgets_tui::ThrobberState;
 
 use super::model::{config::ConfigModel, logs::LogModel, taskinstances::TaskInstanceModel};
@@ -38,9 +48,21 @@ pub struct App {
     pub warning_popup: Option<WarningPopup>,
     /// Whether the terminal window has focus (used to pause refreshes when unfocused)
     pub focused: bool,
+    /// Where the tab bar was last drawn, for switching panels with the mouse
+    pub tab_area: Rect,
+    /// Command palette, drawn over the active panel while open
+    pub palette: Option<CommandPalette>,
+    /// Jump prompt, drawn over the active panel while open
+    pub jump: Option<JumpPrompt>,
+    /// Task jumped to without its try, whose latest try is opened once the
+    /// task instances of its run are loaded
+    pub latest_try_of: Option<(DagId, DagRunId, TaskId)>,
+    /// Where each environment was left, saved on exit
+    pub session: Session,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// A tab of the UI. Panels order from the top of the navigation hierarchy down.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Panel {
     Config,
     Dag,
@@ -49,6 +71,17 @@ pub enum Panel {
     Logs,
 }
 
+impl Panel {
+    /// The panels in the order of their tabs
+    pub const ALL: [Panel; 5] = [
+        Panel::Config,
+        Panel::Dag,
+        Panel::DAGRun,
+        Panel::TaskInstance,
+        Panel::Logs,
+    ];
+}
+
 impl App {
     #[allow(dead_code, reason = "constructor used only in tests")]
     pub fn new(config: FlowrsConfig) -> Self {
@@ -98,6 +131,11 @@ impl App {
             throbber_state: ThrobberState::default(),
             warning_popup,
             focused: true,
+            tab_area: Rect::default(),
+            palette: None,
+            jump: None,
+            latest_try_of: None,
+            session: Session::default(),
         }
     }
 
@@ -138,6 +176,65 @@ impl App {
         }
     }
 
+    /// Whether the active panel shows a popup, which takes mouse events
+    /// before the panel and the tab bar behind it
+    pub fn has_open_popup(&self) -> bool {
+        match self.active_panel {
+            Panel::Config => !self.configs.popup.is_none(),
+            Panel::Dag => !self.dags.popup.is_none(),
+            Panel::DAGRun => !self.dagruns.popup.is_none(),
+            Panel::TaskInstance => !self.task_instances.popup.is_none(),
+            Panel::Logs => self.logs.error_popup.is_some(),
+        }
+    }
+
+    /// The key event that runs `id` when it is picked from the command
+    /// palette. The presses before the last one of a key sequence, such as the
+    /// first `g` of `gg`, only arm the action and are given to the active
+    /// panel here.
+    pub fn palette_event(&mut self, id: ActionId) -> FlowrsEvent {
+        let mut presses = keymap().presses(id);
+        let last = presses.pop().unwrap_or_else(|| keymap().key(id));
+        for press in presses {
+            self.update_active_panel(&FlowrsEvent::Key(press));
+        }
+        FlowrsEvent::Key(last)
+    }
+
+    /// Open the command palette with the actions of the active panel
+    pub fn open_palette(&mut self) {
+        self.palette = Some(CommandPalette::new(&self.active_panel, &self.nav_context));
+    }
+
+    /// Start on `server` instead of the active server, for this run only:
+    /// the configured active server is left as it is
+    pub fn start_on_server(&mut self, server: String) {
+        self.nav_context = NavigationContext::Environment {
+            environment: server,
+        };
+        self.active_panel = Panel::Dag;
+    }
+
+    /// Persist the active server selection on top of the config file as it is
+    /// now, which may have changed since startup, e.g. by a rotated refresh
+    /// token.
+    pub fn save_config(&self) -> anyhow::Result<()> {
+        let mut config = FlowrsConfig::from_file(self.config.path.as_ref(), &CONFIG_PATHS)?;
+        config.active_server.clone_from(&self.config.active_server);
+        config.write_to_file(&CONFIG_PATHS)
+    }
+
+    /// Open the jump prompt over the DAGs, runs and tasks cached for the
+    /// active environment
+    pub fn open_jump(&mut self) {
+        let (targets, partial) = self
+            .environment_state
+            .get_active_environment()
+            .map(|env| (jump_targets(env), env.dags_partial()))
+            .unwrap_or_default();
+        self.jump = Some(JumpPrompt::new(targets, partial));
+    }
+
     /// Show an error popup on whichever panel is currently active.
     pub fn show_error(&mut self, errors: Vec<String>) {
         match self.active_panel {
@@ -1,10 +1,12 @@
+use serde::{Deserialize, Serialize};
+
 use crate::airflow::model::common::{DagId, DagRunId, TaskId};
 
 /// Centralized navigation context — the single source of truth for what the
 /// user is currently looking at. Encoded as an enum to enforce the strict
 /// hierarchy: environment > dag > `dag_run` > task. It is impossible to have,
 /// e.g., a `task_id` without a `dag_id`.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NavigationContext {
     #[default]
     None,
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use crate::app::model::filter::{dag_filter, dag_run_filter};
+use crate::app::session::{EnvironmentSession, Session};
+use crate::app::worker::WorkerMessage;
+
+use super::{App, NavigationContext, Panel};
+
+impl App {
+    /// Record the table filters, and where the user is in the active
+    /// environment, in `self.session`.
+    pub fn remember_session(&mut self) {
+        self.session.filters = BTreeMap::from([
+            (
+                Panel::Config,
+                self.configs.table.filter.confirmed_conditions(),
+            ),
+            (Panel::Dag, self.dags.table.filter.confirmed_conditions()),
+            (
+                Panel::DAGRun,
+                self.dagruns.table.filter.confirmed_conditions(),
+            ),
+            (
+                Panel::TaskInstance,
+                self.task_instances.table.filter.confirmed_conditions(),
+            ),
+        ]);
+
+        let Some(environment) = self.nav_context.environment().cloned() else {
+            return;
+        };
+        let selected = [
+            (
+                Panel::Config,
+                self.configs.table.current().map(|c| c.name.clone()),
+            ),
+            (
+                Panel::Dag,
+                self.dags.table.current().map(|d| d.dag_id.to_string()),
+            ),
+            (
+                Panel::DAGRun,
+                self.dagruns
+                    .table
+                    .current()
+                    .map(|r| r.dag_run_id.to_string()),
+            ),
+            (
+                Panel::TaskInstance,
+                self.task_instances
+                    .table
+                    .current()
+                    .map(|ti| ti.task_id.to_string()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(panel, id)| id.map(|id| (panel, id)))
+        .collect();
+        self.session.environments.insert(
+            environment,
+            EnvironmentSession {
+                context: self.nav_context.clone(),
+                panel: self.active_panel.clone(),
+                selected,
+            },
+        );
+    }
+
+    /// Save the session on exit. Failing to save only logs, so that quitting
+    /// always works.
+    pub fn save_session(&mut self) {
+        self.remember_session();
+        if let Err(e) = self.session.save() {
+            log::error!("Failed to save session: {e}");
+        }
+    }
+
+    /// Restore the table filters of a previous session. Returns the message
+    /// that restores where the user left the active environment, once the
+    /// worker has checked what of it still exists.
+    pub fn restore_session(&mut self, session: Session) -> Vec<WorkerMessage> {
+        for (panel, conditions) in &session.filters {
+            let conditions = conditions.clone();
+            match panel {
+                Panel::Config => {
+                    self.configs.table.filter.restore(conditions);
+                    self.configs.table.apply_filter();
+                }
+                Panel::Dag => self.dags.table.filter.restore(conditions),
+                Panel::DAGRun => self.dagruns.table.filter.restore(conditions),
+                Panel::TaskInstance => self.task_instances.table.filter.restore(conditions),
+                Panel::Logs => {}
+            }
+        }
+        if let Some(environment) = self.nav_context.environment() {
+            let filter = dag_filter(
+                &self.dags.table.filter.confirmed_conditions(),
+                &self.dags.table.all,
+            );
+            self.dags.server_filters.insert(environment.clone(), filter);
+        }
+        self.dagruns.server_filter =
+            dag_run_filter(&self.dagruns.table.filter.confirmed_conditions());
+
+        let messages = match self
+            .nav_context
+            .environment()
+            .and_then(|environment| session.environments.get(environment))
+        {
+            Some(saved) => {
+                self.select_rows(&saved.selected);
+                vec![WorkerMessage::RestoreSession(saved.clone())]
+            }
+            None => vec![],
+        };
+        self.session = session;
+        messages
+    }
+
+    /// Whether restoring `environment` is still wanted: the user has neither
+    /// switched environments nor left the DAG list since startup.
+    pub fn awaits_restore(&self, environment: &str) -> bool {
+        self.environment_state.active_environment.as_deref() == Some(environment)
+            && self.nav_context.dag_id().is_none()
+            && self.active_panel == Panel::Dag
+    }
+
+    /// Show `panel`, or the deepest panel the navigation context allows
+    pub fn restore_panel(&mut self, panel: Panel) {
+        let deepest = match self.nav_context {
+            NavigationContext::None | NavigationContext::Environment { .. } => Panel::Dag,
+            NavigationContext::Dag { .. } => Panel::DAGRun,
+            NavigationContext::DagRun { .. } => Panel::TaskInstance,
+            NavigationContext::Task { .. } => Panel::Logs,
+        };
+        self.active_panel = panel.min(deepest);
+        let panel = self.active_panel.clone();
+        self.sync_panel(&panel);
+    }
+
+    /// Select the saved row of each table, if it is still there
+    pub fn select_rows(&mut self, selected: &BTreeMap<Panel, String>) {
+        for (panel, id) in selected {
+            match panel {
+                Panel::Config => self.configs.table.select_where(|c| &c.name == id),
+                Panel::Dag => self.dags.table.select_where(|d| *d.dag_id == **id),
+                Panel::DAGRun => self.dagruns.table.select_where(|r| *r.dag_run_id == **id),
+                Panel::TaskInstance => self
+                    .task_instances
+                    .table
+                    .select_where(|ti| *ti.task_id == **id),
+                Panel::Logs => false,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use flowrs_airflow::DagFilter;
+    use flowrs_config::FlowrsConfig;
+
+    use super::*;
+    use crate::app::model::filter::FilterCondition;
+
+    #[test]
+    fn restores_filters_and_the_active_environment() {
+        let config = FlowrsConfig::parse_toml(r#"active_server = "prod""#).unwrap();
+        let mut app = App::new(config.clone());
+        let context = NavigationContext::DagRun {
+            environment: "prod".into(),
+            dag_id: "etl".into(),
+            dag_run_id: "manual__2024-01-01".into(),
+        };
+        app.nav_context = context.clone();
+        app.active_panel = Panel::TaskInstance;
+        app.dagruns
+            .table
+            .filter
+            .restore(vec![FilterCondition::new("state", "failed", false)]);
+        app.remember_session();
+        let session = app.session.clone();
+        assert_eq!(session.environments["prod"].context, context);
+
+        let mut app = App::new(config);
+        let messages = app.restore_session(session);
+        assert_eq!(
+            app.dagruns.table.filter.confirmed_conditions(),
+            vec![FilterCondition::new("state", "failed", false)]
+        );
+        assert!(matches!(
+            messages.as_slice(),
+            [WorkerMessage::RestoreSession(saved)]
+                if saved.context == context && saved.panel == Panel::TaskInstance
+        ));
+
+        // A restored context never shows a panel deeper than it reaches
+        app.restore_panel(Panel::Logs);
+        assert_eq!(app.active_panel, Panel::Dag);
+    }
+
+    #[test]
+    fn restores_the_dag_filter_for_the_active_environment_only() {
+        let config = FlowrsConfig::parse_toml(r#"active_server = "prod""#).unwrap();
+        let mut app = App::new(config.clone());
+        app.dags
+            .table
+            .filter
+            .restore(vec![FilterCondition::new("dag_id", "etl", false)]);
+        app.remember_session();
+        let session = app.session.clone();
+
+        let mut app = App::new(config);
+        app.restore_session(session);
+        assert_eq!(
+            app.dags.server_filter("prod").dag_id_pattern.as_deref(),
+            Some("etl")
+        );
+        assert_eq!(app.dags.server_filter("dev"), DagFilter::default());
+    }
+}
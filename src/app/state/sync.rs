@@ -20,6 +20,8 @@ impl App {
             Panel::DAGRun => {
                 if let Some(dag_id) = self.nav_context.dag_id() {
                     self.dagruns.table.all = self.environment_state.get_active_dag_runs(dag_id);
+                    let total = self.environment_state.get_active_dag_run_total(dag_id);
+                    self.dagruns.table.set_paging_total(total);
                     let dag_run_ids: Vec<String> = self
                         .dagruns
                         .table
@@ -0,0 +1,187 @@
+//! Requests that need the terminal itself rather than a panel: running a
+//! pager or editor in the foreground, and copying text via OSC 52.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crossterm::event::{
+    DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use ratatui::{prelude::Backend, Terminal};
+use tempfile::NamedTempFile;
+
+use super::events::generator::EventGenerator;
+
+/// A program to show text in, taken from the environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalProgram {
+    /// `$PAGER`, or `less -R` so ANSI colours are shown
+    Pager,
+    /// `$EDITOR`, or `vi`
+    Editor,
+}
+
+impl ExternalProgram {
+    fn command_line(self) -> String {
+        let (var, default) = match self {
+            Self::Pager => ("PAGER", "less -R"),
+            Self::Editor => ("EDITOR", "vi"),
+        };
+        std::env::var(var)
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or_else(|| default.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalRequest {
+    /// Write `content` to a private temporary file named after `file_name`
+    /// and open it
+    Open {
+        program: ExternalProgram,
+        file_name: String,
+        content: String,
+    },
+    /// Copy text to the system clipboard
+    Copy(String),
+}
+
+/// Carry out a terminal request. For external programs the event reader is
+/// paused and the terminal restored to normal mode while the program runs, so
+/// it gets all input; the TUI is set up and redrawn afterwards.
+pub async fn handle_request<B: Backend>(
+    terminal: &mut Terminal<B>,
+    events: &EventGenerator,
+    request: TerminalRequest,
+) -> Result<()>
+where
+    <B as Backend>::Error: 'static + std::marker::Send + std::marker::Sync,
+{
+    match request {
+        TerminalRequest::Copy(text) => copy_to_clipboard(&text),
+        TerminalRequest::Open {
+            program,
+            file_name,
+            content,
+        } => {
+            let file = temp_file(&file_name, &content)?;
+
+            events.suspend().await;
+            let status = leave_tui().and_then(|()| {
+                tokio::task::block_in_place(|| {
+                    let command_line = program.command_line();
+                    let mut parts = command_line.split_whitespace();
+                    let name = parts.next().unwrap_or_default();
+                    Command::new(name)
+                        .args(parts)
+                        .arg(file.path())
+                        .status()
+                        .with_context(|| format!("failed to run {command_line}"))
+                })
+            });
+            // Restore the TUI and the event reader whatever happened above, so
+            // a failure never leaves the app without input
+            let entered = enter_tui();
+            events.resume().await;
+            entered?;
+            terminal.clear()?;
+
+            if let Err(e) = file.close() {
+                log::warn!("Failed to remove temporary file: {e}");
+            }
+            let status = status?;
+            if !status.success() {
+                anyhow::bail!("{} exited with {status}", program.command_line());
+            }
+            Ok(())
+        }
+    }
+}
+
+/// A file with a random name in the temporary directory, keeping the
+/// extension of `file_name` for syntax detection. It is only readable by the
+/// owner and removed when dropped.
+fn temp_file(file_name: &str, content: &str) -> Result<NamedTempFile> {
+    let path = Path::new(file_name);
+    let prefix = format!(
+        "{}-",
+        path.file_stem().unwrap_or_default().to_string_lossy()
+    );
+    let suffix = path
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+    let mut builder = tempfile::Builder::new();
+    builder.prefix(&prefix).suffix(&suffix);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(std::fs::Permissions::from_mode(0o600));
+    }
+    let mut file = builder
+        .tempfile()
+        .context("failed to create a temporary file")?;
+    file.write_all(content.as_bytes())
+        .and_then(|()| file.flush())
+        .with_context(|| format!("failed to write {}", file.path().display()))?;
+    Ok(file)
+}
+
+/// Copy text with the OSC 52 escape sequence, which the terminal forwards to
+/// the system clipboard, also over SSH.
+fn copy_to_clipboard(text: &str) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()?;
+    Ok(())
+}
+
+fn leave_tui() -> Result<()> {
+    let mut stdout = std::io::stdout();
+    stdout.execute(DisableMouseCapture)?;
+    stdout.execute(DisableFocusChange)?;
+    stdout.execute(LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    Ok(())
+}
+
+fn enter_tui() -> Result<()> {
+    let mut stdout = std::io::stdout();
+    enable_raw_mode()?;
+    stdout.execute(EnterAlternateScreen)?;
+    stdout.execute(EnableFocusChange)?;
+    stdout.execute(EnableMouseCapture)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temp_files_are_private_and_removed() {
+        let file = temp_file("etl__run__load__try1.log", "hello").unwrap();
+        let path = file.path().to_path_buf();
+        let name = path.file_name().unwrap().to_string_lossy();
+        assert!(name.starts_with("etl__run__load__try1-"));
+        assert!(name.ends_with(".log"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "hello");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        drop(file);
+        assert!(!path.exists());
+    }
+}
@@ -4,6 +4,7 @@ use anyhow::{Context, Result};
 
 use crate::airflow::model::common::OpenItem;
 use crate::airflow::traits::AirflowClient;
+use crate::app::model::popup::error::error_message;
 use crate::app::state::App;
 
 /// Open an item (DAG, DAG run, task instance, etc.) in the browser.
@@ -13,7 +14,7 @@ use crate::app::state::App;
 /// instead of being silently logged.
 pub fn handle_open_item(app: &Arc<Mutex<App>>, client: &Arc<dyn AirflowClient>, item: OpenItem) {
     if let Err(e) = try_open_item(app, client, item) {
-        app.lock().unwrap().show_error(vec![e.to_string()]);
+        app.lock().unwrap().show_error(vec![error_message(&e)]);
     }
 }
 
@@ -22,21 +23,21 @@ fn try_open_item(
     client: &Arc<dyn AirflowClient>,
     item: OpenItem,
 ) -> Result<()> {
-    // For Config items, look up the endpoint from active_server instead of using the passed string
+    // For Config items, look up the endpoint of the active environment instead of using the passed string
     let final_item = if let OpenItem::Config(_) = &item {
         let app_lock = app.lock().unwrap();
 
         let active_server_name = app_lock
-            .config
-            .active_server
-            .as_ref()
+            .environment_state
+            .active_environment
+            .as_deref()
             .ok_or_else(|| anyhow::anyhow!("No active server configured"))?;
 
         let server = app_lock
             .config
             .servers
             .iter()
-            .find(|s| &s.name == active_server_name)
+            .find(|s| s.name == active_server_name)
             .ok_or_else(|| {
                 anyhow::anyhow!("Active server '{active_server_name}' not found in configuration")
             })?;
@@ -4,47 +4,64 @@ use anyhow::Result;
 
 use crate::airflow::client::FlowrsClient;
 use crate::airflow::model::common::EnvironmentKey;
+use crate::app::model::popup::error::error_message;
 use crate::app::state::environment_state::EnvironmentData;
 use crate::app::state::App;
 
 /// Handle configuration selection.
 /// Creates a new client for the selected configuration if needed and sets it as active.
-pub fn handle_config_selected(app: &Arc<Mutex<App>>, idx: usize) -> Result<()> {
-    let mut app = app
-        .lock()
-        .map_err(|_| anyhow::anyhow!("Failed to acquire app lock"))?;
+pub async fn handle_config_selected(app: &Arc<Mutex<App>>, idx: usize) -> Result<()> {
+    let (selected_config, has_client) = {
+        let mut app = app
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Failed to acquire app lock"))?;
 
-    let Some(selected_config) = app.configs.table.filtered.items.get(idx).cloned() else {
-        log::error!(
-            "Config index {idx} out of bounds (total: {})",
-            app.configs.table.filtered.items.len()
-        );
-        app.configs
-            .popup
-            .show_error(vec![format!("Configuration index {idx} not found")]);
-        app.loading = false;
-        return Ok(());
+        let Some(selected_config) = app.configs.table.filtered.items.get(idx).cloned() else {
+            log::error!(
+                "Config index {idx} out of bounds (total: {})",
+                app.configs.table.filtered.items.len()
+            );
+            app.configs
+                .popup
+                .show_error(vec![format!("Configuration index {idx} not found")]);
+            app.loading = false;
+            return Ok(());
+        };
+        let has_client = app
+            .environment_state
+            .environments
+            .contains_key(selected_config.name.as_str());
+        (selected_config, has_client)
     };
     let env_name = EnvironmentKey::from(selected_config.name.clone());
 
-    // Check if environment already exists, if not create it
-    if !app.environment_state.environments.contains_key(&env_name) {
-        match FlowrsClient::new(&selected_config) {
-            Ok(client) => {
-                let env_data = EnvironmentData::new(std::sync::Arc::new(client));
-                app.environment_state
-                    .environments
-                    .insert(env_name.clone(), env_data);
-            }
-            Err(e) => {
-                log::error!("Failed to create client for '{env_name}': {e}");
-                app.configs
-                    .popup
-                    .show_error(vec![format!("Failed to connect to '{env_name}': {e}")]);
-                app.loading = false;
-                return Ok(());
-            }
+    // Create the environment if it doesn't exist yet (no lock while connecting)
+    let client = if has_client {
+        None
+    } else {
+        Some(FlowrsClient::connect(&selected_config).await)
+    };
+
+    let mut app = app
+        .lock()
+        .map_err(|_| anyhow::anyhow!("Failed to acquire app lock"))?;
+    match client {
+        Some(Ok(client)) => {
+            let env_data = EnvironmentData::new(Arc::new(client));
+            app.environment_state
+                .environments
+                .insert(env_name.clone(), env_data);
+        }
+        Some(Err(e)) => {
+            log::error!("Failed to create client for '{env_name}': {e}");
+            app.configs.popup.show_error(vec![format!(
+                "Failed to connect to '{env_name}': {}",
+                error_message(&e)
+            )]);
+            app.loading = false;
+            return Ok(());
         }
+        None => {}
     }
 
     // Set this as the active environment
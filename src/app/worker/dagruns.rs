@@ -1,14 +1,36 @@
 use std::sync::{Arc, Mutex};
 
+use flowrs_airflow::DagRunFilter;
 use log::debug;
 
 use crate::airflow::model::common::{DagId, DagRunId, DagRunState};
 use crate::airflow::traits::AirflowClient;
 use crate::app::model::dagruns::popup::mark::MarkState;
+use crate::app::model::popup::error::error_message;
 use crate::app::state::App;
 
+/// Number of DAG runs requested per page.
+const DAG_RUN_PAGE_SIZE: usize = 50;
+
+/// The server-side filter to list DAG runs with, and how many runs of the DAG
+/// are loaded in the environment.
+fn loaded_dag_runs(app: &Arc<Mutex<App>>, dag_id: &DagId, env_name: &str) -> (DagRunFilter, usize) {
+    let app = app.lock().unwrap();
+    let loaded = app
+        .environment_state
+        .environments
+        .get(env_name)
+        .and_then(|env| env.dag_runs.get(dag_id))
+        .map_or(0, Vec::len);
+    (app.dagruns.server_filter.clone(), loaded)
+}
+
 /// Handle updating the list of DAG runs for a specific DAG.
 ///
+/// Fetches only the first page and merges it into the loaded runs (see
+/// `EnvironmentData::refresh_dag_runs`), so polling does not page through
+/// everything the user has scrolled into.
+///
 /// `env_name` identifies which environment initiated this request, ensuring
 /// results are written to the correct environment even if the active one changes.
 pub async fn handle_update_dag_runs(
@@ -17,14 +39,18 @@ pub async fn handle_update_dag_runs(
     dag_id: &DagId,
     env_name: &str,
 ) {
-    let dag_runs = client.list_dagruns(dag_id).await;
+    let filter = app.lock().unwrap().dagruns.server_filter.clone();
+    let page = client
+        .list_dagruns(dag_id, &filter, 0, DAG_RUN_PAGE_SIZE)
+        .await;
 
     let mut app = app.lock().unwrap();
-    match dag_runs {
-        Ok(dag_runs) => {
-            // Replace DAG runs in the originating environment, not the active one
+    match page {
+        Ok(page) => {
+            let total = usize::try_from(page.total_entries).unwrap_or_default();
+            // Update DAG runs in the originating environment, not the active one
             if let Some(env) = app.environment_state.environments.get_mut(env_name) {
-                env.replace_dag_runs(dag_id, dag_runs.dag_runs);
+                env.refresh_dag_runs(dag_id, page.dag_runs, DAG_RUN_PAGE_SIZE, total, &filter);
             }
             // Only sync panel data if this environment is still active
             if app.environment_state.active_environment.as_deref() == Some(env_name) {
@@ -32,7 +58,39 @@ pub async fn handle_update_dag_runs(
             }
         }
         Err(e) => {
-            app.dagruns.popup.show_error(vec![e.to_string()]);
+            app.dagruns.popup.show_error(vec![error_message(&e)]);
+        }
+    }
+}
+
+/// Handle loading the next page of DAG runs, requested when the selection
+/// nears the end of the loaded runs.
+pub async fn handle_load_more_dag_runs(
+    app: &Arc<Mutex<App>>,
+    client: &Arc<dyn AirflowClient>,
+    dag_id: &DagId,
+    env_name: &str,
+) {
+    let (filter, loaded) = loaded_dag_runs(app, dag_id, env_name);
+    debug!("Loading DAG runs of {dag_id} from offset {loaded}");
+    let page = client
+        .list_dagruns(dag_id, &filter, loaded, DAG_RUN_PAGE_SIZE)
+        .await;
+
+    let mut app = app.lock().unwrap();
+    app.dagruns.table.page_loaded();
+    match page {
+        Ok(page) => {
+            let total = usize::try_from(page.total_entries).unwrap_or_default();
+            if let Some(env) = app.environment_state.environments.get_mut(env_name) {
+                env.append_dag_runs(dag_id, page.dag_runs, total, &filter);
+            }
+            if app.environment_state.active_environment.as_deref() == Some(env_name) {
+                app.sync_panel(&crate::app::state::Panel::DAGRun);
+            }
+        }
+        Err(e) => {
+            app.dagruns.popup.show_error(vec![error_message(&e)]);
         }
     }
 }
@@ -49,7 +107,7 @@ pub async fn handle_clear_dag_run(
     if let Err(e) = dag_run {
         debug!("Error clearing dag_run: {e}");
         let mut app = app.lock().unwrap();
-        app.dagruns.popup.show_error(vec![e.to_string()]);
+        app.dagruns.popup.show_error(vec![error_message(&e)]);
     }
 }
 
@@ -74,7 +132,7 @@ pub async fn handle_mark_dag_run(
     if let Err(e) = dag_run {
         debug!("Error marking dag_run: {e}");
         let mut app = app.lock().unwrap();
-        app.dagruns.popup.show_error(vec![e.to_string()]);
+        app.dagruns.popup.show_error(vec![error_message(&e)]);
     }
 }
 
@@ -96,7 +154,7 @@ pub async fn handle_trigger_dag_run(
         Err(e) => {
             debug!("Error triggering dag_run: {e}");
             let mut app = app.lock().unwrap();
-            app.dagruns.popup.show_error(vec![e.to_string()]);
+            app.dagruns.popup.show_error(vec![error_message(&e)]);
         }
     }
 }
@@ -1,5 +1,6 @@
 use std::sync::{Arc, Mutex};
 
+use flowrs_airflow::DagFilter;
 use log::warn;
 
 use crate::airflow::model::common::DagId;
@@ -8,6 +9,7 @@ use crate::app::model::dagruns::popup::trigger::TriggerDagRunPopUp;
 use crate::app::model::dagruns::popup::DagRunPopUp;
 use crate::app::model::dagruns::DagCodeView;
 use crate::app::model::dags::popup::DagPopUp;
+use crate::app::model::popup::error::error_message;
 use crate::app::state::{App, Panel};
 
 /// Handle updating DAGs and their statistics from the Airflow server.
@@ -22,20 +24,22 @@ pub async fn handle_update_dags_and_stats(
     client: &Arc<dyn AirflowClient>,
     env_name: &str,
 ) {
-    // Snapshot cached DAG IDs from the originating environment for the stats request
-    let cached_dag_ids: Vec<DagId> = {
+    // Snapshot cached DAG IDs from the originating environment for the stats
+    // request, and the filter the API should apply to the DAG list
+    let (cached_dag_ids, filter): (Vec<DagId>, DagFilter) = {
         let app_lock = app.lock().unwrap();
-        app_lock
+        let cached_dag_ids = app_lock
             .environment_state
             .environments
             .get(env_name)
             .map(|env| env.dags.iter().map(|dag| dag.dag_id.clone()).collect())
-            .unwrap_or_default()
+            .unwrap_or_default();
+        (cached_dag_ids, app_lock.dags.server_filter(env_name))
     };
 
     if cached_dag_ids.is_empty() {
         // Cold start: fetch DAGs first, then stats with fresh IDs
-        let dag_list_result = client.list_dags().await;
+        let dag_list_result = client.list_dags(&filter).await;
 
         let dag_ids: Vec<DagId> = {
             let mut app = app.lock().unwrap();
@@ -43,12 +47,12 @@ pub async fn handle_update_dags_and_stats(
                 Ok(dag_list) => {
                     let ids: Vec<DagId> = dag_list.dags.iter().map(|d| d.dag_id.clone()).collect();
                     if let Some(env) = app.environment_state.environments.get_mut(env_name) {
-                        env.replace_dags(dag_list.dags);
+                        env.replace_dags(dag_list.dags, &filter);
                     }
                     ids
                 }
                 Err(e) => {
-                    app.dags.popup.show_error(vec![e.to_string()]);
+                    app.dags.popup.show_error(vec![error_message(&e)]);
                     vec![]
                 }
             }
@@ -75,7 +79,7 @@ pub async fn handle_update_dags_and_stats(
         }
     } else {
         // Warm cache: fetch DAG list and stats concurrently using cached IDs
-        let (dag_list_result, dag_stats_result) = tokio::join!(client.list_dags(), async {
+        let (dag_list_result, dag_stats_result) = tokio::join!(client.list_dags(&filter), async {
             let refs: Vec<&str> = cached_dag_ids.iter().map(AsRef::as_ref).collect();
             client.get_dag_stats(refs).await
         });
@@ -85,11 +89,11 @@ pub async fn handle_update_dags_and_stats(
         match dag_list_result {
             Ok(dag_list) => {
                 if let Some(env) = app.environment_state.environments.get_mut(env_name) {
-                    env.replace_dags(dag_list.dags);
+                    env.replace_dags(dag_list.dags, &filter);
                 }
             }
             Err(e) => {
-                app.dags.popup.show_error(vec![e.to_string()]);
+                app.dags.popup.show_error(vec![error_message(&e)]);
             }
         }
 
@@ -128,7 +132,7 @@ pub async fn handle_toggle_dag(
     let dag = client.toggle_dag(dag_id, is_paused).await;
     if let Err(e) = dag {
         let mut app = app.lock().unwrap();
-        app.dags.popup.show_error(vec![e.to_string()]);
+        app.dags.popup.show_error(vec![error_message(&e)]);
     }
 }
 
@@ -159,8 +163,8 @@ pub async fn handle_get_dag_code(
                 }
             }
             Err(e) => match app.active_panel {
-                Panel::DAGRun => app.dagruns.popup.show_error(vec![e.to_string()]),
-                _ => app.dags.popup.show_error(vec![e.to_string()]),
+                Panel::DAGRun => app.dagruns.popup.show_error(vec![error_message(&e)]),
+                _ => app.dags.popup.show_error(vec![error_message(&e)]),
             },
         }
     } else {
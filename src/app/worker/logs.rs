@@ -1,15 +1,21 @@
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
+use anyhow::{Context, Result};
 use futures::future::join_all;
 use log::debug;
 
 use crate::airflow::model::common::{DagId, DagRunId, TaskId};
 use crate::airflow::traits::AirflowClient;
-use crate::app::model::popup::error::ErrorPopup;
+use crate::app::model::logs::{log_file_name, LogDiff};
+use crate::app::model::popup::error::{error_message, ErrorPopup};
 use crate::app::state::App;
 
 /// Handle fetching task logs for all attempts of a task instance.
 ///
+/// Tries already in the cache are continued from their continuation token, so
+/// each poll only downloads what was written since the previous one.
+///
 /// `env_name` identifies which environment initiated this request, ensuring
 /// results are written to the correct environment even if the active one changes.
 pub async fn handle_update_task_logs(
@@ -22,41 +28,131 @@ pub async fn handle_update_task_logs(
     env_name: &str,
 ) {
     debug!("Getting logs for task: {task_id}, try number {task_try}");
-    let logs =
-        join_all((1..=task_try).map(|i| client.get_task_logs(dag_id, dag_run_id, task_id, i)))
+    let tokens = {
+        let app = app.lock().unwrap();
+        app.environment_state
+            .environments
+            .get(env_name)
+            .map_or_else(
+                || vec![None; task_try as usize],
+                |env| env.task_log_tokens(dag_id, dag_run_id, task_id, task_try),
+            )
+    };
+    let logs = join_all(tokens.into_iter().zip(1..).map(|(token, i)| async move {
+        let log = client
+            .get_task_logs(dag_id, dag_run_id, task_id, i, token.as_deref())
             .await;
+        (i, token, log)
+    }))
+    .await;
 
     // Collect logs and errors outside the lock
-    let mut collected_logs = Vec::new();
+    let mut chunks = Vec::new();
     let mut errors = Vec::new();
-    for log in logs {
+    for (i, token, log) in logs {
         match log {
             Ok(log) => {
-                debug!("Got log: {log:?}");
-                collected_logs.push(log);
+                debug!("Got log chunk for try {i}: {} lines", log.lines.len());
+                chunks.push((i, token, log));
             }
             Err(e) => {
                 debug!("Error getting logs: {e}");
-                errors.push(e.to_string());
+                errors.push(error_message(&e));
             }
         }
     }
 
-    let mut app = app.lock().unwrap();
+    let outdated = {
+        let mut app = app.lock().unwrap();
+
+        if !errors.is_empty() {
+            app.logs.error_popup = Some(ErrorPopup::from_strings(errors));
+        }
+
+        // Store logs in the originating environment, not the active one
+        if !chunks.is_empty() {
+            let max_lines = app.config.max_log_lines;
+            if let Some(env) = app.environment_state.environments.get_mut(env_name) {
+                env.merge_task_logs(dag_id, dag_run_id, task_id, chunks, max_lines);
+            }
+        }
+
+        // Only sync panel data if this environment is still active
+        if app.environment_state.active_environment.as_deref() != Some(env_name) {
+            return;
+        }
+        app.sync_panel(&crate::app::state::Panel::Logs);
+        app.logs.outdated_diff()
+    };
 
-    if !errors.is_empty() {
-        app.logs.error_popup = Some(ErrorPopup::from_strings(errors));
+    // Diffing large logs is slow, so an open diff is recomputed without the lock
+    let Some((base, target, old, new)) = outdated else {
+        return;
+    };
+    match tokio::task::spawn_blocking(move || LogDiff::new(base, target, &old, &new)).await {
+        Ok(diff) => app.lock().unwrap().logs.refresh_diff(diff),
+        Err(e) => log::error!("Failed to diff task tries: {e}"),
     }
+}
 
-    // Store logs in the originating environment, not the active one
-    if !collected_logs.is_empty() {
-        if let Some(env) = app.environment_state.environments.get_mut(env_name) {
-            env.replace_task_logs(dag_id, dag_run_id, task_id, collected_logs);
+/// Save the loaded log of one try, or of all tries, as text files in the
+/// configured log directory. The saved paths, or the failure, are reported in
+/// the log panel.
+pub fn handle_save_task_logs(
+    app: &Arc<Mutex<App>>,
+    dag_id: &DagId,
+    dag_run_id: &DagRunId,
+    task_id: &TaskId,
+    task_try: Option<u32>,
+    env_name: &str,
+) {
+    let (dir, files) = {
+        let app = app.lock().unwrap();
+        let logs = app
+            .environment_state
+            .environments
+            .get(env_name)
+            .and_then(|env| {
+                env.task_logs
+                    .get(&(dag_id.clone(), dag_run_id.clone(), task_id.clone()))
+            });
+        let files: Vec<(String, String)> = logs
+            .into_iter()
+            .flatten()
+            .zip(1..)
+            .filter(|(_, i)| task_try.is_none_or(|task_try| task_try == *i))
+            .map(|(log, i)| (log_file_name(dag_id, dag_run_id, task_id, i), log.text()))
+            .collect();
+        (app.config.log_dir(), files)
+    };
+
+    let result = save_files(&dir, &files);
+    let mut app = app.lock().unwrap();
+    match result {
+        Ok(paths) if paths.len() == 1 => {
+            app.logs.notice = Some(format!("Saved to {}", paths[0].display()));
+        }
+        Ok(paths) => {
+            app.logs.notice = Some(format!("Saved {} logs to {}", paths.len(), dir.display()));
+        }
+        Err(e) => {
+            app.logs.error_popup = Some(ErrorPopup::from_strings(vec![error_message(&e)]));
         }
     }
+}
 
-    // Only sync panel data if this environment is still active
-    if app.environment_state.active_environment.as_deref() == Some(env_name) {
-        app.sync_panel(&crate::app::state::Panel::Logs);
+fn save_files(dir: &Path, files: &[(String, String)]) -> Result<Vec<PathBuf>> {
+    if files.is_empty() {
+        anyhow::bail!("No loaded log to save");
     }
+    std::fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    files
+        .iter()
+        .map(|(name, text)| {
+            let path = dir.join(name);
+            std::fs::write(&path, text)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            Ok(path)
+        })
+        .collect()
 }
@@ -3,6 +3,7 @@ use std::sync::{Arc, Mutex};
 
 use super::model::dagruns::popup::mark::MarkState;
 use super::model::taskinstances::popup::mark::MarkState as TaskMarkState;
+use super::session::EnvironmentSession;
 use super::state::App;
 use crate::airflow::model::common::{DagId, DagRunId, OpenItem, TaskId};
 use anyhow::Result;
@@ -14,6 +15,7 @@ mod config;
 mod dagruns;
 mod dags;
 mod logs;
+mod session;
 mod taskinstances;
 mod tasks;
 
@@ -33,6 +35,9 @@ pub enum WorkerMessage {
     UpdateDagRuns {
         dag_id: DagId,
     },
+    LoadMoreDagRuns {
+        dag_id: DagId,
+    },
     UpdateTaskInstances {
         dag_id: DagId,
         dag_run_id: DagRunId,
@@ -53,6 +58,14 @@ pub enum WorkerMessage {
         task_id: TaskId,
         task_try: u32,
     },
+    /// Save the loaded log of one try, or of all tries when `task_try` is
+    /// `None`, to the configured log directory
+    SaveTaskLogs {
+        dag_id: DagId,
+        dag_run_id: DagRunId,
+        task_id: TaskId,
+        task_try: Option<u32>,
+    },
     MarkDagRun {
         dag_run_id: DagRunId,
         dag_id: DagId,
@@ -81,6 +94,8 @@ pub enum WorkerMessage {
         dag_run_id: DagRunId,
     },
     OpenItem(OpenItem),
+    /// Restore where the user left the active environment in a previous session
+    RestoreSession(EnvironmentSession),
 }
 
 impl WorkerMessage {
@@ -91,6 +106,7 @@ impl WorkerMessage {
         match self {
             Self::UpdateDagsAndStats => Some("UpdateDagsAndStats".to_string()),
             Self::UpdateDagRuns { dag_id } => Some(format!("UpdateDagRuns:{dag_id}")),
+            Self::LoadMoreDagRuns { dag_id } => Some(format!("LoadMoreDagRuns:{dag_id}")),
             Self::UpdateTaskInstances { dag_id, dag_run_id } => {
                 Some(format!("UpdateTaskInstances:{dag_id}:{dag_run_id}"))
             }
@@ -166,7 +182,7 @@ async fn process_message(app: Arc<Mutex<App>>, message: WorkerMessage) -> Result
 
     // Handle messages that don't require an active client first
     if let WorkerMessage::ConfigSelected(idx) = message {
-        return config::handle_config_selected(&app, idx);
+        return config::handle_config_selected(&app, idx).await;
     }
 
     // Get the active client and environment name from the environment state.
@@ -219,6 +235,9 @@ async fn process_message(app: Arc<Mutex<App>>, message: WorkerMessage) -> Result
         WorkerMessage::UpdateDagRuns { dag_id, .. } => {
             dagruns::handle_update_dag_runs(&app, &client, &dag_id, &env_name).await;
         }
+        WorkerMessage::LoadMoreDagRuns { dag_id } => {
+            dagruns::handle_load_more_dag_runs(&app, &client, &dag_id, &env_name).await;
+        }
         WorkerMessage::ClearDagRun { dag_run_id, dag_id } => {
             dagruns::handle_clear_dag_run(&app, &client, &dag_id, &dag_run_id).await;
         }
@@ -236,14 +255,17 @@ async fn process_message(app: Arc<Mutex<App>>, message: WorkerMessage) -> Result
         WorkerMessage::UpdateTaskInstances {
             dag_id, dag_run_id, ..
         } => {
-            taskinstances::handle_update_task_instances(
+            if let Some(message) = taskinstances::handle_update_task_instances(
                 &app,
                 &client,
                 &dag_id,
                 &dag_run_id,
                 &env_name,
             )
-            .await;
+            .await
+            {
+                Box::pin(process_message(Arc::clone(&app), message)).await?;
+            }
         }
         WorkerMessage::ClearTaskInstance {
             task_id,
@@ -294,6 +316,14 @@ async fn process_message(app: Arc<Mutex<App>>, message: WorkerMessage) -> Result
             )
             .await;
         }
+        WorkerMessage::SaveTaskLogs {
+            dag_id,
+            dag_run_id,
+            task_id,
+            task_try,
+        } => {
+            logs::handle_save_task_logs(&app, &dag_id, &dag_run_id, &task_id, task_try, &env_name);
+        }
         // Task operations
         WorkerMessage::UpdateTasks { dag_id } => {
             tasks::handle_update_tasks(&app, &client, &dag_id).await;
@@ -305,6 +335,18 @@ async fn process_message(app: Arc<Mutex<App>>, message: WorkerMessage) -> Result
         WorkerMessage::OpenItem(item) => {
             browser::handle_open_item(&app, &client, item);
         }
+        // Session operations
+        WorkerMessage::RestoreSession(session) => {
+            if let Some(messages) =
+                session::handle_restore_session(&app, &client, &session, &env_name).await
+            {
+                // Fetch one after the other, so the rows to select are loaded
+                for message in messages {
+                    Box::pin(process_message(Arc::clone(&app), message)).await?;
+                }
+                app.lock().unwrap().select_rows(&session.selected);
+            }
+        }
     }
 
     // Reset loading state at the end
@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+
+use crate::airflow::traits::AirflowClient;
+use crate::app::model::popup::jump::JumpTarget;
+use crate::app::session::EnvironmentSession;
+use crate::app::state::{App, NavigationContext};
+use crate::app::worker::WorkerMessage;
+
+/// Handle restoring where the user left an environment in a previous session.
+///
+/// Checks how much of the saved DAG, run and task still exists (no lock), then
+/// moves the navigation context there and shows the saved panel. Returns the
+/// fetches for the restored context, or `None` when the user has moved on
+/// since startup and should be left alone.
+pub async fn handle_restore_session(
+    app: &Arc<Mutex<App>>,
+    client: &Arc<dyn AirflowClient>,
+    session: &EnvironmentSession,
+    env_name: &str,
+) -> Option<Vec<WorkerMessage>> {
+    let target = existing_target(client, &session.context).await;
+
+    let mut app = app.lock().unwrap();
+    if !app.awaits_restore(env_name) {
+        return None;
+    }
+    let mut messages = vec![WorkerMessage::UpdateDagsAndStats];
+    if let Some(target) = target {
+        messages.extend(app.jump_to(&target));
+    }
+    app.restore_panel(session.panel.clone());
+    Some(messages)
+}
+
+/// The deepest part of `context` that still exists on the server
+async fn existing_target(
+    client: &Arc<dyn AirflowClient>,
+    context: &NavigationContext,
+) -> Option<JumpTarget> {
+    let dag_id = context.dag_id()?;
+    if let Err(e) = client.list_tasks(dag_id).await {
+        log::info!("Not restoring DAG {dag_id}: {e}");
+        return None;
+    }
+    let dag = JumpTarget::Dag {
+        dag_id: dag_id.clone(),
+    };
+
+    let Some(dag_run_id) = context.dag_run_id() else {
+        return Some(dag);
+    };
+    let task_instances = match client.list_task_instances(dag_id, dag_run_id).await {
+        Ok(list) if !list.task_instances.is_empty() => list.task_instances,
+        _ => {
+            log::info!("Not restoring DAG run {dag_run_id} of {dag_id}");
+            return Some(dag);
+        }
+    };
+    let dag_run = JumpTarget::DagRun {
+        dag_id: dag_id.clone(),
+        dag_run_id: dag_run_id.clone(),
+    };
+
+    let Some(task_id) = context.task_id() else {
+        return Some(dag_run);
+    };
+    if !task_instances.iter().any(|ti| &ti.task_id == task_id) {
+        log::info!("Not restoring task {task_id} of {dag_run_id}");
+        return Some(dag_run);
+    }
+    Some(JumpTarget::Task {
+        dag_id: dag_id.clone(),
+        dag_run_id: dag_run_id.clone(),
+        task_id: task_id.clone(),
+        task_try: context.task_try(),
+    })
+}
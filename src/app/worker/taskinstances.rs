@@ -6,8 +6,10 @@ use log::debug;
 
 use crate::airflow::model::common::{DagId, DagRunId, GanttData, TaskId, TaskInstanceState};
 use crate::airflow::traits::AirflowClient;
+use crate::app::model::popup::error::error_message;
 use crate::app::model::taskinstances::popup::mark::MarkState;
 use crate::app::state::App;
+use crate::app::worker::WorkerMessage;
 
 /// Handle updating the list of task instances for a specific DAG run.
 ///
@@ -17,21 +19,24 @@ use crate::app::state::App;
 /// Fetches task instances, then fetches detailed try history for any retried
 /// tasks, builds the Gantt chart from complete data, and stores everything
 /// atomically under a single lock.
+///
+/// Returns the fetch of the latest try's logs when a task was jumped to
+/// without its try.
 pub async fn handle_update_task_instances(
     app: &Arc<Mutex<App>>,
     client: &Arc<dyn AirflowClient>,
     dag_id: &DagId,
     dag_run_id: &DagRunId,
     env_name: &str,
-) {
+) -> Option<WorkerMessage> {
     // 1. Fetch task instances (no lock)
     let task_instances = match client.list_task_instances(dag_id, dag_run_id).await {
         Ok(list) => list.task_instances,
         Err(e) => {
             log::error!("Error getting task instances: {e:?}");
             let mut app = app.lock().unwrap();
-            app.task_instances.popup.show_error(vec![e.to_string()]);
-            return;
+            app.task_instances.popup.show_error(vec![error_message(&e)]);
+            return None;
         }
     };
 
@@ -71,7 +76,9 @@ pub async fn handle_update_task_instances(
     if app.environment_state.active_environment.as_deref() == Some(env_name) {
         app.sync_panel(&crate::app::state::Panel::TaskInstance);
         app.task_instances.gantt_data = gantt;
+        return app.open_latest_try();
     }
+    None
 }
 
 /// Handle clearing a task instance (resets it to be re-run).
@@ -89,7 +96,7 @@ pub async fn handle_clear_task_instance(
     if let Err(e) = task_instance {
         debug!("Error clearing task_instance: {e}");
         let mut app = app.lock().unwrap();
-        app.task_instances.popup.show_error(vec![e.to_string()]);
+        app.task_instances.popup.show_error(vec![error_message(&e)]);
     }
 }
 
@@ -115,6 +122,6 @@ pub async fn handle_mark_task_instance(
     if let Err(e) = task_instance {
         debug!("Error marking task_instance: {e}");
         let mut app = app.lock().unwrap();
-        app.task_instances.popup.show_error(vec![e.to_string()]);
+        app.task_instances.popup.show_error(vec![error_message(&e)]);
     }
 }
@@ -5,6 +5,7 @@ use log::debug;
 use crate::airflow::graph::TaskGraph;
 use crate::airflow::traits::AirflowClient;
 use crate::app::model::dagruns::popup::DagRunPopUp;
+use crate::app::model::popup::error::error_message;
 use crate::app::model::taskinstances::popup::graph::DagGraphPopup;
 use crate::app::state::App;
 
@@ -64,9 +65,10 @@ pub async fn handle_show_dag_graph(
         (Err(e), _) | (_, Err(e)) => {
             log::warn!("Failed to fetch data for DAG graph: {e}");
             let mut app = app.lock().unwrap();
-            app.dagruns
-                .popup
-                .show_error(vec![format!("Failed to load DAG graph: {e}")]);
+            app.dagruns.popup.show_error(vec![format!(
+                "Failed to load DAG graph: {}",
+                error_message(&e)
+            )]);
         }
     }
 }
@@ -1,6 +1,11 @@
 pub mod add;
+pub mod export;
+pub mod import;
 pub mod list;
 pub mod managed_services;
 pub mod model;
+pub mod oauth2;
 pub mod remove;
+pub mod secret;
+pub mod test;
 pub mod update;
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use inquire::Select;
@@ -6,25 +7,28 @@ use strum::IntoEnumIterator;
 
 use super::model::AddCommand;
 use crate::commands::config::model::{validate_endpoint, ConfigOption};
+use crate::commands::config::oauth2::{log_in, prompt_oauth2};
+use crate::commands::config::secret::store_secret;
 use anyhow::{Context, Result};
 use flowrs_config::{
     AirflowAuth, AirflowConfig, AirflowVersion, BasicAuth, FlowrsConfig, TokenSource,
 };
 
 impl AddCommand {
-    pub fn run(&self) -> Result<()> {
+    pub async fn run(&self) -> Result<()> {
         let name = inquire::Text::new("name").prompt()?;
         let endpoint = inquire::Text::new("endpoint")
             .with_validator(validate_endpoint)
             .prompt()?;
 
-        let version_str = inquire::Select::new("Airflow version", vec!["v2", "v3"])
-            .with_help_message("Select the Airflow API version")
+        let version_str = inquire::Select::new("Airflow version", vec!["auto", "v2", "v3"])
+            .with_help_message("Select the Airflow API version, or detect it at startup")
             .prompt()?;
 
         let version = match version_str {
+            "v2" => AirflowVersion::V2,
             "v3" => AirflowVersion::V3,
-            _ => AirflowVersion::V2,
+            _ => AirflowVersion::Auto,
         };
 
         let insecure = if self.insecure {
@@ -39,22 +43,31 @@ impl AddCommand {
         let auth_type =
             Select::new("authentication type", ConfigOption::iter().collect()).prompt()?;
 
-        let new_config = match auth_type {
+        let auth = match auth_type {
             ConfigOption::BasicAuth => {
                 let username = inquire::Text::new("username").prompt()?;
                 let password = inquire::Password::new("password")
                     .with_display_toggle_enabled()
                     .prompt()?;
-
-                AirflowConfig {
-                    name,
-                    endpoint,
-                    auth: AirflowAuth::Basic(BasicAuth { username, password }),
-                    managed: None,
-                    version,
-                    timeout_secs: 30,
-                    insecure,
-                }
+                let password = store_secret(&name, "password", &password)?;
+                AirflowAuth::Basic(BasicAuth { username, password })
+            }
+            ConfigOption::JwtLogin => {
+                let username = inquire::Text::new("username").prompt()?;
+                let password = inquire::Password::new("password")
+                    .with_display_toggle_enabled()
+                    .prompt()?;
+                let password = store_secret(&name, "password", &password)?;
+                AirflowAuth::Jwt(BasicAuth { username, password })
+            }
+            ConfigOption::OAuth2 => AirflowAuth::OAuth2(prompt_oauth2(&name)?),
+            ConfigOption::StaticToken => {
+                let token = inquire::Password::new("token")
+                    .with_display_toggle_enabled()
+                    .without_confirmation()
+                    .prompt()?;
+                let token = store_secret(&name, "token", &token)?;
+                AirflowAuth::Token(TokenSource::Static { token })
             }
             ConfigOption::Token(_) => {
                 let cmd = inquire::Text::new("cmd").prompt()?;
@@ -66,19 +79,26 @@ impl AddCommand {
                     .with_context(|| format!("Failed to execute token command: {cmd}"))?;
                 // Validate the command produces a token
                 let _token = String::from_utf8(output.stdout)?.trim().to_string();
-
-                AirflowConfig {
-                    name,
-                    endpoint,
-                    auth: AirflowAuth::Token(TokenSource::Command { cmd }),
-                    managed: None,
-                    version,
-                    timeout_secs: 30,
-                    insecure,
-                }
+                AirflowAuth::Token(TokenSource::Command { cmd })
             }
         };
 
+        let mut new_config = AirflowConfig {
+            name,
+            endpoint,
+            auth,
+            managed: None,
+            version,
+            timeout_secs: 30,
+            insecure,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            proxy: None,
+            headers: BTreeMap::new(),
+        };
+        log_in(&mut new_config).await?;
+
         let path = self.file.as_ref().map(PathBuf::from);
         let mut config = FlowrsConfig::from_file(path.as_ref(), &crate::CONFIG_PATHS)?;
 
@@ -0,0 +1,49 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+use super::model::ExportCommand;
+use anyhow::{Context, Result};
+use flowrs_config::FlowrsConfig;
+
+impl ExportCommand {
+    pub fn run(&self) -> Result<()> {
+        let path = self.file.as_ref().map(PathBuf::from);
+        let config = FlowrsConfig::from_file(path.as_ref(), &crate::CONFIG_PATHS)?;
+        let exported = config.export(self.include_secrets)?;
+
+        match &self.output {
+            Some(output) => {
+                write_export(output, &exported, self.include_secrets)
+                    .with_context(|| format!("failed to write export to {output}"))?;
+                if self.include_secrets {
+                    eprintln!("⚠️  The export contains credentials, handle it with care.");
+                }
+                eprintln!("✅ Config exported to {output}");
+            }
+            None => print!("{exported}"),
+        }
+        Ok(())
+    }
+}
+
+/// Write the export, readable only by the owner when it contains credentials.
+fn write_export(path: &str, contents: &str, private: bool) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // The mode only applies to new files, so restrict an existing one as well
+    #[cfg(unix)]
+    if private {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    #[cfg(not(unix))]
+    let _ = private;
+    file.write_all(contents.as_bytes())
+}
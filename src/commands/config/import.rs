@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use super::model::ImportCommand;
+use anyhow::{Context, Result};
+use flowrs_config::{FlowrsConfig, ImportMode};
+
+impl ImportCommand {
+    pub fn run(&self) -> Result<()> {
+        let contents = std::fs::read_to_string(&self.input)
+            .with_context(|| format!("failed to read {}", self.input))?;
+        let imported = FlowrsConfig::parse_toml(&contents)
+            .with_context(|| format!("failed to parse {}", self.input))?;
+
+        let path = self.file.as_ref().map(PathBuf::from);
+        let mut config = FlowrsConfig::from_file(path.as_ref(), &crate::CONFIG_PATHS)?;
+        if let Some(user_path) = path {
+            config.path = Some(user_path);
+        }
+
+        let mode = if self.replace {
+            ImportMode::Replace
+        } else {
+            ImportMode::Merge
+        };
+        let summary = config.import(imported, mode)?;
+        config.write_to_file(&crate::CONFIG_PATHS)?;
+
+        println!("✅ Imported {} server(s)", summary.added.len());
+        for name in &summary.added {
+            println!("  + {name}");
+        }
+        for name in &summary.unchanged {
+            println!("  = {name} (already present)");
+        }
+        if !summary.missing_secrets.is_empty() {
+            println!(
+                "🔑 These servers need credentials, set them with `flowrs config update <name>`: {}",
+                summary.missing_secrets.join(", ")
+            );
+        }
+        Ok(())
+    }
+}
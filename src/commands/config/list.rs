@@ -2,7 +2,8 @@ use std::path::PathBuf;
 
 use super::model::ListCommand;
 use anyhow::Result;
-use flowrs_config::FlowrsConfig;
+use flowrs_config::export::secrets;
+use flowrs_config::{FlowrsConfig, SecretValue};
 
 impl ListCommand {
     pub fn run(&self) -> Result<()> {
@@ -15,10 +16,17 @@ impl ListCommand {
         } else {
             println!("📋 Airflow instances in the config file:");
             for server in servers {
+                let locations: Vec<String> =
+                    secrets(&server).iter().map(SecretValue::location).collect();
+                let secret = if locations.is_empty() {
+                    String::new()
+                } else {
+                    format!(", credentials: {}", locations.join(", "))
+                };
                 if let Some(managed) = server.managed {
-                    println!("  - {} ({})", server.name, managed);
+                    println!("  - {} ({managed})", server.name);
                 } else {
-                    println!("  - {}", server.name);
+                    println!("  - {}{secret}", server.name);
                 }
             }
         }
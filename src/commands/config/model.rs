@@ -120,17 +120,23 @@ pub enum ConfigCommand {
     List(ListCommand),
     Enable(ManagedServiceCommand),
     Disable(ManagedServiceCommand),
+    Export(ExportCommand),
+    Import(ImportCommand),
+    Test(TestCommand),
 }
 
 impl ConfigCommand {
     pub async fn run(&self) -> Result<()> {
         match self {
-            Self::Add(cmd) => cmd.run(),
+            Self::Add(cmd) => cmd.run().await,
             Self::Remove(cmd) => cmd.run(),
-            Self::Update(cmd) => cmd.run(),
+            Self::Update(cmd) => cmd.run().await,
             Self::List(cmd) => cmd.run(),
             Self::Enable(cmd) => cmd.run().await,
             Self::Disable(cmd) => cmd.disable(),
+            Self::Export(cmd) => cmd.run(),
+            Self::Import(cmd) => cmd.run(),
+            Self::Test(cmd) => cmd.run().await,
         }
     }
 }
@@ -165,9 +171,48 @@ pub struct UpdateCommand {
     pub insecure: bool,
 }
 
+/// Print the server list as TOML, for sharing with a team.
+#[derive(Parser, Debug)]
+pub struct ExportCommand {
+    #[clap(short, long)]
+    pub file: Option<String>,
+    /// Write to this file instead of stdout
+    #[clap(short, long)]
+    pub output: Option<String>,
+    /// Keep basic-auth passwords and static tokens in the export
+    #[clap(long)]
+    pub include_secrets: bool,
+}
+
+/// Add the servers of an exported config file to your config.
+#[derive(Parser, Debug)]
+pub struct ImportCommand {
+    /// Exported config file to import
+    pub input: String,
+    #[clap(short, long)]
+    pub file: Option<String>,
+    /// Add to the existing servers (default); fails on conflicting names
+    #[clap(long, conflicts_with = "replace")]
+    pub merge: bool,
+    /// Replace all existing servers with the imported ones
+    #[clap(long)]
+    pub replace: bool,
+}
+
+/// Check that a server is reachable and its credentials and certificates work.
+#[derive(Parser, Debug)]
+pub struct TestCommand {
+    pub name: Option<String>,
+    #[clap(short, long)]
+    pub file: Option<String>,
+}
+
 #[derive(EnumIter, Debug, Display)]
 pub enum ConfigOption {
     BasicAuth,
+    JwtLogin,
+    OAuth2,
+    StaticToken,
     Token(Command),
 }
 
@@ -0,0 +1,121 @@
+use anyhow::{Context, Result};
+use flowrs_airflow::client::auth::{poll_device_token, request_device_code};
+use flowrs_airflow::client::base::http_client;
+use flowrs_config::{AirflowAuth, AirflowConfig, OAuth2Auth, OAuth2Flow};
+use inquire::Select;
+
+use super::model::validate_endpoint;
+use super::secret::store_secret;
+
+const DEVICE_CODE: &str = "device code (log in with a browser)";
+const CLIENT_CREDENTIALS: &str = "client credentials (service account)";
+
+/// Interactively configure OAuth 2.0 for `server`.
+///
+/// For the device-code flow, follow up with [`log_in`] once the server is
+/// configured.
+pub fn prompt_oauth2(server: &str) -> Result<OAuth2Auth> {
+    let flow = match Select::new("OAuth2 flow", vec![DEVICE_CODE, CLIENT_CREDENTIALS]).prompt()? {
+        DEVICE_CODE => OAuth2Flow::DeviceCode,
+        _ => OAuth2Flow::ClientCredentials,
+    };
+
+    let token_url = inquire::Text::new("token URL")
+        .with_help_message("Keycloak: https://<host>/realms/<realm>/protocol/openid-connect/token")
+        .with_validator(validate_endpoint)
+        .prompt()?;
+    let device_authorization_url = match flow {
+        OAuth2Flow::DeviceCode => Some(
+            inquire::Text::new("device authorization URL")
+                .with_help_message(
+                    "Keycloak: https://<host>/realms/<realm>/protocol/openid-connect/auth/device",
+                )
+                .with_validator(validate_endpoint)
+                .prompt()?,
+        ),
+        OAuth2Flow::ClientCredentials => None,
+    };
+    let client_id = inquire::Text::new("client id").prompt()?;
+
+    let client_secret = match flow {
+        OAuth2Flow::ClientCredentials => Some(
+            inquire::Password::new("client secret")
+                .with_display_toggle_enabled()
+                .without_confirmation()
+                .prompt()?,
+        ),
+        OAuth2Flow::DeviceCode => Some(
+            inquire::Password::new("client secret")
+                .with_help_message("Leave empty for public clients")
+                .with_display_toggle_enabled()
+                .without_confirmation()
+                .prompt()?,
+        )
+        .filter(|secret| !secret.is_empty()),
+    };
+    let client_secret = client_secret
+        .map(|secret| store_secret(server, "client_secret", &secret))
+        .transpose()?;
+
+    let default_scopes = match flow {
+        OAuth2Flow::DeviceCode => "openid offline_access",
+        OAuth2Flow::ClientCredentials => "",
+    };
+    let scopes = inquire::Text::new("scopes")
+        .with_help_message("Space-separated")
+        .with_default(default_scopes)
+        .prompt()?
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+
+    Ok(OAuth2Auth {
+        flow,
+        token_url,
+        device_authorization_url,
+        client_id,
+        client_secret,
+        scopes,
+        refresh_token: None,
+    })
+}
+
+/// For a server using the device-code flow, run the browser login right away
+/// and store the resulting refresh token, which flowrs redeems on every start.
+///
+/// The login goes through the server's own HTTP client, so its proxy and
+/// certificates apply to the identity provider as well.
+pub async fn log_in(server: &mut AirflowConfig) -> Result<()> {
+    let AirflowAuth::OAuth2(auth) = &server.auth else {
+        return Ok(());
+    };
+    if auth.flow != OAuth2Flow::DeviceCode {
+        return Ok(());
+    }
+    let client = http_client(server)?;
+    let refresh_token = device_login(&client, auth).await?;
+    let refresh_token = store_secret(&server.name, "refresh_token", &refresh_token)?;
+    if let AirflowAuth::OAuth2(auth) = &mut server.auth {
+        auth.refresh_token = Some(refresh_token);
+    }
+    Ok(())
+}
+
+/// Run the device-code login and return the refresh token.
+async fn device_login(client: &reqwest::Client, auth: &OAuth2Auth) -> Result<String> {
+    let device = request_device_code(client, auth).await?;
+    match &device.verification_uri_complete {
+        Some(uri) => println!("🌐 Open {uri} to log in"),
+        None => println!(
+            "🌐 Open {} and enter the code {}",
+            device.verification_uri, device.user_code
+        ),
+    }
+    println!("⏳ Waiting for the login to be approved...");
+
+    let token = poll_device_token(client, auth, &device).await?;
+    println!("✅ Logged in");
+    token.refresh_token.context(
+        "The identity provider returned no refresh token; request the offline_access scope",
+    )
+}
@@ -3,8 +3,10 @@ use std::path::PathBuf;
 use inquire::Select;
 
 use super::model::RemoveCommand;
+use super::secret::forget_secret;
 use anyhow::Result;
-use flowrs_config::FlowrsConfig;
+use flowrs_config::export::secrets;
+use flowrs_config::{FlowrsConfig, SecretValue};
 
 impl RemoveCommand {
     pub fn run(&self) -> Result<()> {
@@ -23,10 +25,17 @@ impl RemoveCommand {
             .prompt()?,
             Some(ref name) => name.clone(),
         };
+        let removed_secrets: Vec<SecretValue> = config
+            .servers
+            .iter()
+            .filter(|server| server.name == name)
+            .flat_map(secrets)
+            .collect();
         config
             .servers
             .retain(|server| server.name != name && server.managed.is_none());
         config.write_to_file(&crate::CONFIG_PATHS)?;
+        removed_secrets.iter().for_each(forget_secret);
 
         println!("✅ Config '{name}' removed successfully!");
         Ok(())
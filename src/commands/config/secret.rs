@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use flowrs_airflow::client::auth::set_refresh_token_writer;
+use flowrs_airflow::keyring::{delete_secret, set_secret};
+use flowrs_config::{AirflowAuth, FlowrsConfig, KeyringBackend, SecretValue};
+use inquire::Select;
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+/// Where `config add` / `config update` keep a newly entered secret.
+#[derive(EnumIter, Debug, Display, Clone, Copy)]
+enum SecretStorage {
+    #[strum(to_string = "keyring")]
+    Keyring,
+    #[strum(to_string = "config file (plaintext)")]
+    ConfigFile,
+}
+
+/// Ask where to store `value` and return the `SecretValue` to put in the config.
+///
+/// Keyring storage tries the system keyring first and falls back to the file
+/// keyring when none is available (e.g. headless Linux without Secret Service).
+pub fn store_secret(server: &str, kind: &str, value: &str) -> Result<SecretValue> {
+    let storage = Select::new(
+        &format!("where should the {kind} be stored?"),
+        SecretStorage::iter().collect(),
+    )
+    .prompt()?;
+
+    match storage {
+        SecretStorage::ConfigFile => Ok(SecretValue::literal(value)),
+        SecretStorage::Keyring => {
+            let key = format!("{server}/{kind}");
+            let backend = match set_secret(&key, value, KeyringBackend::System) {
+                Ok(()) => KeyringBackend::System,
+                Err(e) => {
+                    println!("⚠️  System keyring unavailable ({e:#}); using the file keyring");
+                    set_secret(&key, value, KeyringBackend::File)?;
+                    KeyringBackend::File
+                }
+            };
+            let secret = SecretValue::Keyring {
+                keyring: key,
+                backend,
+            };
+            println!("🔐 Stored {kind} in {}", secret.location());
+            Ok(secret)
+        }
+    }
+}
+
+/// Write OAuth 2.0 refresh tokens that are stored in plain text in the config
+/// file at `path` back to it when the identity provider rotates them.
+pub fn persist_refresh_tokens(path: Option<PathBuf>) {
+    set_refresh_token_writer(Box::new(move |server, refresh_token| {
+        let mut config = FlowrsConfig::from_file(path.as_ref(), &crate::CONFIG_PATHS)?;
+        let auth = config
+            .servers
+            .iter_mut()
+            .find(|s| s.name == server)
+            .and_then(|s| match &mut s.auth {
+                AirflowAuth::OAuth2(auth) => Some(auth),
+                _ => None,
+            })
+            .with_context(|| format!("no OAuth2 server named '{server}' in the config file"))?;
+        auth.refresh_token = Some(SecretValue::literal(refresh_token));
+        config.write_to_file(&crate::CONFIG_PATHS)
+    }));
+}
+
+/// Best-effort removal of a keyring entry that is no longer referenced.
+pub fn forget_secret(secret: &SecretValue) {
+    if let SecretValue::Keyring { keyring, backend } = secret {
+        if let Err(e) = delete_secret(keyring, *backend) {
+            log::warn!("Failed to delete keyring entry '{keyring}': {e}");
+        }
+    }
+}
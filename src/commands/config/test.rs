@@ -0,0 +1,105 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use flowrs_airflow::client::version::{resolve_version, server_version};
+use flowrs_airflow::BaseClient;
+use flowrs_config::{AirflowConfig, FlowrsConfig};
+use inquire::Select;
+
+use super::model::TestCommand;
+use super::secret::persist_refresh_tokens;
+
+impl TestCommand {
+    pub async fn run(&self) -> Result<()> {
+        let path = self.file.as_ref().map(PathBuf::from);
+        persist_refresh_tokens(path.clone());
+        let config = FlowrsConfig::from_file(path.as_ref(), &crate::CONFIG_PATHS)?;
+
+        if config.servers.is_empty() {
+            println!("❌ No servers found in config file");
+            return Ok(());
+        }
+
+        let name = match &self.name {
+            Some(name) => name.clone(),
+            None => Select::new(
+                "name",
+                config
+                    .servers
+                    .iter()
+                    .map(|server| server.name.clone())
+                    .collect(),
+            )
+            .prompt()?,
+        };
+        let server = config
+            .servers
+            .iter()
+            .find(|server| server.name == name)
+            .ok_or_else(|| anyhow::anyhow!("no server named '{name}' found in config"))?;
+
+        println!("🔌 Connecting to '{}' at {}", server.name, server.endpoint);
+        match check_connection(server).await {
+            Ok(version) => {
+                println!("✅ Connected to '{name}' (Airflow {version})");
+                Ok(())
+            }
+            Err(e) => {
+                println!("❌ Connection to '{name}' failed");
+                if let Some(hint) = tls_hint(&e) {
+                    println!("💡 {hint}");
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Resolve the server's version and fetch its `version` endpoint, which
+/// exercises TLS, authentication and the API path in one request.
+async fn check_connection(server: &AirflowConfig) -> Result<String> {
+    let mut server = server.clone();
+    server.version = resolve_version(&server).await?;
+    let base = BaseClient::connect(server).await?;
+    server_version(&base).await
+}
+
+/// A human explanation for certificate failures, which otherwise surface as
+/// a terse rustls error deep in the error chain.
+fn tls_hint(error: &anyhow::Error) -> Option<&'static str> {
+    let message = format!("{error:#}");
+    let hint = if message.contains("UnknownIssuer") {
+        "The server certificate is signed by a CA that is not trusted. Set `ca_cert` to a PEM bundle with your CA."
+    } else if message.contains("NotValidForName") {
+        "The server certificate does not match the host name in `endpoint`."
+    } else if message.contains("Expired") {
+        "The server certificate has expired (or the local clock is wrong)."
+    } else if message.contains("CertificateRequired")
+        || message.contains("BadCertificate")
+        || message.contains("UnknownCA")
+    {
+        "The server rejected the client certificate. Check `client_cert` and `client_key`."
+    } else if message.contains("certificate") {
+        "TLS certificate verification failed. Check `ca_cert`, `client_cert` and `client_key`."
+    } else {
+        return None;
+    };
+    Some(hint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_certificate_errors() {
+        let unknown_ca = anyhow::anyhow!("invalid peer certificate: UnknownIssuer")
+            .context("error sending request");
+        assert!(tls_hint(&unknown_ca).unwrap().contains("ca_cert"));
+
+        let rejected = anyhow::anyhow!("received fatal alert: CertificateRequired");
+        assert!(tls_hint(&rejected).unwrap().contains("client_cert"));
+
+        assert!(tls_hint(&anyhow::anyhow!("connection refused")).is_none());
+    }
+}
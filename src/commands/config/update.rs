@@ -6,12 +6,17 @@ use strum::IntoEnumIterator;
 
 use super::model::UpdateCommand;
 use crate::commands::config::model::{validate_endpoint, ConfigOption};
-use flowrs_config::{AirflowAuth, AirflowConfig, BasicAuth, FlowrsConfig, TokenSource};
+use crate::commands::config::oauth2::{log_in, prompt_oauth2};
+use crate::commands::config::secret::{forget_secret, store_secret};
+use flowrs_config::export::secrets;
+use flowrs_config::{
+    AirflowAuth, AirflowConfig, BasicAuth, FlowrsConfig, SecretValue, TokenSource,
+};
 
 use anyhow::{anyhow, Context, Result};
 
 impl UpdateCommand {
-    pub fn run(&self) -> Result<()> {
+    pub async fn run(&self) -> Result<()> {
         let path = self.file.as_ref().map(PathBuf::from);
         let mut config = FlowrsConfig::from_file(path.as_ref(), &crate::CONFIG_PATHS)?;
 
@@ -54,6 +59,7 @@ impl UpdateCommand {
         let auth_type =
             Select::new("authentication type", ConfigOption::iter().collect()).prompt()?;
 
+        let previous_secrets = secrets(airflow_config);
         airflow_config.name = name;
         airflow_config.endpoint = endpoint;
         airflow_config.insecure = insecure;
@@ -63,9 +69,33 @@ impl UpdateCommand {
                 let password = inquire::Password::new("password")
                     .with_display_toggle_enabled()
                     .prompt()?;
+                let password = store_secret(&airflow_config.name, "password", &password)?;
 
                 airflow_config.auth = AirflowAuth::Basic(BasicAuth { username, password });
             }
+            ConfigOption::JwtLogin => {
+                let username = inquire::Text::new("username").prompt()?;
+                let password = inquire::Password::new("password")
+                    .with_display_toggle_enabled()
+                    .prompt()?;
+                let password = store_secret(&airflow_config.name, "password", &password)?;
+
+                airflow_config.auth = AirflowAuth::Jwt(BasicAuth { username, password });
+            }
+            ConfigOption::OAuth2 => {
+                let oauth2 = prompt_oauth2(&airflow_config.name)?;
+                airflow_config.auth = AirflowAuth::OAuth2(oauth2);
+                log_in(airflow_config).await?;
+            }
+            ConfigOption::StaticToken => {
+                let token = inquire::Password::new("token")
+                    .with_display_toggle_enabled()
+                    .without_confirmation()
+                    .prompt()?;
+                let token = store_secret(&airflow_config.name, "token", &token)?;
+
+                airflow_config.auth = AirflowAuth::Token(TokenSource::Static { token });
+            }
             ConfigOption::Token(_) => {
                 let cmd = inquire::Text::new("cmd").prompt()?;
                 info!("🔑 Running command: {cmd}");
@@ -80,8 +110,16 @@ impl UpdateCommand {
             }
         }
 
+        // A keyring entry the server no longer points at would otherwise linger.
+        let current_secrets = secrets(airflow_config);
+        let stale_secrets: Vec<SecretValue> = previous_secrets
+            .into_iter()
+            .filter(|previous| !current_secrets.contains(previous))
+            .collect();
+
         config.servers = servers;
         config.write_to_file(&crate::CONFIG_PATHS)?;
+        stale_secrets.iter().for_each(forget_secret);
 
         println!("✅ Config updated successfully!");
         Ok(())
@@ -97,6 +135,8 @@ fn find_server(servers: &[AirflowConfig], name: &str) -> Result<usize> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use super::*;
     use flowrs_config::{AirflowVersion, BasicAuth};
 
@@ -106,12 +146,17 @@ mod tests {
             endpoint: "http://localhost:8080".to_string(),
             auth: AirflowAuth::Basic(BasicAuth {
                 username: "airflow".to_string(),
-                password: "airflow".to_string(),
+                password: "airflow".into(),
             }),
             managed: None,
             version: AirflowVersion::V2,
             timeout_secs: 30,
             insecure: false,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            proxy: None,
+            headers: BTreeMap::new(),
         }
     }
 
@@ -4,16 +4,25 @@ use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 use clap::Parser;
-use crossterm::event::{DisableFocusChange, EnableFocusChange};
+use crossterm::event::{
+    DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+};
 use crossterm::ExecutableCommand;
 use log::{info, LevelFilter, Log, Metadata, Record};
 
+use crate::airflow::client::parse_open_url;
+use crate::airflow::model::common::OpenItem;
+use crate::app::model::popup::jump::JumpTarget;
 use crate::app::run_app;
+use crate::app::session::{state_dir, Session};
 use crate::app::state::App;
+use crate::app::worker::WorkerMessage;
+use crate::commands::config::secret::persist_refresh_tokens;
 use crate::CONFIG_PATHS;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use flowrs_airflow::managed_services::expand::{expand_managed_services, ManagedServiceConfig};
-use flowrs_config::FlowrsConfig;
+use flowrs_config::{AirflowConfig, FlowrsConfig};
+use url::Url;
 
 struct FileLogger {
     file: Mutex<File>,
@@ -46,10 +55,28 @@ impl Log for FileLogger {
     }
 }
 
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Default)]
 pub struct RunCommand {
     #[clap(short, long)]
     pub file: Option<String>,
+    /// Server to open instead of the active one
+    #[clap(short, long)]
+    pub server: Option<String>,
+    /// DAG to open
+    #[clap(short, long)]
+    pub dag: Option<String>,
+    /// DAG run to open
+    #[clap(short, long, requires = "dag")]
+    pub run: Option<String>,
+    /// Task to open the logs of
+    #[clap(short, long, requires = "run")]
+    pub task: Option<String>,
+    /// Try of the task to open, the latest if not given
+    #[clap(long = "try", value_name = "TRY", requires = "task")]
+    pub task_try: Option<u32>,
+    /// Airflow web UI URL of a DAG, DAG run or task to open
+    #[clap(conflicts_with_all = ["dag", "run", "task", "task_try"])]
+    pub url: Option<String>,
 }
 
 impl RunCommand {
@@ -61,6 +88,7 @@ impl RunCommand {
 
         // Read config file
         let path = self.file.as_ref().map(PathBuf::from);
+        persist_refresh_tokens(path.clone());
         let mut config = FlowrsConfig::from_file(path.as_ref(), &CONFIG_PATHS)?;
         let ms_config = ManagedServiceConfig {
             services: config.managed_services.clone(),
@@ -71,7 +99,7 @@ impl RunCommand {
                 .unwrap_or_default(),
             gcc_projects: config.gcc.as_ref().and_then(|c| c.projects.clone()),
         };
-        let (new_servers, errors) = expand_managed_services(ms_config).await?;
+        let (new_servers, mut errors) = expand_managed_services(ms_config).await?;
         config.extend_servers(new_servers);
 
         // Generate warnings for legacy config conflict (only when no explicit --file)
@@ -89,25 +117,141 @@ impl RunCommand {
             ));
         }
 
+        let (server, target) = self.deep_link(&config)?;
+
+        errors.extend(crate::app::keymap::init_keymap(&config.keys));
+
         // Initialize theme before entering raw mode (detection requires terminal queries)
         crate::ui::theme::init_theme(config.theme);
 
         // setup terminal (includes panic hooks) and run app
         let mut terminal = ratatui::init();
-        std::io::stdout().execute(EnableFocusChange)?;
+        std::io::stdout()
+            .execute(EnableFocusChange)?
+            .execute(EnableMouseCapture)?;
+        // The panic hook of `ratatui::init` only knows about raw mode and the
+        // alternate screen, so stop capturing the mouse before it runs
+        let restore_terminal = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = std::io::stdout()
+                .execute(DisableMouseCapture)
+                .and_then(|stdout| stdout.execute(DisableFocusChange));
+            restore_terminal(info);
+        }));
 
-        let app = App::new_with_errors_and_warnings(config, errors, warnings);
-        let result = run_app(&mut terminal, Arc::new(Mutex::new(app))).await;
+        let mut app = App::new_with_errors_and_warnings(config, errors, warnings);
+        if let Some(server) = server {
+            app.start_on_server(server);
+        }
+        let restore = app.restore_session(Session::load());
+        // A deep link replaces the restored position. Load the DAG list as
+        // well, so going back up or jumping elsewhere works.
+        let startup = target.map_or(restore, |target| {
+            let mut messages = vec![WorkerMessage::UpdateDagsAndStats];
+            messages.extend(app.jump_to(&target));
+            messages
+        });
+        let result = run_app(&mut terminal, Arc::new(Mutex::new(app)), startup).await;
 
         info!("Shutting down the terminal...");
-        std::io::stdout().execute(DisableFocusChange)?;
+        std::io::stdout()
+            .execute(DisableMouseCapture)?
+            .execute(DisableFocusChange)?;
         ratatui::restore();
         result
     }
 }
 
+impl RunCommand {
+    /// The server named by `--server` or by the URL, to open instead of the
+    /// active one for this run only, and the DAG, DAG run or task to open on
+    /// startup.
+    fn deep_link(&self, config: &FlowrsConfig) -> Result<(Option<String>, Option<JumpTarget>)> {
+        let (server, item) = match &self.url {
+            Some(url) => {
+                let (server, item) = find_url_server(&config.servers, self.server.as_deref(), url)?;
+                (Some(server), Some(item))
+            }
+            None => (self.server.clone(), self.open_item()),
+        };
+
+        if let Some(server) = &server {
+            if !config.servers.iter().any(|s| &s.name == server) {
+                bail!("No server named '{server}' is configured");
+            }
+        }
+        let Some(item) = item else {
+            return Ok((server, None));
+        };
+        if server.is_none() && config.active_server.is_none() {
+            bail!("No active server to open {item:?} on; pass --server");
+        }
+        Ok((server, JumpTarget::from_open_item(item)))
+    }
+
+    fn open_item(&self) -> Option<OpenItem> {
+        let dag_id = self.dag.as_deref()?.into();
+        let Some(dag_run_id) = self.run.as_deref().map(Into::into) else {
+            return Some(OpenItem::Dag { dag_id });
+        };
+        let Some(task_id) = self.task.as_deref().map(Into::into) else {
+            return Some(OpenItem::DagRun { dag_id, dag_run_id });
+        };
+        Some(match self.task_try {
+            Some(task_try) => OpenItem::Log {
+                dag_id,
+                dag_run_id,
+                task_id,
+                task_try,
+            },
+            None => OpenItem::TaskInstance {
+                dag_id,
+                dag_run_id,
+                task_id,
+            },
+        })
+    }
+}
+
+/// Find the server `url` belongs to, limited to `name` when given, and the
+/// item the URL shows. When servers share a host, the one with the longest
+/// base path the URL is under wins.
+fn find_url_server(
+    servers: &[AirflowConfig],
+    name: Option<&str>,
+    url: &str,
+) -> Result<(String, OpenItem)> {
+    let mut found: Option<(usize, &AirflowConfig, OpenItem)> = None;
+    let mut error = None;
+    for server in servers
+        .iter()
+        .filter(|server| name.is_none_or(|name| server.name == name))
+    {
+        match parse_open_url(&server.endpoint, url) {
+            Ok(Some(item)) => {
+                let depth = Url::parse(&server.endpoint)
+                    .map_or(0, |endpoint| endpoint.path().trim_end_matches('/').len());
+                if found.as_ref().is_none_or(|(longest, ..)| depth > *longest) {
+                    found = Some((depth, server, item));
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::debug!("Skipping server '{}' for {url}: {e}", server.name);
+                error = Some(e);
+            }
+        }
+    }
+    match (found, error, name) {
+        (Some((_, server, item)), ..) => Ok((server.name.clone(), item)),
+        (None, Some(e), _) => Err(e),
+        (None, None, Some(name)) => bail!("{url} is not on server '{name}'"),
+        (None, None, None) => bail!("{url} is not on any configured server"),
+    }
+}
+
 fn setup_logging(log_level: &str) -> Result<()> {
-    let log_dir = dirs::state_dir().map_or_else(|| PathBuf::from("."), |p| p.join("flowrs"));
+    let log_dir = state_dir().unwrap_or_else(|| PathBuf::from("."));
     std::fs::create_dir_all(&log_dir)?;
 
     let log_file = format!(
@@ -131,3 +275,62 @@ fn setup_logging(log_level: &str) -> Result<()> {
     log::set_max_level(level);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVERS: &str = r#"
+[[servers]]
+name = "root"
+endpoint = "https://airflow.example.com/"
+
+[servers.auth.Basic]
+username = "airflow"
+password = "airflow"
+
+[[servers]]
+name = "team"
+endpoint = "https://airflow.example.com/team/"
+
+[servers.auth.Basic]
+username = "airflow"
+password = "airflow"
+"#;
+
+    #[test]
+    fn finds_the_server_with_the_longest_base_path() {
+        let config = FlowrsConfig::parse_toml(SERVERS).unwrap();
+        let (server, item) = find_url_server(
+            &config.servers,
+            None,
+            "https://airflow.example.com/team/dags/etl",
+        )
+        .unwrap();
+        assert_eq!(server, "team");
+        assert!(matches!(item, OpenItem::Dag { dag_id } if *dag_id == *"etl"));
+
+        let (server, _) = find_url_server(
+            &config.servers,
+            None,
+            "https://airflow.example.com/dags/etl",
+        )
+        .unwrap();
+        assert_eq!(server, "root");
+        assert!(
+            find_url_server(&config.servers, None, "https://airflow.example.com/home").is_err()
+        );
+    }
+
+    #[test]
+    fn deep_links_do_not_change_the_active_server() {
+        let config = FlowrsConfig::parse_toml(SERVERS).unwrap();
+        let command = RunCommand {
+            url: Some("https://airflow.example.com/team/dags/etl".into()),
+            ..RunCommand::default()
+        };
+        let (server, target) = command.deep_link(&config).unwrap();
+        assert_eq!(server.as_deref(), Some("team"));
+        assert!(matches!(target, Some(JumpTarget::Dag { .. })));
+    }
+}
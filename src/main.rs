@@ -39,7 +39,7 @@ impl FlowrsApp {
         match &self.command {
             Some(FlowrsCommand::Run(cmd)) => cmd.run().await,
             Some(FlowrsCommand::Config(cmd)) => cmd.run().await,
-            None => RunCommand { file: None }.run().await,
+            None => RunCommand::default().run().await,
         }
     }
 }
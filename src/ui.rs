@@ -93,6 +93,7 @@ pub fn draw_ui(f: &mut Frame, app: &Arc<Mutex<App>>) {
     };
     let tab_bar = TabBar::new(active_tab_index);
     f.render_widget(tab_bar, tab_area);
+    app.tab_area = tab_area;
 
     // Only frame has the ability to set the cursor position, so we need to control the cursor filter from here
     match app.active_panel {
@@ -123,6 +124,15 @@ pub fn draw_ui(f: &mut Frame, app: &Arc<Mutex<App>>) {
         Panel::Logs => app.logs.render(panel_area, f.buffer_mut()),
     }
 
+    if let Some(palette) = &mut app.palette {
+        palette.render(panel_area, f.buffer_mut());
+        f.set_cursor_position(palette.cursor_position);
+    }
+    if let Some(jump) = &mut app.jump {
+        jump.render(panel_area, f.buffer_mut());
+        f.set_cursor_position(jump.cursor_position);
+    }
+
     // Render global warning popup on top of all panels
     if let Some(warning_popup) = &app.warning_popup {
         warning_popup.render(panel_area, f.buffer_mut());
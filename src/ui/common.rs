@@ -6,6 +6,8 @@ use ratatui::{
 
 use super::constants::AirflowStateColor;
 use super::theme::theme;
+use crate::app::actions::ActionId;
+use crate::app::keymap::keymap;
 
 /// Builds a modal popup `Block` with the shared popup chrome: rounded, fully
 /// bordered, themed border and background, and a title padded with a single
@@ -24,6 +26,14 @@ pub fn titled_popup_block(title: &str, accent: Color) -> Block<'static> {
         .title_style(Style::default().fg(accent).add_modifier(Modifier::BOLD))
 }
 
+/// Title of the tables pointing at the help popup, with the key bound to it
+pub fn help_title() -> String {
+    format!(
+        " Press <{}> to see available commands ",
+        keymap().hint(ActionId::Help)
+    )
+}
+
 pub fn create_headers<'a>(
     headers: impl IntoIterator<Item = &'a str>,
 ) -> impl Iterator<Item = Line<'a>> {
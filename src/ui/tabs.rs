@@ -66,6 +66,27 @@ impl TabBar {
             border_style: t.border_style,
         }
     }
+
+    /// Index of the tab drawn at `column` when the bar is rendered in `area`
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "tab widths are small, truncation won't occur"
+    )]
+    pub fn tab_at(area: Rect, column: u16) -> Option<usize> {
+        let mut x = area.x;
+        for (idx, tab) in TABS.iter().enumerate() {
+            let tab_width = tab.total_width() as u16;
+            // Tabs that do not fit are not rendered
+            if x + tab_width > area.x + area.width {
+                return None;
+            }
+            if (x..x + tab_width).contains(&column) {
+                return Some(idx);
+            }
+            x += tab_width;
+        }
+        None
+    }
 }
 
 impl Widget for TabBar {
@@ -162,3 +183,21 @@ impl Widget for TabBar {
 
 /// Returns the height needed for the tab bar (3 lines: top, content, bottom/shared border)
 pub const TAB_BAR_HEIGHT: u16 = 3;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_tab_under_a_column() {
+        let area = Rect::new(2, 1, 80, TAB_BAR_HEIGHT);
+        // "│ ⚙ Config │" spans 12 columns from the left edge of the area
+        assert_eq!(TabBar::tab_at(area, 2), Some(0));
+        assert_eq!(TabBar::tab_at(area, 13), Some(0));
+        assert_eq!(TabBar::tab_at(area, 14), Some(1));
+        assert_eq!(TabBar::tab_at(area, 1), None);
+        assert_eq!(TabBar::tab_at(area, 79), None);
+        // Tabs cut off by a narrow terminal cannot be clicked
+        assert_eq!(TabBar::tab_at(Rect::new(0, 0, 20, 3), 15), None);
+    }
+}
@@ -60,6 +60,18 @@ pub struct Theme {
     pub button_selected: Style,
     pub border_default: Color,
     pub border_selected: Color,
+    /// Styles for the level column of task logs.
+    pub log_debug_style: Style,
+    pub log_info_style: Style,
+    pub log_warning_style: Style,
+    pub log_error_style: Style,
+    pub log_critical_style: Style,
+    /// Styles for log search matches; the current match stands out.
+    pub search_match_style: Style,
+    pub search_current_style: Style,
+    /// Styles for lines removed and added in a diff between task tries.
+    pub diff_removed_style: Style,
+    pub diff_added_style: Style,
 }
 
 static THEME: OnceLock<Theme> = OnceLock::new();
@@ -81,6 +81,19 @@ impl Theme {
                 .add_modifier(Modifier::BOLD),
             border_default: p.purple_dim,
             border_selected: p.border_selected,
+            log_debug_style: Style::new().fg(p.text_muted),
+            log_info_style: Style::new().fg(p.state_success),
+            log_warning_style: Style::new().fg(p.state_up_for_retry),
+            log_error_style: Style::new().fg(p.state_failed).add_modifier(Modifier::BOLD),
+            log_critical_style: Style::new()
+                .fg(p.state_failed)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            search_match_style: Style::new().fg(p.text_primary).bg(p.marked_bg),
+            search_current_style: Style::new()
+                .fg(p.accent)
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+            diff_removed_style: Style::new().fg(p.state_failed),
+            diff_added_style: Style::new().fg(p.state_success),
         }
     }
 }
@@ -4,10 +4,11 @@
     reason = "helpers are shared across test files and look unused when each is compiled separately"
 )]
 
+use std::collections::BTreeMap;
 use std::env;
 use std::sync::Arc;
 
-use flowrs_config::{AirflowAuth, AirflowConfig, AirflowVersion, BasicAuth, TokenSource};
+use flowrs_config::{AirflowAuth, AirflowConfig, AirflowVersion, BasicAuth};
 use flowrs_tui::airflow::client::FlowrsClient;
 use flowrs_tui::airflow::traits::AirflowClient;
 
@@ -23,36 +24,6 @@ pub fn should_run_for_api_version(version: &str) -> bool {
     test_version.is_empty() || test_version == version
 }
 
-/// Get a JWT token from Airflow 3's /auth/token endpoint
-async fn get_jwt_token(url: &str, username: &str, password: &str) -> anyhow::Result<String> {
-    let client = reqwest::Client::new();
-    let token_url = format!("{}/auth/token", url.trim_end_matches('/'));
-
-    let response = client
-        .post(&token_url)
-        .header("Content-Type", "application/json")
-        .json(&serde_json::json!({
-            "username": username,
-            "password": password
-        }))
-        .send()
-        .await?;
-
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        anyhow::bail!("Failed to get JWT token: {status} - {body}");
-    }
-
-    let token_response: serde_json::Value = response.json().await?;
-    let token = token_response["access_token"]
-        .as_str()
-        .ok_or_else(|| anyhow::anyhow!("No access_token in response"))?
-        .to_string();
-
-    Ok(token)
-}
-
 /// Create a test client from environment variables
 pub fn create_test_client() -> anyhow::Result<Arc<dyn AirflowClient>> {
     let url = env::var("TEST_AIRFLOW_URL").expect("TEST_AIRFLOW_URL must be set");
@@ -68,34 +39,47 @@ pub fn create_test_client() -> anyhow::Result<Arc<dyn AirflowClient>> {
     let config = AirflowConfig {
         name: "test".to_string(),
         endpoint: url,
-        auth: AirflowAuth::Basic(BasicAuth { username, password }),
+        auth: AirflowAuth::Basic(BasicAuth {
+            username,
+            password: password.into(),
+        }),
         managed: None,
         version,
         timeout_secs: 30,
         insecure: false,
+        ca_cert: None,
+        client_cert: None,
+        client_key: None,
+        proxy: None,
+        headers: BTreeMap::new(),
     };
 
     let client = FlowrsClient::new(&config)?;
     Ok(Arc::new(client))
 }
 
-/// Create a test client for Airflow 3.x using JWT authentication
-pub async fn create_test_client_v3() -> anyhow::Result<Arc<dyn AirflowClient>> {
+/// Create a test client for Airflow 3.x, logging in at /auth/token for a JWT
+pub fn create_test_client_v3() -> anyhow::Result<Arc<dyn AirflowClient>> {
     let url = env::var("TEST_AIRFLOW_URL").expect("TEST_AIRFLOW_URL must be set");
     let username = env::var("TEST_AIRFLOW_USERNAME").unwrap_or_else(|_| "airflow".to_string());
     let password = env::var("TEST_AIRFLOW_PASSWORD").unwrap_or_else(|_| "airflow".to_string());
 
-    // Get JWT token from Airflow 3's auth endpoint
-    let jwt_token = get_jwt_token(&url, &username, &password).await?;
-
     let config = AirflowConfig {
         name: "test".to_string(),
         endpoint: url,
-        auth: AirflowAuth::Token(TokenSource::Static { token: jwt_token }),
+        auth: AirflowAuth::Jwt(BasicAuth {
+            username,
+            password: password.into(),
+        }),
         managed: None,
         version: AirflowVersion::V3,
         timeout_secs: 30,
         insecure: false,
+        ca_cert: None,
+        client_cert: None,
+        client_key: None,
+        proxy: None,
+        headers: BTreeMap::new(),
     };
 
     let client = FlowrsClient::new(&config)?;
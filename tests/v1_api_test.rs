@@ -1,5 +1,7 @@
 mod common;
 
+use flowrs_airflow::{DagFilter, DagRunFilter};
+
 use common::{create_test_client, should_run_for_api_version};
 
 #[tokio::test]
@@ -10,7 +12,7 @@ async fn test_v1_list_dags() {
     }
 
     let client = create_test_client().expect("Failed to create test client");
-    let result = client.list_dags().await;
+    let result = client.list_dags(&DagFilter::default()).await;
 
     assert!(result.is_ok(), "Failed to list DAGs: {:?}", result.err());
 
@@ -29,7 +31,10 @@ async fn test_v1_dag_has_required_fields() {
     }
 
     let client = create_test_client().expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
+        .await
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
         // Verify DAG has required fields populated
@@ -44,7 +49,10 @@ async fn test_v1_get_dag_code() {
     }
 
     let client = create_test_client().expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
+        .await
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
         let code = client
@@ -65,10 +73,15 @@ async fn test_v1_list_dagruns() {
     }
 
     let client = create_test_client().expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
+        .await
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
-        let result = client.list_dagruns(&dag.dag_id).await;
+        let result = client
+            .list_dagruns(&dag.dag_id, &DagRunFilter::default(), 0, 50)
+            .await;
         assert!(
             result.is_ok(),
             "Failed to list DAG runs: {:?}",
@@ -85,7 +98,10 @@ async fn test_v1_list_tasks() {
     }
 
     let client = create_test_client().expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
+        .await
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
         let result = client.list_tasks(&dag.dag_id).await;
@@ -1,5 +1,7 @@
 mod common;
 
+use flowrs_airflow::{DagFilter, DagRunFilter};
+
 use common::{create_test_client_v3, should_run_for_api_version};
 
 #[tokio::test]
@@ -9,10 +11,8 @@ async fn test_v2_list_dags() {
         return;
     }
 
-    let client = create_test_client_v3()
-        .await
-        .expect("Failed to create test client");
-    let result = client.list_dags().await;
+    let client = create_test_client_v3().expect("Failed to create test client");
+    let result = client.list_dags(&DagFilter::default()).await;
 
     assert!(result.is_ok(), "Failed to list DAGs: {:?}", result.err());
 
@@ -30,10 +30,11 @@ async fn test_v2_dag_has_required_fields() {
         return;
     }
 
-    let client = create_test_client_v3()
+    let client = create_test_client_v3().expect("Failed to create test client");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
         .await
-        .expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
         // Verify DAG has required fields populated
@@ -47,10 +48,11 @@ async fn test_v2_get_dag_code() {
         return;
     }
 
-    let client = create_test_client_v3()
+    let client = create_test_client_v3().expect("Failed to create test client");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
         .await
-        .expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
         let code = client
@@ -70,13 +72,16 @@ async fn test_v2_list_dagruns() {
         return;
     }
 
-    let client = create_test_client_v3()
+    let client = create_test_client_v3().expect("Failed to create test client");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
         .await
-        .expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
-        let result = client.list_dagruns(&dag.dag_id).await;
+        let result = client
+            .list_dagruns(&dag.dag_id, &DagRunFilter::default(), 0, 50)
+            .await;
         assert!(
             result.is_ok(),
             "Failed to list DAG runs: {:?}",
@@ -92,10 +97,11 @@ async fn test_v2_dag_stats() {
         return;
     }
 
-    let client = create_test_client_v3()
+    let client = create_test_client_v3().expect("Failed to create test client");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
         .await
-        .expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+        .expect("Failed to list DAGs");
 
     if !dag_list.dags.is_empty() {
         let dag_ids: Vec<&str> = dag_list
@@ -121,14 +127,15 @@ async fn test_v2_list_task_instances() {
         return;
     }
 
-    let client = create_test_client_v3()
+    let client = create_test_client_v3().expect("Failed to create test client");
+    let dag_list = client
+        .list_dags(&DagFilter::default())
         .await
-        .expect("Failed to create test client");
-    let dag_list = client.list_dags().await.expect("Failed to list DAGs");
+        .expect("Failed to list DAGs");
 
     if let Some(dag) = dag_list.dags.first() {
         let dagruns = client
-            .list_dagruns(&dag.dag_id)
+            .list_dagruns(&dag.dag_id, &DagRunFilter::default(), 0, 50)
             .await
             .expect("Failed to list DAG runs");
 